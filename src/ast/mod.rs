@@ -1,6 +1,7 @@
 pub mod node;
 
 use crate::error::CrocoError;
+use crate::symbol_type::SymbolType;
 use crate::token::CodePos;
 use dyn_clonable::*;
 
@@ -21,6 +22,19 @@ pub trait AstNode: Clone {
     fn get_type(&self) -> AstNodeType {
         unimplemented!();
     }
+
+    /// Returns the type of the value produced by the node, if it is known at parse time
+    fn get_static_type(&self) -> Option<SymbolType> {
+        None
+    }
+
+    /// Marks the node as the target of an assignment
+    fn set_assigned(&mut self) {}
+
+    /// Returns true if the node always leaves the enclosing block, e.g a return statement
+    fn exits_early(&self) -> bool {
+        false
+    }
 }
 
 /// A node implementing all backends
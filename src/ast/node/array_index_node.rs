@@ -3,10 +3,12 @@ use crate::token::CodePos;
 
 #[derive(Clone)]
 
-/// A node used to access an array element at a certain index.
+/// A node used to access an array element at a certain index, or a map value at a certain key.
 pub struct ArrayIndexNode {
     pub array: Option<Box<dyn BackendNode>>,
     pub index: Box<dyn BackendNode>,
+    // wether or not we're assigning to this element, in which case a missing map key is inserted
+    pub is_assigned: bool,
     pub code_pos: CodePos,
 }
 
@@ -15,6 +17,7 @@ impl ArrayIndexNode {
         ArrayIndexNode {
             array: None,
             index,
+            is_assigned: false,
             code_pos,
         }
     }
@@ -28,6 +31,10 @@ impl AstNode for ArrayIndexNode {
             unreachable!()
         }
     }
+
+    fn set_assigned(&mut self) {
+        self.is_assigned = true;
+    }
 }
 
 impl BackendNode for ArrayIndexNode {}
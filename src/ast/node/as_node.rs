@@ -6,6 +6,8 @@ use crate::{symbol_type::SymbolType, token::CodePos};
 pub struct AsNode {
     pub bottom: Option<Box<dyn BackendNode>>,
     pub as_type: SymbolType,
+    // implicit casts, e.g in interpolated strs, can be redundant
+    pub implicit: bool,
     pub code_pos: CodePos,
 }
 
@@ -14,6 +16,16 @@ impl AsNode {
         AsNode {
             bottom: None,
             as_type,
+            implicit: false,
+            code_pos,
+        }
+    }
+
+    pub fn new_implicit(as_type: SymbolType, code_pos: CodePos) -> Self {
+        AsNode {
+            bottom: None,
+            as_type,
+            implicit: true,
             code_pos,
         }
     }
@@ -31,6 +43,10 @@ impl AstNode for AsNode {
     fn get_type(&self) -> AstNodeType {
         AstNodeType::UnaryNode
     }
+
+    fn get_static_type(&self) -> Option<SymbolType> {
+        Some(self.as_type.clone())
+    }
 }
 
 impl BackendNode for AsNode {}
@@ -0,0 +1,41 @@
+use crate::ast::{AstNode, AstNodeType, BackendNode};
+use crate::token::CodePos;
+use crate::token::OperatorEnum;
+
+#[derive(Clone)]
+/// A node operating on the bits of two integers, e.g a & b, a ^^ b or a << b
+pub struct BitwiseNode {
+    pub left: Option<Box<dyn BackendNode>>,
+    pub right: Option<Box<dyn BackendNode>>,
+    pub bitwise_kind: OperatorEnum,
+    pub code_pos: CodePos,
+}
+
+impl BitwiseNode {
+    pub fn new(bitwise_kind: OperatorEnum, code_pos: CodePos) -> Self {
+        BitwiseNode {
+            left: None,
+            right: None,
+            bitwise_kind,
+            code_pos,
+        }
+    }
+}
+
+impl AstNode for BitwiseNode {
+    fn add_child(&mut self, node: Box<dyn BackendNode>) {
+        if self.left.is_none() {
+            self.left = Some(node);
+        } else if self.right.is_none() {
+            self.right = Some(node);
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn get_type(&self) -> AstNodeType {
+        AstNodeType::BinaryNode
+    }
+}
+
+impl BackendNode for BitwiseNode {}
@@ -0,0 +1,34 @@
+use crate::ast::{AstNode, AstNodeType, BackendNode};
+use crate::token::CodePos;
+
+#[derive(Clone)]
+/// A node inverting the bits of an integer, e.g ~a
+pub struct BitwiseNotNode {
+    pub bottom: Option<Box<dyn BackendNode>>,
+    pub code_pos: CodePos,
+}
+
+impl BitwiseNotNode {
+    pub fn new(code_pos: CodePos) -> Self {
+        BitwiseNotNode {
+            bottom: None,
+            code_pos,
+        }
+    }
+}
+
+impl AstNode for BitwiseNotNode {
+    fn add_child(&mut self, node: Box<dyn BackendNode>) {
+        if self.bottom.is_none() {
+            self.bottom = Some(node);
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn get_type(&self) -> AstNodeType {
+        AstNodeType::UnaryNode
+    }
+}
+
+impl BackendNode for BitwiseNotNode {}
@@ -27,6 +27,10 @@ impl AstNode for BlockNode {
     fn add_child(&mut self, node: Box<dyn BackendNode>) {
         self.body.push(node);
     }
+
+    fn exits_early(&self) -> bool {
+        self.body.last().is_some_and(|node| node.exits_early())
+    }
 }
 
 impl BackendNode for BlockNode {}
@@ -10,5 +10,10 @@ impl BreakNode {
     }
 }
 
-impl AstNode for BreakNode {}
+impl AstNode for BreakNode {
+    fn exits_early(&self) -> bool {
+        true
+    }
+}
+
 impl BackendNode for BreakNode {}
@@ -0,0 +1,45 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::symbol_type::SymbolType;
+use crate::token::CodePos;
+
+/// What to do when a result holds an error
+#[derive(Clone)]
+pub enum CatchHandler {
+    /// use another value, e.g read_file("a.txt") else ""
+    Fallback(Box<dyn BackendNode>),
+    /// run a block with the error message, e.g read_file("a.txt") catch e { ... }
+    Block {
+        var_name: String,
+        body: Box<dyn BackendNode>,
+    },
+}
+
+/// a node handling the error of a result
+#[derive(Clone)]
+pub struct CatchNode {
+    // the result being handled
+    pub bottom: Box<dyn BackendNode>,
+    pub handler: CatchHandler,
+    pub code_pos: CodePos,
+}
+
+impl CatchNode {
+    pub fn new(bottom: Box<dyn BackendNode>, handler: CatchHandler, code_pos: CodePos) -> Self {
+        CatchNode {
+            bottom,
+            handler,
+            code_pos,
+        }
+    }
+}
+
+impl AstNode for CatchNode {
+    fn get_static_type(&self) -> Option<SymbolType> {
+        match self.bottom.get_static_type() {
+            Some(SymbolType::Result(Some(ok_type))) => Some(*ok_type),
+            _ => None,
+        }
+    }
+}
+
+impl BackendNode for CatchNode {}
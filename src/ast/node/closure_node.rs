@@ -0,0 +1,29 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::symbol::FunctionDecl;
+use crate::symbol_type::SymbolType;
+use crate::token::CodePos;
+
+/// a node holding an anonymous function, which captures the variables around it
+#[derive(Clone)]
+pub struct ClosureNode {
+    pub fn_decl: FunctionDecl,
+    pub fn_body: Box<dyn BackendNode>,
+    pub code_pos: CodePos,
+}
+
+impl ClosureNode {
+    pub fn new(fn_decl: FunctionDecl, fn_body: Box<dyn BackendNode>, code_pos: CodePos) -> Self {
+        ClosureNode {
+            fn_decl,
+            fn_body,
+            code_pos,
+        }
+    }
+}
+
+impl AstNode for ClosureNode {
+    fn get_static_type(&self) -> Option<SymbolType> {
+        Some(self.fn_decl.get_type())
+    }
+}
+impl BackendNode for ClosureNode {}
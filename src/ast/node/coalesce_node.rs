@@ -0,0 +1,46 @@
+use crate::ast::{AstNode, AstNodeType, BackendNode};
+use crate::symbol_type::SymbolType;
+use crate::token::CodePos;
+
+/// a node giving the value of an optional, or a default value if it's empty, e.g name ?? "anonymous"
+#[derive(Clone)]
+pub struct CoalesceNode {
+    pub left: Option<Box<dyn BackendNode>>,
+    pub right: Option<Box<dyn BackendNode>>,
+    pub code_pos: CodePos,
+}
+
+impl CoalesceNode {
+    pub fn new(code_pos: CodePos) -> Self {
+        CoalesceNode {
+            left: None,
+            right: None,
+            code_pos,
+        }
+    }
+}
+
+impl AstNode for CoalesceNode {
+    fn add_child(&mut self, node: Box<dyn BackendNode>) {
+        if self.left.is_none() {
+            self.left = Some(node);
+        } else if self.right.is_none() {
+            self.right = Some(node);
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn get_type(&self) -> AstNodeType {
+        AstNodeType::BinaryNode
+    }
+
+    // the default value has the type of the unwrapped optional
+    fn get_static_type(&self) -> Option<SymbolType> {
+        self.right
+            .as_ref()
+            .and_then(|right| right.get_static_type())
+    }
+}
+
+impl BackendNode for CoalesceNode {}
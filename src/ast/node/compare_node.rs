@@ -1,4 +1,5 @@
 use crate::ast::{AstNode, AstNodeType, BackendNode};
+use crate::symbol_type::SymbolType;
 use crate::token::CodePos;
 use crate::token::OperatorEnum;
 
@@ -36,6 +37,10 @@ impl AstNode for CompareNode {
     fn get_type(&self) -> AstNodeType {
         AstNodeType::BinaryNode
     }
+
+    fn get_static_type(&self) -> Option<SymbolType> {
+        Some(SymbolType::Bool)
+    }
 }
 
 impl BackendNode for CompareNode {}
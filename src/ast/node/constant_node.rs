@@ -1,5 +1,6 @@
 use crate::{
     ast::{AstNode, BackendNode},
+    symbol_type::SymbolType,
     token::{CodePos, LiteralEnum},
 };
 
@@ -16,5 +17,9 @@ impl ConstantNode {
     }
 }
 
-impl AstNode for ConstantNode {}
+impl AstNode for ConstantNode {
+    fn get_static_type(&self) -> Option<SymbolType> {
+        Some(self.value.get_symbol_type())
+    }
+}
 impl BackendNode for ConstantNode {}
@@ -10,5 +10,10 @@ impl ContinueNode {
     }
 }
 
-impl AstNode for ContinueNode {}
+impl AstNode for ContinueNode {
+    fn exits_early(&self) -> bool {
+        true
+    }
+}
+
 impl BackendNode for ContinueNode {}
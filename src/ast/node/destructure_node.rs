@@ -0,0 +1,28 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::token::CodePos;
+
+/// A node declaring a variable for each element of a tuple, e.g let (quotient, remainder) = div(7, 2)
+#[derive(Clone)]
+pub struct DestructureNode {
+    // the variable names, None for an ignored element
+    pub var_names: Vec<Option<String>>,
+    pub right: Box<dyn BackendNode>,
+    pub code_pos: CodePos,
+}
+
+impl DestructureNode {
+    pub fn new(
+        var_names: Vec<Option<String>>,
+        right: Box<dyn BackendNode>,
+        code_pos: CodePos,
+    ) -> Self {
+        DestructureNode {
+            var_names,
+            right,
+            code_pos,
+        }
+    }
+}
+
+impl AstNode for DestructureNode {}
+impl BackendNode for DestructureNode {}
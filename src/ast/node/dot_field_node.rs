@@ -6,14 +6,17 @@ use crate::token::CodePos;
 pub struct DotFieldNode {
     pub field_name: String,
     pub bottom: Option<Box<dyn BackendNode>>,
+    // if the field is accessed through an optional with ?.
+    pub optional: bool,
     pub code_pos: CodePos,
 }
 
 impl DotFieldNode {
-    pub fn new(field_name: String, code_pos: CodePos) -> Self {
+    pub fn new(field_name: String, optional: bool, code_pos: CodePos) -> Self {
         DotFieldNode {
             bottom: None,
             field_name,
+            optional,
             code_pos,
         }
     }
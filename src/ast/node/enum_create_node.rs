@@ -0,0 +1,36 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::symbol_type::SymbolType;
+use crate::token::CodePos;
+
+/// a node holding an enum variant, e.g Shape.Circle(2.0)
+#[derive(Clone)]
+pub struct EnumCreateNode {
+    pub enum_type: String,
+    pub variant: String,
+    // the values held by the variant
+    pub payload: Vec<Box<dyn BackendNode>>,
+    pub code_pos: CodePos,
+}
+
+impl EnumCreateNode {
+    pub fn new(
+        enum_type: String,
+        variant: String,
+        payload: Vec<Box<dyn BackendNode>>,
+        code_pos: CodePos,
+    ) -> Self {
+        EnumCreateNode {
+            enum_type,
+            variant,
+            payload,
+            code_pos,
+        }
+    }
+}
+
+impl AstNode for EnumCreateNode {
+    fn get_static_type(&self) -> Option<SymbolType> {
+        Some(SymbolType::Enum(self.enum_type.clone()))
+    }
+}
+impl BackendNode for EnumCreateNode {}
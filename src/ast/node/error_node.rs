@@ -0,0 +1,24 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::symbol_type::SymbolType;
+use crate::token::CodePos;
+
+/// a node building an error from a message, e.g error("file not found")
+#[derive(Clone)]
+pub struct ErrorNode {
+    pub message: Box<dyn BackendNode>,
+    pub code_pos: CodePos,
+}
+
+impl ErrorNode {
+    pub fn new(message: Box<dyn BackendNode>, code_pos: CodePos) -> Self {
+        ErrorNode { message, code_pos }
+    }
+}
+
+impl AstNode for ErrorNode {
+    fn get_static_type(&self) -> Option<SymbolType> {
+        Some(SymbolType::Error)
+    }
+}
+
+impl BackendNode for ErrorNode {}
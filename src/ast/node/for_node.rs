@@ -0,0 +1,45 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::token::CodePos;
+
+/// What a for loop iterates over
+#[derive(Clone)]
+pub enum ForIterable {
+    /// a range of nums, e.g 0..5 or 0..=5
+    Range {
+        start: Box<dyn BackendNode>,
+        end: Box<dyn BackendNode>,
+        inclusive: bool,
+    },
+    /// the elements of an array
+    Array(Box<dyn BackendNode>),
+}
+
+/// a node representing a for statement
+#[derive(Clone)]
+pub struct ForNode {
+    // the name of the loop variable
+    pub var_name: String,
+    pub iterable: ForIterable,
+    // for body (a BlockNode)
+    pub body: Box<dyn BackendNode>,
+    pub code_pos: CodePos,
+}
+
+impl ForNode {
+    pub fn new(
+        var_name: String,
+        iterable: ForIterable,
+        body: Box<dyn BackendNode>,
+        code_pos: CodePos,
+    ) -> Self {
+        ForNode {
+            var_name,
+            iterable,
+            body,
+            code_pos,
+        }
+    }
+}
+
+impl AstNode for ForNode {}
+impl BackendNode for ForNode {}
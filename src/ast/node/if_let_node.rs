@@ -0,0 +1,35 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::token::CodePos;
+
+/// a node unwrapping an optional, e.g if let node = list.head { ... } else { ... }
+#[derive(Clone)]
+pub struct IfLetNode {
+    // the variable holding the value of the optional in the body
+    pub var_name: String,
+    pub value: Box<dyn BackendNode>,
+    pub body: Box<dyn BackendNode>,
+    // the body taken if the optional is empty
+    pub else_body: Option<Box<dyn BackendNode>>,
+    pub code_pos: CodePos,
+}
+
+impl IfLetNode {
+    pub fn new(
+        var_name: String,
+        value: Box<dyn BackendNode>,
+        body: Box<dyn BackendNode>,
+        else_body: Option<Box<dyn BackendNode>>,
+        code_pos: CodePos,
+    ) -> Self {
+        IfLetNode {
+            var_name,
+            value,
+            body,
+            else_body,
+            code_pos,
+        }
+    }
+}
+
+impl AstNode for IfLetNode {}
+impl BackendNode for IfLetNode {}
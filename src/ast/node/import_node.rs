@@ -1,6 +1,7 @@
 use crate::ast::{AstNode, BackendNode};
 use crate::token::CodePos;
-/// a node to import code from another module, at runtime.
+/// a node to import code from another module.
+/// The files imported are parsed with the file importing them, the builtin modules are imported by the backends.
 #[derive(Clone)]
 pub struct ImportNode {
     pub name: String,
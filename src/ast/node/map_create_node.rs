@@ -0,0 +1,36 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::token::CodePos;
+
+/// A node representing a map symbol
+/// checks at runtime if the type constraints are respected
+#[derive(Clone)]
+pub struct MapCreateNode {
+    // the key-value pairs, in declaration order
+    pub contents: Vec<(Box<dyn BackendNode>, Box<dyn BackendNode>)>,
+    pub code_pos: CodePos,
+}
+
+impl MapCreateNode {
+    pub fn new(
+        contents: Vec<(Box<dyn BackendNode>, Box<dyn BackendNode>)>,
+        code_pos: CodePos,
+    ) -> Self {
+        MapCreateNode { contents, code_pos }
+    }
+}
+
+impl AstNode for MapCreateNode {
+    fn prepend_child(&mut self, _node: Box<dyn BackendNode>) {
+        unimplemented!();
+    }
+
+    fn add_child(&mut self, _node: Box<dyn BackendNode>) {
+        unimplemented!();
+    }
+
+    fn get_type(&self) -> crate::ast::AstNodeType {
+        unimplemented!();
+    }
+}
+
+impl BackendNode for MapCreateNode {}
@@ -0,0 +1,90 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::symbol::EnumDecl;
+use crate::symbol_type::SymbolType;
+use crate::token::{CodePos, LiteralEnum};
+
+/// A pattern tested against the matched value
+#[derive(Clone, Debug)]
+pub enum MatchPattern {
+    /// a literal value, e.g 3 or "croco"
+    Literal(LiteralEnum),
+    /// a range of numbers, e.g 0..5 or 0..=5
+    Range {
+        start: LiteralEnum,
+        end: LiteralEnum,
+        inclusive: bool,
+    },
+    /// an enum variant, binding its values to variables, e.g Shape.Rect(w, _)
+    Variant {
+        enum_type: String,
+        variant: String,
+        // the variable names, or None for _
+        bindings: Vec<Option<String>>,
+    },
+    /// the _ pattern, matching everything
+    Wildcard,
+}
+
+/// An arm of a match, e.g 1 | 2 => "small"
+#[derive(Clone)]
+pub struct MatchArm {
+    // the arm is taken if any of these patterns matches
+    pub patterns: Vec<MatchPattern>,
+    // the arm value, an expression or a BlockNode
+    pub body: Box<dyn BackendNode>,
+}
+
+/// a node representing a match statement or expression
+#[derive(Clone)]
+pub struct MatchNode {
+    // the value being matched
+    pub bottom: Box<dyn BackendNode>,
+    pub arms: Vec<MatchArm>,
+    pub code_pos: CodePos,
+}
+
+impl MatchNode {
+    pub fn new(bottom: Box<dyn BackendNode>, arms: Vec<MatchArm>, code_pos: CodePos) -> Self {
+        MatchNode {
+            bottom,
+            arms,
+            code_pos,
+        }
+    }
+
+    /// Returns the variants of an enum which aren't handled by any arm
+    pub fn missing_variants(&self, enum_decl: &EnumDecl) -> Vec<String> {
+        let patterns: Vec<&MatchPattern> = self
+            .arms
+            .iter()
+            .flat_map(|arm| arm.patterns.iter())
+            .collect();
+
+        if patterns
+            .iter()
+            .any(|pattern| matches!(pattern, MatchPattern::Wildcard))
+        {
+            return Vec::new();
+        }
+
+        enum_decl
+            .variants
+            .iter()
+            .map(|(variant_name, _)| variant_name)
+            .filter(|variant_name| {
+                !patterns.iter().any(|pattern| {
+                    matches!(pattern, MatchPattern::Variant { variant, .. } if variant == *variant_name)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+impl AstNode for MatchNode {
+    fn get_static_type(&self) -> Option<SymbolType> {
+        self.arms.iter().find_map(|arm| arm.body.get_static_type())
+    }
+}
+
+impl BackendNode for MatchNode {}
@@ -10,12 +10,27 @@ pub use self::as_node::AsNode;
 mod assignment_node;
 pub use self::assignment_node::AssignmentNode;
 
+mod bitwise_node;
+pub use self::bitwise_node::BitwiseNode;
+
+mod bitwise_not_node;
+pub use self::bitwise_not_node::BitwiseNotNode;
+
 mod block_node;
 pub use self::block_node::BlockNode;
 
 mod break_node;
 pub use self::break_node::BreakNode;
 
+mod catch_node;
+pub use self::catch_node::{CatchHandler, CatchNode};
+
+mod closure_node;
+pub use self::closure_node::ClosureNode;
+
+mod coalesce_node;
+pub use self::coalesce_node::CoalesceNode;
+
 mod compare_node;
 pub use self::compare_node::CompareNode;
 
@@ -28,30 +43,57 @@ pub use self::continue_node::ContinueNode;
 mod deref_node;
 pub use self::deref_node::DerefNode;
 
+mod destructure_node;
+pub use self::destructure_node::DestructureNode;
+
 mod divide_node;
 pub use self::divide_node::DivideNode;
 
 mod dot_field_node;
 pub use self::dot_field_node::DotFieldNode;
 
+mod enum_create_node;
+pub use self::enum_create_node::EnumCreateNode;
+
+mod error_node;
+pub use self::error_node::ErrorNode;
+
+mod for_node;
+pub use self::for_node::{ForIterable, ForNode};
+
 mod function_call_node;
 pub use self::function_call_node::FunctionCallNode;
 
 mod function_decl_node;
 pub use self::function_decl_node::FunctionDeclNode;
 
+mod if_let_node;
+pub use self::if_let_node::IfLetNode;
+
 mod if_node;
 pub use self::if_node::IfNode;
 
 mod import_node;
 pub use self::import_node::ImportNode;
 
+mod map_create_node;
+pub use self::map_create_node::MapCreateNode;
+
+mod match_node;
+pub use self::match_node::{MatchArm, MatchNode, MatchPattern};
+
 mod minus_node;
 pub use self::minus_node::MinusNode;
 
+mod modulo_node;
+pub use self::modulo_node::ModuloNode;
+
 mod multiplicate_node;
 pub use self::multiplicate_node::MultiplicateNode;
 
+mod none_node;
+pub use self::none_node::NoneNode;
+
 mod not_node;
 pub use self::not_node::NotNode;
 
@@ -70,6 +112,12 @@ pub use self::struct_create_node::StructCreateNode;
 mod struct_decl_node;
 pub use self::struct_decl_node::StructDeclNode;
 
+mod try_node;
+pub use self::try_node::TryNode;
+
+mod tuple_create_node;
+pub use self::tuple_create_node::TupleCreateNode;
+
 mod type_node;
 pub use self::type_node::TypeNode;
 
@@ -0,0 +1,20 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::symbol_type::SymbolType;
+
+/// a node holding the none literal, the value of an empty optional
+#[derive(Clone)]
+pub struct NoneNode {}
+
+impl NoneNode {
+    pub fn new() -> Self {
+        NoneNode {}
+    }
+}
+
+impl AstNode for NoneNode {
+    fn get_static_type(&self) -> Option<SymbolType> {
+        Some(SymbolType::None)
+    }
+}
+
+impl BackendNode for NoneNode {}
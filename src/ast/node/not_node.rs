@@ -1,4 +1,5 @@
 use crate::ast::{AstNode, AstNodeType, BackendNode};
+use crate::symbol_type::SymbolType;
 use crate::token::CodePos;
 #[derive(Clone)]
 /// a node used to invert a boolean value
@@ -28,6 +29,10 @@ impl AstNode for NotNode {
     fn get_type(&self) -> AstNodeType {
         AstNodeType::UnaryNode
     }
+
+    fn get_static_type(&self) -> Option<SymbolType> {
+        Some(SymbolType::Bool)
+    }
 }
 
 impl BackendNode for NotNode {}
@@ -14,5 +14,10 @@ impl ReturnNode {
     }
 }
 
-impl AstNode for ReturnNode {}
+impl AstNode for ReturnNode {
+    fn exits_early(&self) -> bool {
+        true
+    }
+}
+
 impl BackendNode for ReturnNode {}
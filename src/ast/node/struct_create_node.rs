@@ -1,4 +1,5 @@
 use crate::ast::{AstNode, BackendNode};
+use crate::symbol_type::SymbolType;
 use crate::token::CodePos;
 use std::collections::HashMap;
 
@@ -24,5 +25,9 @@ impl StructCreateNode {
     }
 }
 
-impl AstNode for StructCreateNode {}
+impl AstNode for StructCreateNode {
+    fn get_static_type(&self) -> Option<SymbolType> {
+        Some(SymbolType::Struct(self.struct_type.clone()))
+    }
+}
 impl BackendNode for StructCreateNode {}
@@ -0,0 +1,38 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::symbol_type::SymbolType;
+use crate::token::CodePos;
+
+/// a node unwrapping a result, or returning its error to the caller, e.g read_file("a.txt")?
+#[derive(Clone)]
+pub struct TryNode {
+    pub bottom: Option<Box<dyn BackendNode>>,
+    pub code_pos: CodePos,
+}
+
+impl TryNode {
+    pub fn new(code_pos: CodePos) -> Self {
+        TryNode {
+            bottom: None,
+            code_pos,
+        }
+    }
+}
+
+impl AstNode for TryNode {
+    fn add_child(&mut self, node: Box<dyn BackendNode>) {
+        if self.bottom.is_none() {
+            self.bottom = Some(node);
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn get_static_type(&self) -> Option<SymbolType> {
+        match self.bottom.as_ref()?.get_static_type() {
+            Some(SymbolType::Result(Some(ok_type))) => Some(*ok_type),
+            _ => None,
+        }
+    }
+}
+
+impl BackendNode for TryNode {}
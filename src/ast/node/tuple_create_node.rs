@@ -0,0 +1,18 @@
+use crate::ast::{AstNode, BackendNode};
+use crate::token::CodePos;
+
+/// a node holding a tuple, e.g (3, "three")
+#[derive(Clone)]
+pub struct TupleCreateNode {
+    pub elements: Vec<Box<dyn BackendNode>>,
+    pub code_pos: CodePos,
+}
+
+impl TupleCreateNode {
+    pub fn new(elements: Vec<Box<dyn BackendNode>>, code_pos: CodePos) -> Self {
+        TupleCreateNode { elements, code_pos }
+    }
+}
+
+impl AstNode for TupleCreateNode {}
+impl BackendNode for TupleCreateNode {}
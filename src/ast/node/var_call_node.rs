@@ -1,5 +1,6 @@
 use crate::{
     ast::{AstNode, BackendNode},
+    symbol_type::SymbolType,
     token::CodePos,
 };
 
@@ -8,13 +9,33 @@ use crate::{
 pub struct VarCallNode {
     pub name: String,
     pub code_pos: CodePos,
+    /// the type of the variable, if the parser knows it
+    var_type: Option<SymbolType>,
 }
 
 impl VarCallNode {
     pub fn new(name: String, code_pos: CodePos) -> Self {
-        VarCallNode { name, code_pos }
+        VarCallNode {
+            name,
+            code_pos,
+            var_type: None,
+        }
+    }
+
+    /// Creates a node for a variable whose type is known at parse time
+    pub fn new_typed(name: String, var_type: Option<SymbolType>, code_pos: CodePos) -> Self {
+        VarCallNode {
+            name,
+            code_pos,
+            var_type,
+        }
+    }
+}
+
+impl AstNode for VarCallNode {
+    fn get_static_type(&self) -> Option<SymbolType> {
+        self.var_type.clone()
     }
 }
 
-impl AstNode for VarCallNode {}
 impl BackendNode for VarCallNode {}
@@ -11,6 +11,9 @@ struct MyOptions {
 
     #[options(help = "show crocoi version")]
     version: bool,
+
+    #[options(help = "run the tests of the file instead of the main function")]
+    test: bool,
 }
 
 pub fn main() {
@@ -34,8 +37,47 @@ pub fn main() {
 
     let mut croco = Crocoi::new();
 
+    if opts.test {
+        run_tests(&mut croco, file_path);
+    }
+
     if let Err(e) = croco.exec_file(file_path) {
         eprintln!("{}", e);
         std::process::exit(1);
     }
 }
+
+/// Runs the tests of a file and exits with a non-zero code if one of them failed
+fn run_tests(croco: &mut Crocoi, file_path: &str) -> ! {
+    let results = match croco.test_file(file_path) {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    println!("running {} tests", results.len());
+
+    let mut failed = 0;
+
+    for (name, res) in &results {
+        match res {
+            Ok(()) => println!("test \"{}\" ... ok", name),
+            Err(e) => {
+                failed += 1;
+                println!("test \"{}\" ... FAILED", name);
+                eprintln!("{}", e);
+            }
+        }
+    }
+
+    println!(
+        "\ntest result: {}. {} passed; {} failed",
+        if failed == 0 { "ok" } else { "FAILED" },
+        results.len() - failed,
+        failed
+    );
+
+    std::process::exit(if failed == 0 { 0 } else { 1 });
+}
@@ -9,14 +9,14 @@ pub use self::symbol::ICodegen;
 pub use self::symbol::INodeResult;
 pub use self::symbol::ISymbol;
 
-use crate::ast::{node::FunctionCallNode, AstNode};
+use crate::ast::{node::FunctionCallNode, AstNode, BackendNode};
 use crate::lexer::Lexer;
 use crate::parser::Parser;
 use crate::symbol::SymTable;
 use crate::token::CodePos;
 use crate::{
     error::{CrocoError, CrocoErrorKind},
-    symbol::Decl,
+    symbol::{Decl, EnumDecl, FunctionDecl, StructDecl, TraitDecl},
 };
 use std::{collections::HashMap, fs};
 
@@ -28,6 +28,9 @@ pub trait CrocoiNode: AstNode + Clone {
     }
 }
 
+/// The name and the outcome of each test block
+pub type TestResults = Vec<(String, Result<(), CrocoError>)>;
+
 pub struct Crocoi {
     file_path: String,
 }
@@ -49,8 +52,84 @@ impl Crocoi {
     }
 
     pub fn exec(&mut self, code: &str) -> Result<(), CrocoError> {
+        let (mut tree, mut parser, code_pos) = self.parse(code)?;
+
+        // the first element in the tree should be a BlockNode.
+        // add a call for the main function in it
+        tree.add_child(Box::new(FunctionCallNode::new(
+            "main".to_owned(),
+            Vec::new(),
+            None,
+            code_pos,
+        )));
+
+        let mut codegen = Crocoi::init_codegen(
+            parser.take_fn_decls().collect(),
+            parser.take_struct_decls().collect(),
+            parser.take_trait_decls().collect(),
+            parser.take_enum_decls().collect(),
+        )?;
+
+        // println!("symbol tables: {:?}", self.symtable);
+        if let Err(mut e) = tree.crocoi(&mut codegen) {
+            e.set_kind_if_unknown(CrocoErrorKind::Runtime);
+            return Err(e);
+        }
+
+        // println!("symbol tables: {:?}", self.symtable);
+
+        Ok(())
+    }
+
+    pub fn test_file(&mut self, file_path: &str) -> Result<TestResults, CrocoError> {
+        let contents = fs::read_to_string(file_path).map_err(|_| {
+            CrocoError::from_type(format!("file not found: {}", file_path), CrocoErrorKind::Io)
+        })?;
+
+        self.file_path = file_path.to_owned();
+        self.test(&contents)
+    }
+
+    /// Runs all the test blocks of the code and returns the result of each test
+    pub fn test(&mut self, code: &str) -> Result<TestResults, CrocoError> {
+        let (tree, mut parser, _) = self.parse(code)?;
+
+        let fn_decls: Vec<(String, FunctionDecl)> = parser.take_fn_decls().collect();
+        let struct_decls: Vec<(String, StructDecl)> = parser.take_struct_decls().collect();
+        let trait_decls: Vec<(String, TraitDecl)> = parser.take_trait_decls().collect();
+        let enum_decls: Vec<(String, EnumDecl)> = parser.take_enum_decls().collect();
+        let mut results = Vec::new();
+
+        for mut test in parser.take_tests() {
+            // each test runs in its own interpreter, so a failing test can't affect the others
+            let mut codegen = Crocoi::init_codegen(
+                fn_decls.clone(),
+                struct_decls.clone(),
+                trait_decls.clone(),
+                enum_decls.clone(),
+            )?;
+
+            let res = tree
+                .clone()
+                .crocoi(&mut codegen)
+                .and_then(|_| test.body.crocoi(&mut codegen))
+                .map(|_| ())
+                .map_err(|mut e| {
+                    e.set_kind_if_unknown(CrocoErrorKind::Runtime);
+                    e
+                });
+
+            results.push((test.name, res));
+        }
+
+        Ok(results)
+    }
+
+    /// Builds the abstract syntax tree of the code.
+    /// The parser is returned so the declarations and tests can be retreived.
+    fn parse(&self, code: &str) -> Result<(Box<dyn BackendNode>, Parser, CodePos), CrocoError> {
         let tokens;
-        let mut tree;
+        let tree;
 
         let mut lexer = Lexer::new();
         lexer.set_file(&self.file_path);
@@ -72,53 +151,61 @@ impl Crocoi {
             }
         }
 
-        // the first element in the tree should be a BlockNode.
-        // add a call for the main function in it
         let code_pos = CodePos {
             file: lexer.get_file(),
             line: 0,
             word: 0,
         };
 
-        tree.add_child(Box::new(FunctionCallNode::new(
-            "main".to_owned(),
-            Vec::new(),
-            None,
-            code_pos,
-        )));
+        Ok((tree, parser, code_pos))
+    }
 
+    /// Creates a codegen with the builtin library and the declarations found by the parser
+    fn init_codegen(
+        fn_decls: Vec<(String, FunctionDecl)>,
+        struct_decls: Vec<(String, StructDecl)>,
+        trait_decls: Vec<(String, TraitDecl)>,
+        enum_decls: Vec<(String, EnumDecl)>,
+    ) -> Result<ICodegen, CrocoError> {
         let mut codegen = ICodegen {
             functions: HashMap::new(),
             symtable: SymTable::new(),
+            generics: HashMap::new(),
         };
 
         // import the builtin library
         import_builtin_module(&mut codegen, "global");
 
         // import all the declarations found by the parser
-        for (fn_name, fn_decl) in parser.take_fn_decls() {
+        for (fn_name, fn_decl) in fn_decls {
             codegen
                 .symtable
                 .register_decl(fn_name, Decl::FunctionDecl(fn_decl))
                 .map_err(|e| CrocoError::from_type(e, CrocoErrorKind::Runtime))?;
         }
 
-        for (struct_name, struct_decl) in parser.take_struct_decls() {
+        for (struct_name, struct_decl) in struct_decls {
             codegen
                 .symtable
                 .register_decl(struct_name, Decl::StructDecl(struct_decl))
                 .unwrap();
         }
 
-        // println!("symbol tables: {:?}", self.symtable);
-        if let Err(mut e) = tree.crocoi(&mut codegen) {
-            e.set_kind_if_unknown(CrocoErrorKind::Runtime);
-            return Err(e);
+        for (trait_name, trait_decl) in trait_decls {
+            codegen
+                .symtable
+                .register_decl(trait_name, Decl::TraitDecl(trait_decl))
+                .unwrap();
         }
 
-        // println!("symbol tables: {:?}", self.symtable);
+        for (enum_name, enum_decl) in enum_decls {
+            codegen
+                .symtable
+                .register_decl(enum_name, Decl::EnumDecl(enum_decl))
+                .unwrap();
+        }
 
-        Ok(())
+        Ok(codegen)
     }
 }
 
@@ -1,20 +1,17 @@
-use crate::crocoi::CrocoiNode;
+use crate::crocoi::{symbol::get_symbol_type, symbol::MapKey, utils::init_default, CrocoiNode};
 use crate::{ast::node::ArrayIndexNode, error::CrocoError};
 
 #[cfg(feature = "crocoi")]
-use crate::crocoi::{ICodegen, INodeResult, ISymbol::*};
+use crate::crocoi::{
+    ICodegen, INodeResult,
+    ISymbol::{Array, Map},
+};
 
 impl CrocoiNode for ArrayIndexNode {
     fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
-        // visit the index node to get the number of the element to access
+        // visit the index node to get the number of the element to access, or the map key
         let index_symbol = self.index.crocoi(codegen)?.into_symbol(&self.code_pos)?;
 
-        let index = index_symbol
-            .into_primitive()
-            .map_err(|e| CrocoError::new(&self.code_pos, e))?
-            .into_num()
-            .map_err(|e| CrocoError::new(&self.code_pos, e))?;
-
         // get the variable referencing  the array, it should not fail on unwraps
         let array_ref = self
             .array
@@ -27,9 +24,40 @@ impl CrocoiNode for ArrayIndexNode {
 
         let array = match array_borrow {
             Array(arr) => arr,
+
+            Map(map) => {
+                if get_symbol_type(&index_symbol) != *map.key_type {
+                    return Err(CrocoError::new(
+                        &self.code_pos,
+                        format!("expected a key of type {}", map.key_type),
+                    ));
+                }
+
+                let key = MapKey::from_symbol(index_symbol)
+                    .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+
+                return match map.contents.get(&key) {
+                    Some(value) => Ok(INodeResult::Variable(value.clone())),
+
+                    // the key is inserted only if we're assigning to it
+                    None if self.is_assigned => {
+                        let default = init_default(&map.value_type, codegen, &self.code_pos)?;
+                        Ok(INodeResult::Variable(map.insert(key, default)))
+                    }
+
+                    None => Err(CrocoError::map_missing_key_error(&self.code_pos)),
+                };
+            }
+
             _ => return Err(CrocoError::wrong_type_indexing(&self.code_pos)),
         };
 
+        let index = index_symbol
+            .into_primitive()
+            .map_err(|e| CrocoError::new(&self.code_pos, e))?
+            .into_num()
+            .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+
         if index < 0 {
             return Err(CrocoError::negative_indexing_error(&self.code_pos));
         }
@@ -1,4 +1,4 @@
-use crate::crocoi::CrocoiNode;
+use crate::crocoi::{utils::cast_num, CrocoiNode};
 use crate::{ast::node::AsNode, error::CrocoError};
 use crate::{symbol_type::SymbolType, token::LiteralEnum::*};
 
@@ -18,30 +18,16 @@ impl CrocoiNode for AsNode {
             .into_primitive()
             .map_err(|_| CrocoError::cast_non_primitive_error(&self.code_pos))?;
 
-        let casted = match (val_primitive, &self.as_type) {
-            // useless cast
-            (Bool(_), SymbolType::Bool)
-            | (Str(_), SymbolType::Str)
-            | (Num(_), SymbolType::Num)
-            | (Fnum(_), SymbolType::Fnum) => {
-                return Err(CrocoError::cast_redundant_error(&self.code_pos))
-            }
-            (Bool(b), SymbolType::Fnum) => {
-                if b {
-                    Fnum(1.)
-                } else {
-                    Fnum(0.)
-                }
+        // useless cast
+        if val_primitive.get_symbol_type() == self.as_type {
+            if self.implicit {
+                return Ok(INodeResult::Value(ISymbol::Primitive(val_primitive)));
             }
 
-            (Bool(b), SymbolType::Num) => {
-                if b {
-                    Num(1)
-                } else {
-                    Num(0)
-                }
-            }
+            return Err(CrocoError::cast_redundant_error(&self.code_pos));
+        }
 
+        let casted = match (val_primitive, &self.as_type) {
             (Bool(b), SymbolType::Str) => {
                 if b {
                     Str("true".to_owned())
@@ -50,35 +36,9 @@ impl CrocoiNode for AsNode {
                 }
             }
 
-            (Num(n), SymbolType::Bool) => {
-                if n == 0 {
-                    Bool(false)
-                } else {
-                    Bool(true)
-                }
-            }
+            (Bool(b), as_type) => cast_num(&Num(b as i32), as_type)
+                .ok_or_else(|| CrocoError::cast_non_primitive_error(&self.code_pos))?,
 
-            (Num(n), SymbolType::Str) => Str(n.to_string()),
-
-            (Num(n), SymbolType::Fnum) => Fnum(n as f32),
-
-            (Fnum(n), SymbolType::Bool) => {
-                if n == 0. {
-                    Bool(false)
-                } else {
-                    Bool(true)
-                }
-            }
-            (Fnum(n), SymbolType::Str) => Str(n.to_string()),
-
-            (Fnum(n), SymbolType::Num) => Num(n as i32),
-
-            (Str(s), SymbolType::Fnum) => {
-                let n = s.parse().map_err(|_| {
-                    CrocoError::new(&self.code_pos, "could not parse the str into a fnum")
-                })?;
-                Fnum(n)
-            }
             (Str(s), SymbolType::Bool) => {
                 if s.is_empty() {
                     Bool(false)
@@ -87,16 +47,40 @@ impl CrocoiNode for AsNode {
                 }
             }
 
-            (Str(s), SymbolType::Num) => {
-                let n = s.parse().map_err(|_| {
-                    CrocoError::new(&self.code_pos, "could not parse the str into a num")
-                })?;
-                Num(n)
-            }
+            (Str(s), as_type) => {
+                let parsed = match as_type {
+                    SymbolType::Num => s.parse().ok().map(Num),
+                    SymbolType::Fnum => s.parse().ok().map(Fnum),
+                    SymbolType::I64 => s.parse().ok().map(I64),
+                    SymbolType::U64 => s.parse().ok().map(U64),
+                    SymbolType::U8 => s.parse().ok().map(U8),
+                    SymbolType::F64 => s.parse().ok().map(F64),
+                    _ => return Err(CrocoError::cast_non_primitive_error(&self.code_pos)),
+                };
 
-            _ => {
-                return Err(CrocoError::cast_non_primitive_error(&self.code_pos));
+                parsed.ok_or_else(|| {
+                    CrocoError::new(
+                        &self.code_pos,
+                        format!("could not parse the str into a {}", as_type),
+                    )
+                })?
             }
+
+            // the remaining values are numbers
+            (n, SymbolType::Bool) => Bool(cast_num(&n, &SymbolType::F64) != Some(F64(0.))),
+
+            (n, SymbolType::Str) => Str(match n {
+                Num(n) => n.to_string(),
+                Fnum(n) => n.to_string(),
+                I64(n) => n.to_string(),
+                U64(n) => n.to_string(),
+                U8(n) => n.to_string(),
+                F64(n) => n.to_string(),
+                _ => unreachable!(),
+            }),
+
+            (n, as_type) => cast_num(&n, as_type)
+                .ok_or_else(|| CrocoError::cast_non_primitive_error(&self.code_pos))?,
         };
 
         Ok(INodeResult::Value(ISymbol::Primitive(casted)))
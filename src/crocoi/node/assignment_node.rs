@@ -1,6 +1,6 @@
 use crate::{
     ast::node::AssignmentNode,
-    crocoi::{symbol::get_symbol_type, CrocoiNode, ICodegen, INodeResult},
+    crocoi::{symbol::get_symbol_type, utils::coerce_to_type, CrocoiNode, ICodegen, INodeResult},
 };
 
 use crate::error::CrocoError;
@@ -13,9 +13,19 @@ impl CrocoiNode for AssignmentNode {
             .crocoi(codegen)?
             .into_var(&self.code_pos)
             .map_err(|_| CrocoError::new(&self.code_pos, "can't assign to this expression"))?;
-        let expr = self.expr.crocoi(codegen)?.into_symbol(&self.code_pos)?;
+        let expr = self.expr.crocoi(codegen)?;
 
-        if get_symbol_type(&*var.borrow()) != get_symbol_type(&expr) {
+        // a catch block can exit early instead of giving a value
+        if let INodeResult::Return(_) | INodeResult::Break | INodeResult::Continue = expr {
+            return Ok(expr);
+        }
+
+        let expr = expr.into_symbol(&self.code_pos)?;
+
+        let var_type = get_symbol_type(&*var.borrow());
+        let expr = coerce_to_type(expr, &var_type, codegen, &self.code_pos)?;
+
+        if var_type != get_symbol_type(&expr) {
             return Err(CrocoError::type_change_error(&self.code_pos));
         }
 
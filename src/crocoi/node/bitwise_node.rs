@@ -0,0 +1,64 @@
+use crate::crocoi::{
+    utils::{cast_num, get_value, widen_values},
+    CrocoiNode, ICodegen, INodeResult, ISymbol,
+};
+use crate::symbol_type::SymbolType;
+use crate::token::{LiteralEnum::*, OperatorEnum};
+use crate::{ast::node::BitwiseNode, error::CrocoError};
+
+impl CrocoiNode for BitwiseNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let left_val = get_value(&mut self.left, codegen, &self.code_pos)?;
+        let right_val = get_value(&mut self.right, codegen, &self.code_pos)?;
+
+        if !left_val.get_symbol_type().is_integer() || !right_val.get_symbol_type().is_integer() {
+            return Err(CrocoError::bitwise_error(&self.code_pos));
+        }
+
+        let value = match self.bitwise_kind {
+            // a shift keeps the type of the shifted number, and the shift amount wraps around its size
+            OperatorEnum::ShiftLeft | OperatorEnum::ShiftRight => {
+                let shift = match cast_num(&right_val, &SymbolType::U64) {
+                    Some(U64(shift)) => shift as u32,
+                    _ => unreachable!(),
+                };
+                let left = self.bitwise_kind == OperatorEnum::ShiftLeft;
+
+                match left_val {
+                    Num(n) if left => Num(n.wrapping_shl(shift)),
+                    Num(n) => Num(n.wrapping_shr(shift)),
+                    I64(n) if left => I64(n.wrapping_shl(shift)),
+                    I64(n) => I64(n.wrapping_shr(shift)),
+                    U64(n) if left => U64(n.wrapping_shl(shift)),
+                    U64(n) => U64(n.wrapping_shr(shift)),
+                    U8(n) if left => U8(n.wrapping_shl(shift)),
+                    U8(n) => U8(n.wrapping_shr(shift)),
+                    _ => unreachable!(),
+                }
+            }
+
+            _ => {
+                let (left_val, right_val) = widen_values(&left_val, &right_val)
+                    .ok_or_else(|| CrocoError::bitwise_error(&self.code_pos))?;
+
+                match (left_val, right_val, &self.bitwise_kind) {
+                    (Num(n1), Num(n2), OperatorEnum::BitwiseAnd) => Num(n1 & n2),
+                    (Num(n1), Num(n2), OperatorEnum::BitwiseOr) => Num(n1 | n2),
+                    (Num(n1), Num(n2), OperatorEnum::BitwiseXor) => Num(n1 ^ n2),
+                    (I64(n1), I64(n2), OperatorEnum::BitwiseAnd) => I64(n1 & n2),
+                    (I64(n1), I64(n2), OperatorEnum::BitwiseOr) => I64(n1 | n2),
+                    (I64(n1), I64(n2), OperatorEnum::BitwiseXor) => I64(n1 ^ n2),
+                    (U64(n1), U64(n2), OperatorEnum::BitwiseAnd) => U64(n1 & n2),
+                    (U64(n1), U64(n2), OperatorEnum::BitwiseOr) => U64(n1 | n2),
+                    (U64(n1), U64(n2), OperatorEnum::BitwiseXor) => U64(n1 ^ n2),
+                    (U8(n1), U8(n2), OperatorEnum::BitwiseAnd) => U8(n1 & n2),
+                    (U8(n1), U8(n2), OperatorEnum::BitwiseOr) => U8(n1 | n2),
+                    (U8(n1), U8(n2), OperatorEnum::BitwiseXor) => U8(n1 ^ n2),
+                    _ => unreachable!(),
+                }
+            }
+        };
+
+        Ok(INodeResult::Value(ISymbol::Primitive(value)))
+    }
+}
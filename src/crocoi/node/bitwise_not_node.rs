@@ -0,0 +1,21 @@
+use crate::crocoi::INodeResult;
+use crate::crocoi::{utils::get_value, CrocoiNode};
+use crate::{ast::node::BitwiseNotNode, error::CrocoError};
+
+use crate::{
+    crocoi::{ICodegen, ISymbol},
+    token::LiteralEnum::*,
+};
+
+impl CrocoiNode for BitwiseNotNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let value = match get_value(&mut self.bottom, codegen, &self.code_pos)? {
+            Num(n) => Num(!n),
+            I64(n) => I64(!n),
+            U64(n) => U64(!n),
+            U8(n) => U8(!n),
+            _ => return Err(CrocoError::bitwise_not_error(&self.code_pos)),
+        };
+        Ok(INodeResult::Value(ISymbol::Primitive(value)))
+    }
+}
@@ -0,0 +1,64 @@
+use crate::ast::node::{CatchHandler, CatchNode};
+use crate::crocoi::{
+    symbol::get_symbol_type, utils::auto_deref, CrocoiNode, ICodegen, INodeResult, ISymbol,
+};
+use crate::error::CrocoError;
+use crate::token::LiteralEnum;
+use std::{cell::RefCell, rc::Rc};
+
+impl CrocoiNode for CatchNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let code_pos = &self.code_pos;
+
+        let value = self.bottom.crocoi(codegen)?.into_symbol(code_pos)?;
+        let result = match auto_deref(value) {
+            ISymbol::Result(result) => result,
+            _ => return Err(CrocoError::result_error(code_pos)),
+        };
+
+        let message = match result.value {
+            Ok(Some(value)) => return Ok(INodeResult::Value(*value)),
+            Ok(None) => return Ok(INodeResult::Void),
+            Err(message) => message,
+        };
+
+        match &mut self.handler {
+            CatchHandler::Fallback(fallback) => {
+                let fallback = fallback.crocoi(codegen)?.into_symbol(code_pos)?;
+
+                match &result.ok_type {
+                    Some(ok_type) if **ok_type == get_symbol_type(&fallback) => {
+                        Ok(INodeResult::Value(fallback))
+                    }
+                    ok_type => Err(CrocoError::fallback_type_error(
+                        code_pos,
+                        ok_type.as_deref(),
+                    )),
+                }
+            }
+
+            CatchHandler::Block { var_name, body } => {
+                // the error message lives in its own scope, around the body scope
+                codegen.symtable.add_scope();
+                codegen
+                    .symtable
+                    .insert_symbol(
+                        var_name,
+                        Rc::new(RefCell::new(ISymbol::Primitive(LiteralEnum::Str(message)))),
+                    )
+                    .map_err(|e| CrocoError::new(code_pos, e))?;
+
+                let value = body.crocoi(codegen)?;
+                codegen.symtable.drop_scope();
+
+                // the catch block only gives a value when it exits early
+                match value {
+                    INodeResult::Return(_) | INodeResult::Break | INodeResult::Continue => {
+                        Ok(value)
+                    }
+                    _ => Ok(INodeResult::Void),
+                }
+            }
+        }
+    }
+}
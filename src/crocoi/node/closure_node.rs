@@ -0,0 +1,21 @@
+use crate::crocoi::CrocoiNode;
+use crate::{ast::node::ClosureNode, error::CrocoError};
+
+use crate::crocoi::{
+    symbol::{Closure, Function},
+    ICodegen, INodeResult, ISymbol,
+};
+
+impl CrocoiNode for ClosureNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        // the captured variables are shared, so the closure sees their later changes
+        let closure = Closure {
+            fn_decl: self.fn_decl.clone(),
+            body: Function::Regular(self.fn_body.clone()),
+            captures: codegen.symtable.get_visible_symbols(),
+            code_pos: self.code_pos.clone(),
+        };
+
+        Ok(INodeResult::Value(ISymbol::Function(closure)))
+    }
+}
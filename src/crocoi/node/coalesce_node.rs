@@ -0,0 +1,45 @@
+use crate::ast::node::CoalesceNode;
+use crate::crocoi::{
+    symbol::get_symbol_type,
+    utils::{auto_deref, coerce_to_type},
+    CrocoiNode, ICodegen, INodeResult, ISymbol,
+};
+use crate::error::CrocoError;
+
+impl CrocoiNode for CoalesceNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let optional = self
+            .left
+            .as_mut()
+            .unwrap()
+            .crocoi(codegen)?
+            .into_symbol(&self.code_pos)?;
+
+        let optional = match auto_deref(optional) {
+            ISymbol::Optional(optional) => optional,
+            _ => return Err(CrocoError::optional_error(&self.code_pos)),
+        };
+
+        // the default value is only evaluated if the optional is empty
+        if let Some(value) = optional.value {
+            return Ok(INodeResult::Value(*value));
+        }
+
+        let default = self
+            .right
+            .as_mut()
+            .unwrap()
+            .crocoi(codegen)?
+            .into_symbol(&self.code_pos)?;
+        let default = coerce_to_type(default, &optional.some_type, codegen, &self.code_pos)?;
+
+        if get_symbol_type(&default) != *optional.some_type {
+            return Err(CrocoError::default_type_error(
+                &self.code_pos,
+                &optional.some_type,
+            ));
+        }
+
+        Ok(INodeResult::Value(default))
+    }
+}
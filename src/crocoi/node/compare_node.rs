@@ -2,12 +2,58 @@ use crate::token::{LiteralEnum::*, OperatorEnum};
 use crate::{ast::node::CompareNode, error::CrocoError};
 use crate::{crocoi::CrocoiNode, token::literal_eq};
 
-use crate::crocoi::{utils::get_value, ICodegen, INodeResult, ISymbol};
+use crate::crocoi::{
+    symbol::get_symbol_type,
+    utils::{symbols_eq, widen_values},
+    ICodegen, INodeResult, ISymbol,
+};
 
 impl CrocoiNode for CompareNode {
     fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
-        let left_val = get_value(&mut self.left, codegen, &self.code_pos)?;
-        let right_val = get_value(&mut self.right, codegen, &self.code_pos)?;
+        let left_val = self
+            .left
+            .as_mut()
+            .unwrap()
+            .crocoi(codegen)?
+            .into_symbol(&self.code_pos)?;
+
+        let right_val = self
+            .right
+            .as_mut()
+            .unwrap()
+            .crocoi(codegen)?
+            .into_symbol(&self.code_pos)?;
+
+        let is_equality = self.compare_kind == OperatorEnum::Equals
+            || self.compare_kind == OperatorEnum::NotEquals;
+
+        let (left_val, right_val) = match (left_val, right_val) {
+            (ISymbol::Primitive(left_val), ISymbol::Primitive(right_val)) => (left_val, right_val),
+
+            // arrays and structs are compared deeply
+            (left_val, right_val) => {
+                let left_type = get_symbol_type(&left_val);
+
+                if left_type != get_symbol_type(&right_val) {
+                    return Err(CrocoError::compare_different_types_error(&self.code_pos));
+                }
+
+                if !is_equality {
+                    return Err(CrocoError::compare_numbers_only_error(&self.code_pos));
+                }
+
+                let is_equal = symbols_eq(&left_val, &right_val).ok_or_else(|| {
+                    CrocoError::compare_unsupported_type_error(&self.code_pos, &left_type)
+                })?;
+
+                let value = is_equal == (self.compare_kind == OperatorEnum::Equals);
+                return Ok(INodeResult::Value(ISymbol::Primitive(Bool(value))));
+            }
+        };
+
+        // numbers of different sizes are compared once widened to the same type
+        let (left_val, right_val) =
+            widen_values(&left_val, &right_val).unwrap_or((left_val, right_val));
 
         // make sure we can compare our values
         // that is, if they are both a (f)num, or if they are of the same type
@@ -17,10 +63,8 @@ impl CrocoiNode for CompareNode {
             _ => return Err(CrocoError::compare_different_types_error(&self.code_pos)),
         }
 
-        if (self.compare_kind != OperatorEnum::Equals
-            && self.compare_kind != OperatorEnum::NotEquals)
-            && !left_val.is_num_fnum()
-        {
+        // numbers and strs can be ordered, strs lexicographically
+        if !is_equality && !left_val.is_num_fnum() && !matches!(left_val, Str(_)) {
             return Err(CrocoError::compare_numbers_only_error(&self.code_pos));
         }
 
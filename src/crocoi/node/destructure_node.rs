@@ -0,0 +1,44 @@
+use crate::ast::node::DestructureNode;
+use crate::crocoi::{
+    symbol::get_symbol_type, utils::auto_deref, CrocoiNode, ICodegen, INodeResult, ISymbol,
+};
+use crate::error::CrocoError;
+use std::{cell::RefCell, rc::Rc};
+
+impl CrocoiNode for DestructureNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let value = self.right.crocoi(codegen)?;
+
+        // a catch block can exit early instead of giving a value
+        if let INodeResult::Return(_) | INodeResult::Break | INodeResult::Continue = value {
+            return Ok(value);
+        }
+
+        let value = auto_deref(value.into_symbol(&self.code_pos)?);
+
+        let elements = match value {
+            ISymbol::Tuple(elements) if elements.len() == self.var_names.len() => elements,
+            value => {
+                return Err(CrocoError::destructure_error(
+                    &self.code_pos,
+                    &get_symbol_type(&value),
+                    self.var_names.len(),
+                ))
+            }
+        };
+
+        for (var_name, el) in self.var_names.iter().zip(elements) {
+            if let Some(var_name) = var_name {
+                // the variables are copies of the tuple elements
+                let el = el.borrow().clone();
+
+                codegen
+                    .symtable
+                    .insert_symbol(var_name, Rc::new(RefCell::new(el)))
+                    .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+            }
+        }
+
+        Ok(INodeResult::Void)
+    }
+}
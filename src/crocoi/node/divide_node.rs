@@ -1,4 +1,7 @@
-use crate::crocoi::{utils::get_value, CrocoiNode, INodeResult};
+use crate::crocoi::{
+    utils::{get_value, widen_values},
+    CrocoiNode, INodeResult,
+};
 use crate::error::CrocoError;
 use crate::token::LiteralEnum::*;
 use crate::{
@@ -8,12 +11,16 @@ use crate::{
 
 impl CrocoiNode for DivideNode {
     fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
-        let value = match (
-            get_value(&mut self.left, codegen, &self.code_pos)?,
-            get_value(&mut self.right, codegen, &self.code_pos)?,
-        ) {
-            (Fnum(f1), Fnum(f2)) => Fnum(f1 / f2),
-            (Num(n1), Num(n2)) => Num(n1 / n2),
+        let left_val = get_value(&mut self.left, codegen, &self.code_pos)?;
+        let right_val = get_value(&mut self.right, codegen, &self.code_pos)?;
+
+        let value = match widen_values(&left_val, &right_val) {
+            Some((Num(n1), Num(n2))) => Num(n1.wrapping_div(n2)),
+            Some((I64(n1), I64(n2))) => I64(n1.wrapping_div(n2)),
+            Some((U64(n1), U64(n2))) => U64(n1.wrapping_div(n2)),
+            Some((U8(n1), U8(n2))) => U8(n1.wrapping_div(n2)),
+            Some((Fnum(f1), Fnum(f2))) => Fnum(f1 / f2),
+            Some((F64(f1), F64(f2))) => F64(f1 / f2),
             _ => return Err(CrocoError::divide_error(&self.code_pos)),
         };
         Ok(INodeResult::Value(ISymbol::Primitive(value)))
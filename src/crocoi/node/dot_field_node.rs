@@ -1,5 +1,6 @@
-use crate::crocoi::{utils::auto_deref, ICodegen, INodeResult, ISymbol};
+use crate::crocoi::{symbol::OptionalValue, utils::auto_deref, ICodegen, INodeResult, ISymbol};
 use crate::error::CrocoError;
+use crate::symbol_type::SymbolType;
 use crate::token::LiteralEnum;
 use crate::{ast::node::DotFieldNode, crocoi::CrocoiNode};
 
@@ -14,6 +15,10 @@ impl CrocoiNode for DotFieldNode {
 
         symbol = auto_deref(symbol);
 
+        if self.optional {
+            return self.optional_field(symbol, codegen);
+        }
+
         let value = match &symbol {
             // access a struct field
             ISymbol::Struct(s) => s
@@ -22,6 +27,33 @@ impl CrocoiNode for DotFieldNode {
                 .ok_or_else(|| CrocoError::no_field_error(&self.field_name, &self.code_pos))?
                 .clone(),
 
+            // access a tuple element
+            ISymbol::Tuple(elements) => self
+                .field_name
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| elements.get(index))
+                .ok_or_else(|| CrocoError::no_field_error(&self.field_name, &self.code_pos))?
+                .clone(),
+
+            // the struct behind a trait is only reachable through the trait methods
+            ISymbol::Trait(t) => {
+                return Err(CrocoError::new(
+                    &self.code_pos,
+                    format!(
+                        "can't access the field {} through the trait {}",
+                        self.field_name, t.trait_type
+                    ),
+                ))
+            }
+
+            ISymbol::Optional(_) => {
+                return Err(CrocoError::optional_field_error(
+                    &self.code_pos,
+                    &self.field_name,
+                ))
+            }
+
             // str fields
             ISymbol::Primitive(LiteralEnum::Str(_s)) => {
                 todo!();
@@ -48,3 +80,54 @@ impl CrocoiNode for DotFieldNode {
         Ok(INodeResult::Variable(value))
     }
 }
+
+impl DotFieldNode {
+    /// Accesses a field through an optional struct, e.g node?.next
+    fn optional_field(
+        &self,
+        symbol: ISymbol,
+        codegen: &mut ICodegen,
+    ) -> Result<INodeResult, CrocoError> {
+        let optional = match symbol {
+            ISymbol::Optional(optional) => optional,
+            _ => return Err(CrocoError::optional_error(&self.code_pos)),
+        };
+
+        let struct_type = match &*optional.some_type {
+            SymbolType::Struct(struct_type) => struct_type,
+            _ => return Err(CrocoError::no_field_error(&self.field_name, &self.code_pos)),
+        };
+
+        let field_type = codegen
+            .symtable
+            .get_struct_decl(struct_type)
+            .map_err(|e| CrocoError::new(&self.code_pos, e))?
+            .fields
+            .get(&self.field_name)
+            .ok_or_else(|| CrocoError::no_field_error(&self.field_name, &self.code_pos))?
+            .clone();
+
+        let value = match optional.value {
+            Some(value) => match *value {
+                ISymbol::Struct(s) => Some(s.fields[&self.field_name].borrow().clone()),
+                _ => unreachable!(),
+            },
+            None => None,
+        };
+
+        // an optional field isn't wrapped in another optional
+        let field = match (field_type, value) {
+            (SymbolType::Optional(_), Some(value)) => value,
+            (SymbolType::Optional(some_type), None) => ISymbol::Optional(OptionalValue {
+                value: None,
+                some_type,
+            }),
+            (field_type, value) => ISymbol::Optional(OptionalValue {
+                value: value.map(Box::new),
+                some_type: Box::new(field_type),
+            }),
+        };
+
+        Ok(INodeResult::Value(field))
+    }
+}
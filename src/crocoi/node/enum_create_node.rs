@@ -0,0 +1,51 @@
+use crate::crocoi::CrocoiNode;
+use crate::{ast::node::EnumCreateNode, error::CrocoError};
+
+use crate::crocoi::{
+    symbol::{get_symbol_type, EnumValue},
+    utils::coerce_to_type,
+    ICodegen, INodeResult, ISymbol,
+};
+
+impl CrocoiNode for EnumCreateNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let enum_decl = codegen
+            .symtable
+            .get_enum_decl(&self.enum_type)
+            .map_err(|e| CrocoError::new(&self.code_pos, e))?
+            .clone();
+
+        let (_, payload_types) = enum_decl.get_variant(&self.variant).ok_or_else(|| {
+            CrocoError::no_variant_error(&self.code_pos, &self.enum_type, &self.variant)
+        })?;
+
+        if payload_types.len() != self.payload.len() {
+            return Err(CrocoError::variant_payload_error(
+                &self.code_pos,
+                &self.variant,
+            ));
+        }
+
+        let mut payload = Vec::with_capacity(self.payload.len());
+
+        for (value, value_type) in self.payload.iter_mut().zip(payload_types) {
+            let value = value.crocoi(codegen)?.into_symbol(&self.code_pos)?;
+            let value = coerce_to_type(value, value_type, codegen, &self.code_pos)?;
+
+            if get_symbol_type(&value) != *value_type {
+                return Err(CrocoError::variant_payload_error(
+                    &self.code_pos,
+                    &self.variant,
+                ));
+            }
+
+            payload.push(value);
+        }
+
+        Ok(INodeResult::Value(ISymbol::Enum(EnumValue {
+            enum_type: self.enum_type.clone(),
+            variant: self.variant.clone(),
+            payload,
+        })))
+    }
+}
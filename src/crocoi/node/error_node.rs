@@ -0,0 +1,17 @@
+use crate::ast::node::ErrorNode;
+use crate::crocoi::{utils::auto_deref, CrocoiNode, ICodegen, INodeResult, ISymbol};
+use crate::error::CrocoError;
+use crate::token::LiteralEnum;
+
+impl CrocoiNode for ErrorNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let message = self.message.crocoi(codegen)?.into_symbol(&self.code_pos)?;
+
+        match auto_deref(message) {
+            ISymbol::Primitive(LiteralEnum::Str(message)) => {
+                Ok(INodeResult::Value(ISymbol::Error(message)))
+            }
+            _ => Err(CrocoError::error_message_error(&self.code_pos)),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::ast::node::{ForIterable, ForNode};
+use crate::crocoi::{utils::auto_deref, CrocoiNode, ICodegen, INodeResult, ISymbol};
+use crate::error::CrocoError;
+use crate::token::LiteralEnum::*;
+use std::{cell::RefCell, rc::Rc};
+
+impl CrocoiNode for ForNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let code_pos = &self.code_pos;
+
+        // all the values taken by the loop variable
+        let values: Box<dyn Iterator<Item = ISymbol>> = match &mut self.iterable {
+            ForIterable::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                let start = start
+                    .crocoi(codegen)?
+                    .into_symbol(code_pos)?
+                    .into_primitive()
+                    .and_then(|primitive| primitive.into_num())
+                    .map_err(|_| CrocoError::range_error(code_pos))?;
+
+                let end = end
+                    .crocoi(codegen)?
+                    .into_symbol(code_pos)?
+                    .into_primitive()
+                    .and_then(|primitive| primitive.into_num())
+                    .map_err(|_| CrocoError::range_error(code_pos))?;
+
+                if *inclusive {
+                    Box::new((start..=end).map(|n| ISymbol::Primitive(Num(n))))
+                } else {
+                    Box::new((start..end).map(|n| ISymbol::Primitive(Num(n))))
+                }
+            }
+
+            ForIterable::Array(array) => {
+                let array_symbol = array.crocoi(codegen)?.into_symbol(code_pos)?;
+
+                let array = auto_deref(array_symbol)
+                    .into_array()
+                    .map_err(|_| CrocoError::iterable_error(code_pos))?;
+
+                // each element is copied into the loop variable
+                Box::new(array.contents.into_iter().map(|el| {
+                    let value = el.borrow().clone();
+                    value
+                }))
+            }
+        };
+
+        for value in values {
+            // the loop variable lives in its own scope, around the body scope
+            codegen.symtable.add_scope();
+            codegen
+                .symtable
+                .insert_symbol(&self.var_name, Rc::new(RefCell::new(value)))
+                .map_err(|e| CrocoError::new(code_pos, e))?;
+
+            let value = self.body.crocoi(codegen)?;
+            codegen.symtable.drop_scope();
+
+            match value {
+                // propagate the early-return
+                INodeResult::Return(_) => return Ok(value),
+                INodeResult::Break => break,
+                _ => (),
+            }
+        }
+
+        Ok(INodeResult::Void)
+    }
+}
@@ -1,19 +1,44 @@
-use crate::crocoi::INodeResult;
-use crate::token::LiteralEnum;
+use crate::crocoi::{stdlib::self_element_type, INodeResult};
+use crate::parser::TypedArg;
+use crate::symbol_type::SymbolType;
+use crate::token::{CodePos, LiteralEnum};
 use crate::{ast::node::*, crocoi::CrocoiNode};
-use crate::{crocoi::symbol::Function, error::CrocoError};
+use crate::{
+    crocoi::symbol::Function,
+    error::{CrocoError, CrocoErrorKind},
+};
 
-use crate::crocoi::{self, symbol::get_symbol_type, ICodegen, ISymbol};
+use crate::crocoi::{
+    self,
+    symbol::{get_symbol_type, Array, MapKey, ResultValue},
+    utils::{auto_deref, coerce_to_type},
+    ICodegen, ISymbol,
+};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 impl CrocoiNode for FunctionCallNode {
     fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
-        let fn_decl;
+        let mut fn_decl;
         let fn_code;
+        let mut captures = HashMap::new();
+        // the errors of a function value body are reported where the function value was created
+        let mut body_pos = self.code_pos.clone();
         let mut visited_args = Vec::with_capacity(self.args.len());
 
         // if we're dealing with a method, inject self as the first argument
         if let Some(method_self) = self.method.as_mut() {
-            let method_symbol = method_self.crocoi(codegen)?.into_var_ref(&self.code_pos)?;
+            let mut method_symbol = method_self.crocoi(codegen)?.into_var_ref(&self.code_pos)?;
+
+            // the signatures of the map methods depend on the map types, so they're not declared
+            let map_ref = method_symbol.get_ref();
+            if matches!(&*map_ref.borrow(), ISymbol::Map(_)) {
+                let mut args = Vec::with_capacity(self.args.len());
+                for arg in &mut self.args {
+                    args.push(arg.crocoi(codegen)?.into_symbol(&self.code_pos)?);
+                }
+
+                return call_map_method(&self.fn_name, map_ref, args, &self.code_pos);
+            }
 
             let err_closure = || {
                 CrocoError::new(
@@ -22,12 +47,29 @@ impl CrocoiNode for FunctionCallNode {
                 )
             };
 
+            // dispatch a trait method to the method of the underlying struct
+            let trait_object = match &*method_symbol.get_ref().borrow() {
+                ISymbol::Trait(t) => Some(t.clone()),
+                _ => None,
+            };
+
+            if let Some(trait_object) = trait_object {
+                codegen
+                    .symtable
+                    .get_trait_decl(&trait_object.trait_type)
+                    .map_err(|e| CrocoError::new(&self.code_pos, e))?
+                    .methods
+                    .get(&self.fn_name)
+                    .ok_or_else(err_closure)?;
+
+                method_symbol = ISymbol::Ref(trait_object.value);
+            }
+
             let fn_name = match &*method_symbol.get_ref().borrow() {
                 ISymbol::Struct(s) => format!("_{}_{}", s.struct_type, self.fn_name),
-                ISymbol::Primitive(LiteralEnum::Str(_)) => format!("_str_{}", &self.fn_name),
-                ISymbol::Primitive(LiteralEnum::Fnum(_)) => format!("_fnum_{}", &self.fn_name),
-                ISymbol::Primitive(LiteralEnum::Num(_)) => format!("_num_{}", &self.fn_name),
-                ISymbol::Primitive(LiteralEnum::Bool(_)) => format!("_bool_{}", &self.fn_name),
+                ISymbol::Primitive(primitive) => {
+                    format!("_{}_{}", primitive.get_symbol_type(), &self.fn_name)
+                }
                 ISymbol::Array(_) => format!("_array_{}", &self.fn_name),
                 _ => unreachable!(),
             };
@@ -40,18 +82,54 @@ impl CrocoiNode for FunctionCallNode {
 
             fn_code = codegen.functions.get(&fn_name).unwrap().clone();
 
+            // the builtin methods don't declare their self argument.
+            // the array methods are generic over the element type of self
+            if let Function::Builtin(_) = fn_code {
+                let self_type = match get_symbol_type(&method_symbol) {
+                    SymbolType::Ref(self_type) if matches!(*self_type, SymbolType::Array(_)) => {
+                        SymbolType::Ref(Box::new(SymbolType::Array(Box::new(self_element_type()))))
+                    }
+                    self_type => self_type,
+                };
+
+                fn_decl.args.insert(
+                    0,
+                    TypedArg {
+                        arg_name: String::new(),
+                        arg_type: self_type,
+                    },
+                );
+            }
+
             // insert the self symbol in the args
             visited_args.push(method_symbol);
 
         // this is just a regular function
         } else {
-            fn_decl = codegen
+            // a variable holding a function value shadows the named functions
+            let fn_value = codegen
                 .symtable
-                .get_function_decl(&self.fn_name)
-                .map_err(|e| CrocoError::new(&self.code_pos, e))?
-                .clone();
+                .get_symbol(&self.fn_name)
+                .ok()
+                .map(|symbol| auto_deref(symbol.borrow().clone()));
 
-            fn_code = codegen.functions.get(&self.fn_name).unwrap().clone();
+            if let Some(ISymbol::Function(closure)) = fn_value {
+                fn_decl = closure.fn_decl;
+                fn_code = closure.body;
+                captures = closure.captures;
+                body_pos = closure.code_pos;
+            } else {
+                fn_decl = codegen
+                    .symtable
+                    .get_function_decl(&self.fn_name)
+                    .map_err(|e| match fn_value {
+                        Some(_) => CrocoError::not_callable_error(&self.code_pos, &self.fn_name),
+                        None => CrocoError::new(&self.code_pos, e),
+                    })?
+                    .clone();
+
+                fn_code = codegen.functions.get(&self.fn_name).unwrap().clone();
+            }
         }
 
         // resolve the function arguments
@@ -60,6 +138,15 @@ impl CrocoiNode for FunctionCallNode {
             visited_args.push(value);
         }
 
+        // the trailing optional arguments can be omitted, and are then none
+        while let Some(TypedArg {
+            arg_type: SymbolType::Optional(_),
+            ..
+        }) = fn_decl.args.get(visited_args.len())
+        {
+            visited_args.push(ISymbol::None);
+        }
+
         // ensure that the arguments provided and the arguments in the function call match
         if visited_args.len() != fn_decl.args.len() {
             return Err(CrocoError::mismatched_number_of_arguments_error(
@@ -69,8 +156,14 @@ impl CrocoiNode for FunctionCallNode {
             ));
         }
 
-        for (i, arg) in visited_args.iter().enumerate() {
-            if get_symbol_type(arg) != fn_decl.args[i].arg_type {
+        // the type parameters are bound to the types of the arguments
+        let mut generics = HashMap::new();
+
+        for (i, arg) in visited_args.iter_mut().enumerate() {
+            let arg_type = &fn_decl.args[i].arg_type;
+            *arg = coerce_to_type(arg.clone(), arg_type, codegen, &self.code_pos)?;
+
+            if !arg_type.bind_generics(&get_symbol_type(arg), &mut generics) {
                 return Err(CrocoError::parameter_error(
                     &self.code_pos,
                     i,
@@ -79,9 +172,21 @@ impl CrocoiNode for FunctionCallNode {
             }
         }
 
+        if fn_decl.is_generic() {
+            fn_decl = fn_decl.resolve_generics(&generics);
+        }
+
         // clear the variables when evaluating the function
         let old_symbols = codegen.symtable.pop_symbols();
 
+        // the type parameters are visible in the function body
+        let old_generics = std::mem::replace(&mut codegen.generics, generics);
+
+        // the variables captured by a closure are visible in its body
+        for (name, symbol) in captures {
+            codegen.symtable.insert_symbol(&name, symbol).unwrap();
+        }
+
         let return_value;
 
         match fn_code {
@@ -102,27 +207,51 @@ impl CrocoiNode for FunctionCallNode {
                     )));
                 }
 
-                return_value = match body.crocoi(codegen)? {
-                    INodeResult::Return(ret) => ret,
-                    INodeResult::Break => {
-                        return Err(CrocoError::break_in_function_error(&self.code_pos))
+                return_value = match body.crocoi(codegen) {
+                    Ok(INodeResult::Return(ret)) => ret,
+                    Ok(INodeResult::Break) => {
+                        return Err(CrocoError::break_in_function_error(&body_pos))
                     }
-                    INodeResult::Continue => {
-                        return Err(CrocoError::continue_in_function_error(&self.code_pos));
+                    Ok(INodeResult::Continue) => {
+                        return Err(CrocoError::continue_in_function_error(&body_pos));
                     }
-                    INodeResult::Value(val) => Some(val),
-                    INodeResult::Variable(var) => Some(var.borrow().clone()),
+                    Ok(INodeResult::Value(val)) => Some(val),
+                    Ok(INodeResult::Variable(var)) => Some(var.borrow().clone()),
                     // this must be void if it's returned by a block node
-                    INodeResult::Void => None,
+                    Ok(INodeResult::Void) => None,
+
+                    // an error propagated in the body becomes the error of the returned result
+                    Err(e) if e.is_propagated() => match &fn_decl.return_type {
+                        Some(SymbolType::Result(_)) => Some(ISymbol::Error(e.message)),
+                        _ => {
+                            let mut e = e;
+                            e.set_kind(CrocoErrorKind::Runtime);
+                            return Err(e);
+                        }
+                    },
+                    Err(e) => return Err(e),
                 }
             }
 
             Function::Builtin(callback) => {
-                return_value = callback(visited_args);
+                return_value =
+                    callback(visited_args).map_err(|e| CrocoError::new(&self.code_pos, e))?;
             }
         }
 
         // make sure the return value matches the function declaration
+        let return_value = match (return_value, &fn_decl.return_type) {
+            (Some(value), Some(fn_ty)) => {
+                Some(coerce_to_type(value, fn_ty, codegen, &self.code_pos)?)
+            }
+            // a function returning an empty result succeeds when it doesn't return anything
+            (None, Some(SymbolType::Result(None))) => Some(ISymbol::Result(ResultValue {
+                value: Ok(None),
+                ok_type: None,
+            })),
+            (value, _) => value,
+        };
+
         let ret_ty_opt = return_value.as_ref().map(|x| get_symbol_type(x));
         match (&fn_decl.return_type, &ret_ty_opt) {
             (None, None) => (),
@@ -130,7 +259,7 @@ impl CrocoiNode for FunctionCallNode {
                 return Err(CrocoError::wrong_return(
                     fn_decl.return_type.as_ref(),
                     ret_ty_opt.as_ref(),
-                    &self.code_pos,
+                    &body_pos,
                 ))
             }
             (Some(_), Some(_)) => (),
@@ -138,13 +267,14 @@ impl CrocoiNode for FunctionCallNode {
                 return Err(CrocoError::wrong_return(
                     fn_decl.return_type.as_ref(),
                     ret_ty_opt.as_ref(),
-                    &self.code_pos,
+                    &body_pos,
                 ))
             }
         }
 
         // get back the old variables
         codegen.symtable.push_symbols(old_symbols);
+        codegen.generics = old_generics;
 
         match return_value {
             None => Ok(INodeResult::Void),
@@ -152,3 +282,81 @@ impl CrocoiNode for FunctionCallNode {
         }
     }
 }
+
+/// Calls one of the builtin map methods
+fn call_map_method(
+    fn_name: &str,
+    map_ref: Rc<RefCell<ISymbol>>,
+    args: Vec<ISymbol>,
+    code_pos: &CodePos,
+) -> Result<INodeResult, CrocoError> {
+    let mut map_borrow = map_ref.borrow_mut();
+
+    let map = match &mut *map_borrow {
+        ISymbol::Map(map) => map,
+        _ => unreachable!(),
+    };
+
+    let args_len = match fn_name {
+        "len" | "keys" | "values" => 0,
+        "contains" | "remove" => 1,
+        _ => {
+            return Err(CrocoError::new(
+                code_pos,
+                &format!("no method called {}", fn_name),
+            ))
+        }
+    };
+
+    if args.len() != args_len {
+        return Err(CrocoError::mismatched_number_of_arguments_error(
+            code_pos,
+            args_len,
+            args.len(),
+        ));
+    }
+
+    // the only argument a map method can take is a key
+    let key = match args.into_iter().next() {
+        Some(arg) if get_symbol_type(&arg) == *map.key_type => {
+            Some(MapKey::from_symbol(arg).map_err(|e| CrocoError::new(code_pos, e))?)
+        }
+        Some(_) => return Err(CrocoError::parameter_error(code_pos, 1, true)),
+        None => None,
+    };
+
+    let value = match fn_name {
+        "len" => ISymbol::Primitive(LiteralEnum::Num(map.keys.len() as i32)),
+
+        "contains" => {
+            ISymbol::Primitive(LiteralEnum::Bool(map.contents.contains_key(&key.unwrap())))
+        }
+
+        "remove" => {
+            map.remove(&key.unwrap());
+            return Ok(INodeResult::Void);
+        }
+
+        "keys" => ISymbol::Array(Array {
+            contents: map
+                .keys
+                .iter()
+                .map(|key| Rc::new(RefCell::new(key.clone().into_symbol())))
+                .collect(),
+            array_type: map.key_type.clone(),
+        }),
+
+        "values" => ISymbol::Array(Array {
+            contents: map
+                .keys
+                .iter()
+                .map(|key| Rc::new(RefCell::new(map.contents[key].borrow().clone())))
+                .collect(),
+            array_type: map.value_type.clone(),
+        }),
+
+        _ => unreachable!(),
+    };
+
+    Ok(INodeResult::Value(value))
+}
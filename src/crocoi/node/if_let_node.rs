@@ -0,0 +1,41 @@
+use crate::ast::node::IfLetNode;
+use crate::crocoi::{utils::auto_deref, CrocoiNode, ICodegen, INodeResult, ISymbol};
+use crate::error::CrocoError;
+use std::{cell::RefCell, rc::Rc};
+
+impl CrocoiNode for IfLetNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let code_pos = &self.code_pos;
+
+        let optional = self.value.crocoi(codegen)?.into_symbol(code_pos)?;
+        let optional = match auto_deref(optional) {
+            ISymbol::Optional(optional) => optional,
+            _ => return Err(CrocoError::optional_error(code_pos)),
+        };
+
+        let value = match (optional.value, &mut self.else_body) {
+            // the unwrapped value lives in its own scope, around the body scope
+            (Some(value), _) => {
+                codegen.symtable.add_scope();
+                codegen
+                    .symtable
+                    .insert_symbol(&self.var_name, Rc::new(RefCell::new(*value)))
+                    .map_err(|e| CrocoError::new(code_pos, e))?;
+
+                let value = self.body.crocoi(codegen)?;
+                codegen.symtable.drop_scope();
+                value
+            }
+
+            (None, Some(else_body)) => else_body.crocoi(codegen)?,
+
+            (None, None) => INodeResult::Void,
+        };
+
+        match value {
+            // propagate the early-return
+            INodeResult::Return(_) | INodeResult::Break | INodeResult::Continue => Ok(value),
+            _ => Ok(INodeResult::Void),
+        }
+    }
+}
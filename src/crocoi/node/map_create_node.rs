@@ -0,0 +1,47 @@
+use crate::ast::{node::MapCreateNode, NodeResult};
+use crate::{crocoi::CrocoiNode, error::CrocoError};
+
+use {
+    crate::crocoi::{
+        symbol::{get_symbol_type, Map, MapKey},
+        ICodegen, INodeResult, ISymbol,
+    },
+    std::collections::HashMap,
+};
+
+impl CrocoiNode for MapCreateNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        // visit all the map entries
+        let mut visited = Vec::with_capacity(self.contents.len());
+
+        for (key, value) in &mut self.contents {
+            let key = key.crocoi(codegen)?.into_symbol(&self.code_pos)?;
+            let value = value.crocoi(codegen)?.into_symbol(&self.code_pos)?;
+            visited.push((key, value));
+        }
+
+        // infer the map types from the first entry
+        let key_type = get_symbol_type(&visited[0].0);
+        let value_type = get_symbol_type(&visited[0].1);
+
+        let mut map = Map {
+            contents: HashMap::with_capacity(visited.len()),
+            keys: Vec::with_capacity(visited.len()),
+            key_type: Box::new(key_type),
+            value_type: Box::new(value_type),
+        };
+
+        // make sure all entries are of the same types
+        for (key, value) in visited.into_iter() {
+            if get_symbol_type(&key) != *map.key_type || get_symbol_type(&value) != *map.value_type
+            {
+                return Err(CrocoError::mixed_type_map(&self.code_pos));
+            }
+
+            let key = MapKey::from_symbol(key).map_err(|e| CrocoError::new(&self.code_pos, e))?;
+            map.insert(key, value);
+        }
+
+        Ok(NodeResult::Value(ISymbol::Map(map)))
+    }
+}
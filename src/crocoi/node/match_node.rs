@@ -0,0 +1,150 @@
+use crate::ast::node::{MatchNode, MatchPattern};
+use crate::crocoi::{utils::auto_deref, CrocoiNode, ICodegen, INodeResult, ISymbol};
+use crate::error::CrocoError;
+use crate::token::{literal_eq, CodePos, LiteralEnum};
+use std::{cell::RefCell, rc::Rc};
+
+impl CrocoiNode for MatchNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let code_pos = &self.code_pos;
+
+        let value = self.bottom.crocoi(codegen)?.into_symbol(code_pos)?;
+        let value = auto_deref(value);
+
+        match &value {
+            ISymbol::Primitive(_) => (),
+
+            // the variant patterns must exist and all the variants must be handled
+            ISymbol::Enum(enum_value) => {
+                let enum_decl = codegen
+                    .symtable
+                    .get_enum_decl(&enum_value.enum_type)
+                    .map_err(|e| CrocoError::new(code_pos, e))?;
+
+                for pattern in self.arms.iter().flat_map(|arm| arm.patterns.iter()) {
+                    if let MatchPattern::Variant {
+                        enum_type,
+                        variant,
+                        bindings,
+                    } = pattern
+                    {
+                        if *enum_type != enum_value.enum_type {
+                            return Err(CrocoError::match_pattern_error(code_pos));
+                        }
+
+                        let (_, payload_types) =
+                            enum_decl.get_variant(variant).ok_or_else(|| {
+                                CrocoError::no_variant_error(code_pos, enum_type, variant)
+                            })?;
+
+                        if !bindings.is_empty() && bindings.len() != payload_types.len() {
+                            return Err(CrocoError::variant_payload_error(code_pos, variant));
+                        }
+                    }
+                }
+
+                let missing = self.missing_variants(enum_decl);
+                if !missing.is_empty() {
+                    return Err(CrocoError::non_exhaustive_match_error(code_pos, &missing));
+                }
+            }
+
+            _ => return Err(CrocoError::match_pattern_error(code_pos)),
+        }
+
+        for arm in &mut self.arms {
+            let mut matched = None;
+
+            for pattern in &arm.patterns {
+                if pattern_matches(pattern, &value, code_pos)? {
+                    matched = Some(pattern);
+                    break;
+                }
+            }
+
+            if let Some(pattern) = matched {
+                // the values held by the variant live in their own scope, around the arm body
+                codegen.symtable.add_scope();
+
+                if let (MatchPattern::Variant { bindings, .. }, ISymbol::Enum(enum_value)) =
+                    (pattern, &value)
+                {
+                    for (binding, payload) in bindings.iter().zip(enum_value.payload.iter()) {
+                        if let Some(var_name) = binding {
+                            codegen
+                                .symtable
+                                .insert_symbol(var_name, Rc::new(RefCell::new(payload.clone())))
+                                .map_err(|e| CrocoError::new(code_pos, e))?;
+                        }
+                    }
+                }
+
+                let value = arm.body.crocoi(codegen)?;
+                codegen.symtable.drop_scope();
+
+                // we don't want to keep a reference to the variable in an arm
+                return match value {
+                    INodeResult::Variable(_) => {
+                        Ok(INodeResult::Value(value.into_symbol(code_pos)?))
+                    }
+                    _ => Ok(value),
+                };
+            }
+        }
+
+        Ok(INodeResult::Void)
+    }
+}
+
+/// Checks if a value matches the pattern of an arm
+fn pattern_matches(
+    pattern: &MatchPattern,
+    value: &ISymbol,
+    code_pos: &CodePos,
+) -> Result<bool, CrocoError> {
+    match pattern {
+        MatchPattern::Wildcard => Ok(true),
+
+        MatchPattern::Literal(literal) => {
+            let value = as_primitive(value, code_pos)?;
+
+            if !literal_eq(literal, value) {
+                return Err(CrocoError::match_pattern_error(code_pos));
+            }
+
+            Ok(literal == value)
+        }
+
+        MatchPattern::Range {
+            start,
+            end,
+            inclusive,
+        } => {
+            let value = as_primitive(value, code_pos)?;
+
+            if !literal_eq(start, value) {
+                return Err(CrocoError::match_pattern_error(code_pos));
+            }
+
+            let below_end = if *inclusive {
+                value <= end
+            } else {
+                value < end
+            };
+            Ok(value >= start && below_end)
+        }
+
+        MatchPattern::Variant { variant, .. } => match value {
+            ISymbol::Enum(enum_value) => Ok(enum_value.variant == *variant),
+            _ => Err(CrocoError::match_pattern_error(code_pos)),
+        },
+    }
+}
+
+/// Gets the primitive matched by a literal pattern
+fn as_primitive<'a>(value: &'a ISymbol, code_pos: &CodePos) -> Result<&'a LiteralEnum, CrocoError> {
+    match value {
+        ISymbol::Primitive(primitive) => Ok(primitive),
+        _ => Err(CrocoError::match_pattern_error(code_pos)),
+    }
+}
@@ -0,0 +1,33 @@
+use crate::crocoi::{
+    utils::{get_value, widen_values},
+    CrocoiNode, INodeResult,
+};
+use crate::error::CrocoError;
+use crate::token::LiteralEnum::*;
+use crate::{
+    ast::node::ModuloNode,
+    crocoi::{ICodegen, ISymbol},
+};
+
+impl CrocoiNode for ModuloNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let left_val = get_value(&mut self.left, codegen, &self.code_pos)?;
+        let right_val = get_value(&mut self.right, codegen, &self.code_pos)?;
+
+        let value = match widen_values(&left_val, &right_val) {
+            // integers can't be divided by zero
+            Some((Num(_), Num(0)))
+            | Some((I64(_), I64(0)))
+            | Some((U64(_), U64(0)))
+            | Some((U8(_), U8(0))) => return Err(CrocoError::modulo_by_zero_error(&self.code_pos)),
+            Some((Num(n1), Num(n2))) => Num(n1.wrapping_rem(n2)),
+            Some((I64(n1), I64(n2))) => I64(n1.wrapping_rem(n2)),
+            Some((U64(n1), U64(n2))) => U64(n1.wrapping_rem(n2)),
+            Some((U8(n1), U8(n2))) => U8(n1.wrapping_rem(n2)),
+            Some((Fnum(f1), Fnum(f2))) => Fnum(f1 % f2),
+            Some((F64(f1), F64(f2))) => F64(f1 % f2),
+            _ => return Err(CrocoError::modulo_error(&self.code_pos)),
+        };
+        Ok(INodeResult::Value(ISymbol::Primitive(value)))
+    }
+}
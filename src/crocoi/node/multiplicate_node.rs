@@ -1,6 +1,9 @@
 use crate::{
     ast::node::MultiplicateNode,
-    crocoi::{utils::get_value, CrocoiNode, ICodegen, INodeResult, ISymbol},
+    crocoi::{
+        utils::{get_value, widen_values},
+        CrocoiNode, ICodegen, INodeResult, ISymbol,
+    },
 };
 
 use crate::error::CrocoError;
@@ -9,12 +12,16 @@ use crate::token::LiteralEnum::*;
 impl CrocoiNode for MultiplicateNode {
     #[cfg(feature = "crocoi")]
     fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
-        let value = match (
-            get_value(&mut self.left, codegen, &self.code_pos)?,
-            get_value(&mut self.right, codegen, &self.code_pos)?,
-        ) {
-            (Fnum(f1), Fnum(f2)) => Fnum(f1 * f2),
-            (Num(n1), Num(n2)) => Num(n1 * n2),
+        let left_val = get_value(&mut self.left, codegen, &self.code_pos)?;
+        let right_val = get_value(&mut self.right, codegen, &self.code_pos)?;
+
+        let value = match widen_values(&left_val, &right_val) {
+            Some((Num(n1), Num(n2))) => Num(n1.wrapping_mul(n2)),
+            Some((I64(n1), I64(n2))) => I64(n1.wrapping_mul(n2)),
+            Some((U64(n1), U64(n2))) => U64(n1.wrapping_mul(n2)),
+            Some((U8(n1), U8(n2))) => U8(n1.wrapping_mul(n2)),
+            Some((Fnum(f1), Fnum(f2))) => Fnum(f1 * f2),
+            Some((F64(f1), F64(f2))) => F64(f1 * f2),
             _ => return Err(CrocoError::multiplicate_error(&self.code_pos)),
         };
         Ok(INodeResult::Value(ISymbol::Primitive(value)))
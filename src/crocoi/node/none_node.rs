@@ -0,0 +1,9 @@
+use crate::ast::node::NoneNode;
+use crate::crocoi::{CrocoiNode, ICodegen, INodeResult, ISymbol};
+use crate::error::CrocoError;
+
+impl CrocoiNode for NoneNode {
+    fn crocoi(&mut self, _codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        Ok(INodeResult::Value(ISymbol::None))
+    }
+}
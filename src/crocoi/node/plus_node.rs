@@ -1,5 +1,9 @@
 use crate::ast::node::PlusNode;
-use crate::crocoi::{symbol::ISymbol, utils::get_value, CrocoiNode, ICodegen, INodeResult};
+use crate::crocoi::{
+    symbol::ISymbol,
+    utils::{get_value, widen_values},
+    CrocoiNode, ICodegen, INodeResult,
+};
 use crate::error::CrocoError;
 use crate::token::LiteralEnum::*;
 
@@ -10,10 +14,16 @@ impl CrocoiNode for PlusNode {
 
         // different kinds of additions can happen (concatenation or number addition)
         let value = match (left_val, right_val) {
-            (Num(n1), Num(n2)) => Num(n1 + n2),
-            (Fnum(n1), Fnum(n2)) => Fnum(n1 + n2),
             (Str(s1), Str(s2)) => Str(format!("{}{}", s1, s2)),
-            _ => return Err(CrocoError::add_error(&self.code_pos)),
+            (left_val, right_val) => match widen_values(&left_val, &right_val) {
+                Some((Num(n1), Num(n2))) => Num(n1.wrapping_add(n2)),
+                Some((I64(n1), I64(n2))) => I64(n1.wrapping_add(n2)),
+                Some((U64(n1), U64(n2))) => U64(n1.wrapping_add(n2)),
+                Some((U8(n1), U8(n2))) => U8(n1.wrapping_add(n2)),
+                Some((Fnum(n1), Fnum(n2))) => Fnum(n1 + n2),
+                Some((F64(n1), F64(n2))) => F64(n1 + n2),
+                _ => return Err(CrocoError::add_error(&self.code_pos)),
+            },
         };
         Ok(INodeResult::Value(ISymbol::Primitive(value)))
     }
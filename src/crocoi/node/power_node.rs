@@ -1,4 +1,7 @@
-use crate::crocoi::{utils::get_value, CrocoiNode};
+use crate::crocoi::{
+    utils::{get_value, widen_values},
+    CrocoiNode,
+};
 use crate::error::CrocoError;
 use crate::token::LiteralEnum::*;
 use crate::{
@@ -8,15 +11,42 @@ use crate::{
 
 impl CrocoiNode for PowerNode {
     fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
-        let value = match (
-            get_value(&mut self.left, codegen, &self.code_pos)?,
-            get_value(&mut self.right, codegen, &self.code_pos)?,
-        ) {
-            (Fnum(f1), Fnum(f2)) => Fnum(f1.powf(f2)),
-            // TODO: handle panic ?
-            (Num(n1), Num(n2)) => Num(n1.pow(n2 as u32)),
+        let left_val = get_value(&mut self.left, codegen, &self.code_pos)?;
+        let right_val = get_value(&mut self.right, codegen, &self.code_pos)?;
+
+        let value = match widen_values(&left_val, &right_val) {
+            Some((Fnum(f1), Fnum(f2))) => Fnum(f1.powf(f2)),
+            Some((F64(f1), F64(f2))) => F64(f1.powf(f2)),
+            // integers can't be powered to a negative exponent
+            Some((Num(_), Num(n2))) if n2 < 0 => {
+                return Err(CrocoError::negative_exponent_error(&self.code_pos))
+            }
+            Some((I64(_), I64(n2))) if n2 < 0 => {
+                return Err(CrocoError::negative_exponent_error(&self.code_pos))
+            }
+            Some((Num(n1), Num(n2))) => Num(wrapping_pow(n1, n2 as u64, 1, i32::wrapping_mul)),
+            Some((I64(n1), I64(n2))) => I64(wrapping_pow(n1, n2 as u64, 1, i64::wrapping_mul)),
+            Some((U64(n1), U64(n2))) => U64(wrapping_pow(n1, n2, 1, u64::wrapping_mul)),
+            Some((U8(n1), U8(n2))) => U8(wrapping_pow(n1, n2 as u64, 1, u8::wrapping_mul)),
             _ => return Err(CrocoError::power_error(&self.code_pos)),
         };
         Ok(INodeResult::Value(ISymbol::Primitive(value)))
     }
 }
+
+/// Powers an integer by squaring, wrapping around on overflow like in crocol.
+/// The whole exponent is used, even when it doesn't fit in an u32
+fn wrapping_pow<T: Copy>(mut base: T, mut exp: u64, one: T, mul: fn(T, T) -> T) -> T {
+    let mut result = one;
+
+    while exp != 0 {
+        if exp & 1 == 1 {
+            result = mul(result, base);
+        }
+
+        base = mul(base, base);
+        exp >>= 1;
+    }
+
+    result
+}
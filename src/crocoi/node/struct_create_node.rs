@@ -3,7 +3,10 @@ use crate::{ast::node::StructCreateNode, error::CrocoError};
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use crate::crocoi::{
-    symbol::get_symbol_type, symbol::Struct, utils::init_default, ICodegen, INodeResult, ISymbol,
+    symbol::get_symbol_type,
+    symbol::Struct,
+    utils::{coerce_to_type, init_default},
+    ICodegen, INodeResult, ISymbol,
 };
 
 impl CrocoiNode for StructCreateNode {
@@ -36,7 +39,9 @@ impl CrocoiNode for StructCreateNode {
 
                 // the field is present, visit it
                 Some(field) => {
-                    let field_val = field.crocoi(codegen)?.into_value(&self.code_pos)?;
+                    let field_val = field.crocoi(codegen)?.into_symbol(&self.code_pos)?;
+                    let field_val =
+                        coerce_to_type(field_val, &field_decl.1, codegen, &self.code_pos)?;
 
                     if field_decl.1 != get_symbol_type(&field_val) {
                         return Err(CrocoError::field_type_error(&field_decl.0, &self.code_pos));
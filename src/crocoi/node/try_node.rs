@@ -0,0 +1,24 @@
+use crate::ast::node::TryNode;
+use crate::crocoi::{utils::auto_deref, CrocoiNode, ICodegen, INodeResult, ISymbol};
+use crate::error::CrocoError;
+
+impl CrocoiNode for TryNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let value = self
+            .bottom
+            .as_mut()
+            .unwrap()
+            .crocoi(codegen)?
+            .into_symbol(&self.code_pos)?;
+
+        // the error unwinds the interpreter until a function returning a result is found
+        match auto_deref(value) {
+            ISymbol::Result(result) => match result.value {
+                Ok(Some(value)) => Ok(INodeResult::Value(*value)),
+                Ok(None) => Ok(INodeResult::Void),
+                Err(message) => Err(CrocoError::propagated_error(&self.code_pos, &message)),
+            },
+            _ => Err(CrocoError::result_error(&self.code_pos)),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::ast::node::TupleCreateNode;
+use crate::crocoi::{CrocoiNode, ICodegen, INodeResult, ISymbol};
+use crate::error::CrocoError;
+use std::{cell::RefCell, rc::Rc};
+
+impl CrocoiNode for TupleCreateNode {
+    fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        let mut elements = Vec::with_capacity(self.elements.len());
+
+        for el in &mut self.elements {
+            let el = el.crocoi(codegen)?.into_symbol(&self.code_pos)?;
+            elements.push(Rc::new(RefCell::new(el)));
+        }
+
+        Ok(INodeResult::Value(ISymbol::Tuple(elements)))
+    }
+}
@@ -10,8 +10,11 @@ use crate::{
 impl CrocoiNode for UnaryMinusNode {
     fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
         let value = match get_value(&mut self.bottom, codegen, &self.code_pos)? {
-            Num(n) => Num(-n),
+            Num(n) => Num(n.wrapping_neg()),
+            I64(n) => I64(n.wrapping_neg()),
             Fnum(f) => Fnum(-f),
+            F64(f) => F64(-f),
+            // unsigned numbers can't be negated
             _ => return Err(CrocoError::unary_minus_error(&self.code_pos)),
         };
         Ok(INodeResult::Value(ISymbol::Primitive(value)))
@@ -1,15 +1,36 @@
 use crate::crocoi::CrocoiNode;
 use crate::{ast::node::VarCallNode, error::CrocoError};
 
-use crate::crocoi::{ICodegen, INodeResult};
+use crate::crocoi::{symbol::Closure, ICodegen, INodeResult, ISymbol};
+use std::collections::HashMap;
 
 impl CrocoiNode for VarCallNode {
     fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
-        let symbol = codegen
-            .symtable
-            .get_symbol(&self.name)
-            .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+        let err = match codegen.symtable.get_symbol(&self.name) {
+            Ok(symbol) => return Ok(INodeResult::Variable(symbol.clone())),
+            Err(e) => e,
+        };
 
-        Ok(INodeResult::Variable(symbol.clone()))
+        // a named function can also be used as a value
+        let fn_decl = match codegen.symtable.get_function_decl(&self.name) {
+            Ok(fn_decl) => fn_decl.clone(),
+            Err(_) => return Err(CrocoError::new(&self.code_pos, &err)),
+        };
+
+        if fn_decl.is_generic() {
+            return Err(CrocoError::generic_fn_value_error(
+                &self.code_pos,
+                &self.name,
+            ));
+        }
+
+        let body = codegen.functions.get(&self.name).unwrap().clone();
+
+        Ok(INodeResult::Value(ISymbol::Function(Closure {
+            fn_decl,
+            body,
+            captures: HashMap::new(),
+            code_pos: self.code_pos.clone(),
+        })))
     }
 }
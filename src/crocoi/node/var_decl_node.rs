@@ -1,20 +1,39 @@
 use crate::{ast::node::VarDeclNode, crocoi::INodeResult};
 use crate::{crocoi::CrocoiNode, error::CrocoError};
 
-use crate::crocoi::{symbol::get_symbol_type, symbol::ICodegen, utils::init_default};
+use crate::crocoi::{
+    symbol::get_symbol_type, symbol::ICodegen, utils::coerce_to_type, utils::init_default, ISymbol,
+};
 use std::cell::RefCell;
 use std::rc::Rc;
 
 impl CrocoiNode for VarDeclNode {
     #[cfg(feature = "crocoi")]
     fn crocoi(&mut self, codegen: &mut ICodegen) -> Result<INodeResult, CrocoError> {
+        // in a generic function, the annotation can use the type parameters
+        let var_type = self
+            .var_type
+            .as_ref()
+            .map(|var_type| var_type.resolve_generics(&codegen.generics));
+
         let value = match &mut self.right {
             // there is a node
             Some(node) => {
-                let var_value = node.crocoi(codegen)?.into_symbol(&self.code_pos)?;
+                let var_value = node.crocoi(codegen)?;
+
+                // a catch block can exit early instead of giving a value
+                if let INodeResult::Return(_) | INodeResult::Break | INodeResult::Continue =
+                    var_value
+                {
+                    return Ok(var_value);
+                }
+
+                let mut var_value = var_value.into_symbol(&self.code_pos)?;
 
                 // type differs from annotation
-                if let Some(var_type) = &self.var_type {
+                if let Some(var_type) = &var_type {
+                    var_value = coerce_to_type(var_value, var_type, codegen, &self.code_pos)?;
+
                     if get_symbol_type(&var_value) != *var_type {
                         return Err(CrocoError::type_annotation_error(
                             &self.code_pos,
@@ -23,11 +42,16 @@ impl CrocoiNode for VarDeclNode {
                     }
                 }
 
+                // none alone doesn't tell which optional it is
+                if let ISymbol::None = var_value {
+                    return Err(CrocoError::infer_error(&self.code_pos, &self.left));
+                }
+
                 var_value
             }
 
             // no node, use the defaut value
-            None => match &self.var_type {
+            None => match &var_type {
                 None => return Err(CrocoError::infer_error(&self.code_pos, &self.left)),
 
                 Some(var_type) => init_default(var_type, codegen, &self.code_pos)?,
@@ -1,7 +1,10 @@
 #![allow(clippy::unnecessary_wraps)]
 
 use crate::crocoi::stdlib::*;
-use crate::crocoi::symbol::{ISymbol, ISymbol::*};
+use crate::crocoi::symbol::{
+    ISymbol,
+    ISymbol::{Error, Primitive},
+};
 use crate::symbol_type::SymbolType;
 use crate::token::LiteralEnum::*;
 
@@ -14,7 +17,7 @@ pub fn get_module() -> BuiltinModule {
         BuiltinFunction {
             name: "create_dir".to_owned(),
             args: vec![SymbolType::Str],
-            return_type: None,
+            return_type: Some(SymbolType::Result(None)),
             pointer: create_dir,
         },
         BuiltinFunction {
@@ -26,13 +29,13 @@ pub fn get_module() -> BuiltinModule {
         BuiltinFunction {
             name: "read_file".to_owned(),
             args: vec![SymbolType::Str],
-            return_type: Some(SymbolType::Str),
+            return_type: Some(SymbolType::Result(Some(Box::new(SymbolType::Str)))),
             pointer: read_file,
         },
         BuiltinFunction {
             name: "write_file".to_owned(),
             args: vec![SymbolType::Str, SymbolType::Str],
-            return_type: None,
+            return_type: Some(SymbolType::Result(None)),
             pointer: write_file,
         },
     ];
@@ -43,29 +46,35 @@ pub fn get_module() -> BuiltinModule {
 }
 
 /// create a directory at <path>, as well as all the needed parent directories
-fn create_dir(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn create_dir(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let path = get_arg_str(&mut args);
-    fs::create_dir_all(path).unwrap();
-    None
+    match fs::create_dir_all(path) {
+        Ok(()) => Ok(None),
+        Err(e) => Ok(Some(Error(e.to_string()))),
+    }
 }
 
 /// retuns true if <path> exists
-fn exists(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn exists(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let path = get_arg_str(&mut args);
-    Some(Primitive(Bool(Path::new(&path).exists())))
+    Ok(Some(Primitive(Bool(Path::new(&path).exists()))))
 }
 
 /// reads the content of the file at <path>
-fn read_file(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn read_file(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let path = get_arg_str(&mut args);
-    let contents = fs::read_to_string(path).unwrap_or_default();
-    Some(Primitive(Str(contents)))
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(Primitive(Str(contents)))),
+        Err(e) => Ok(Some(Error(e.to_string()))),
+    }
 }
 
 /// writes to <path> the <content> of a str
-fn write_file(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn write_file(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let path = get_arg_str(&mut args);
     let content = get_arg_str(&mut args);
-    fs::write(path, content).unwrap();
-    None
+    match fs::write(path, content) {
+        Ok(()) => Ok(None),
+        Err(e) => Ok(Some(Error(e.to_string()))),
+    }
 }
@@ -1,12 +1,16 @@
 #![allow(clippy::unnecessary_wraps)]
 
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{RefCell, RefMut},
+    rc::Rc,
+};
 
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::crocoi::stdlib::*;
 use crate::crocoi::symbol::Array;
 use crate::crocoi::symbol::ISymbol;
+use crate::crocoi::utils::symbols_eq;
 use crate::token::LiteralEnum::*;
 
 /// module definition
@@ -44,7 +48,30 @@ pub fn get_module() -> BuiltinModule {
             pointer: println,
         },
         // array methods
-        // since we don't have generics we can't yet implement push() and pop() :S
+        BuiltinFunction {
+            name: "_array_concat".to_owned(),
+            args: vec![SymbolType::Array(Box::new(self_element_type()))],
+            return_type: Some(SymbolType::Array(Box::new(self_element_type()))),
+            pointer: _array_concat,
+        },
+        BuiltinFunction {
+            name: "_array_contains".to_owned(),
+            args: vec![self_element_type()],
+            return_type: Some(SymbolType::Bool),
+            pointer: _array_contains,
+        },
+        BuiltinFunction {
+            name: "_array_index_of".to_owned(),
+            args: vec![self_element_type()],
+            return_type: Some(SymbolType::Num),
+            pointer: _array_index_of,
+        },
+        BuiltinFunction {
+            name: "_array_insert".to_owned(),
+            args: vec![SymbolType::Num, self_element_type()],
+            return_type: None,
+            pointer: _array_insert,
+        },
         BuiltinFunction {
             name: "_array_join".to_owned(),
             args: vec![SymbolType::Str],
@@ -54,9 +81,39 @@ pub fn get_module() -> BuiltinModule {
         BuiltinFunction {
             name: "_array_len".to_owned(),
             args: Vec::new(),
-            return_type: Some(SymbolType::Fnum),
+            return_type: Some(SymbolType::Num),
             pointer: _array_len,
         },
+        BuiltinFunction {
+            name: "_array_pop".to_owned(),
+            args: Vec::new(),
+            return_type: Some(self_element_type()),
+            pointer: _array_pop,
+        },
+        BuiltinFunction {
+            name: "_array_push".to_owned(),
+            args: vec![self_element_type()],
+            return_type: None,
+            pointer: _array_push,
+        },
+        BuiltinFunction {
+            name: "_array_remove".to_owned(),
+            args: vec![SymbolType::Num],
+            return_type: Some(self_element_type()),
+            pointer: _array_remove,
+        },
+        BuiltinFunction {
+            name: "_array_reverse".to_owned(),
+            args: Vec::new(),
+            return_type: None,
+            pointer: _array_reverse,
+        },
+        BuiltinFunction {
+            name: "_array_slice".to_owned(),
+            args: vec![SymbolType::Num, SymbolType::Num],
+            return_type: Some(SymbolType::Array(Box::new(self_element_type()))),
+            pointer: _array_slice,
+        },
         // fnum methods
         BuiltinFunction {
             name: "_fnum_times".to_owned(),
@@ -72,15 +129,63 @@ pub fn get_module() -> BuiltinModule {
             pointer: _num_times,
         },
         // str methods
+        BuiltinFunction {
+            name: "_str_chars".to_owned(),
+            args: Vec::new(),
+            return_type: Some(SymbolType::Array(Box::new(SymbolType::Str))),
+            pointer: _str_chars,
+        },
+        BuiltinFunction {
+            name: "_str_contains".to_owned(),
+            args: vec![SymbolType::Str],
+            return_type: Some(SymbolType::Bool),
+            pointer: _str_contains,
+        },
+        BuiltinFunction {
+            name: "_str_ends_with".to_owned(),
+            args: vec![SymbolType::Str],
+            return_type: Some(SymbolType::Bool),
+            pointer: _str_ends_with,
+        },
+        BuiltinFunction {
+            name: "_str_find".to_owned(),
+            args: vec![SymbolType::Str],
+            return_type: Some(SymbolType::Num),
+            pointer: _str_find,
+        },
         BuiltinFunction {
             name: "_str_len".to_owned(),
             args: Vec::new(),
             return_type: Some(SymbolType::Fnum),
             pointer: _str_len,
         },
+        BuiltinFunction {
+            name: "_str_parse_fnum".to_owned(),
+            args: Vec::new(),
+            return_type: Some(SymbolType::Result(Some(Box::new(SymbolType::Fnum)))),
+            pointer: _str_parse_fnum,
+        },
+        BuiltinFunction {
+            name: "_str_parse_num".to_owned(),
+            args: Vec::new(),
+            return_type: Some(SymbolType::Result(Some(Box::new(SymbolType::Num)))),
+            pointer: _str_parse_num,
+        },
+        BuiltinFunction {
+            name: "_str_repeat".to_owned(),
+            args: vec![SymbolType::Num],
+            return_type: Some(SymbolType::Str),
+            pointer: _str_repeat,
+        },
+        BuiltinFunction {
+            name: "_str_replace".to_owned(),
+            args: vec![SymbolType::Str, SymbolType::Str],
+            return_type: Some(SymbolType::Str),
+            pointer: _str_replace,
+        },
         BuiltinFunction {
             name: "_str_slice".to_owned(),
-            args: vec![SymbolType::Fnum, SymbolType::Fnum],
+            args: vec![SymbolType::Num, SymbolType::Num],
             return_type: Some(SymbolType::Str),
             pointer: _str_slice,
         },
@@ -90,6 +195,24 @@ pub fn get_module() -> BuiltinModule {
             return_type: Some(SymbolType::Array(Box::new(SymbolType::Str))),
             pointer: _str_split,
         },
+        BuiltinFunction {
+            name: "_str_starts_with".to_owned(),
+            args: vec![SymbolType::Str],
+            return_type: Some(SymbolType::Bool),
+            pointer: _str_starts_with,
+        },
+        BuiltinFunction {
+            name: "_str_to_lower".to_owned(),
+            args: Vec::new(),
+            return_type: Some(SymbolType::Str),
+            pointer: _str_to_lower,
+        },
+        BuiltinFunction {
+            name: "_str_to_upper".to_owned(),
+            args: Vec::new(),
+            return_type: Some(SymbolType::Str),
+            pointer: _str_to_upper,
+        },
         BuiltinFunction {
             name: "_str_trim".to_owned(),
             args: Vec::new(),
@@ -103,52 +226,131 @@ pub fn get_module() -> BuiltinModule {
     BuiltinModule { functions, vars }
 }
 
-/// Exits if the first argument is false
-fn assert(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+/// Fails if the first argument is false
+fn assert(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let assertion = get_arg_bool(&mut args);
 
     if !assertion {
-        eprintln!("Assertion failed!");
-        std::process::exit(1);
+        return Err("assertion failed".to_owned());
     }
-    None
+    Ok(None)
 }
 
 /// Prints to stderr the first argument
-fn eprint(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn eprint(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let err = get_arg_str(&mut args);
     eprint!("{}", err);
-    None
+    Ok(None)
 }
 
 /// Prints to stderr the first argument, with a line feed
-fn eprintln(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn eprintln(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let err = get_arg_str(&mut args);
     eprintln!("{}", err);
-    None
+    Ok(None)
 }
 
 /// Prints to stdout the first argument
-fn print(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn print(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let msg = get_arg_str(&mut args);
     print!("{}", msg);
-    None
+    Ok(None)
 }
 
 /// Prints to stdout the first argument, with a line feed
-fn println(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn println(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let msg = get_arg_str(&mut args);
     println!("{}", msg);
-    None
+    Ok(None)
 }
 
 /// Array methods
 
+/// Borrows mutably the array behind a reference, so that it can be modified in place
+fn borrow_array_mut(array_ref: &Rc<RefCell<ISymbol>>) -> RefMut<'_, Array> {
+    RefMut::map(array_ref.borrow_mut(), |symbol| match symbol {
+        ISymbol::Array(array) => array,
+        _ => unreachable!(),
+    })
+}
+
+/// Returns a new array with the elements of the array followed by the elements of another one
+fn _array_concat(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let array = get_arg_array(&mut args);
+    let other = get_arg_array(&mut args);
+
+    let contents = array
+        .contents
+        .iter()
+        .chain(other.contents.iter())
+        .map(|el| Rc::new(RefCell::new(el.borrow().clone())))
+        .collect();
+
+    Ok(Some(ISymbol::Array(Array {
+        contents,
+        array_type: array.array_type,
+    })))
+}
+
+/// Returns the index of the first element equal to the given value, or -1 if there's none
+fn array_index_of(array: &Array, value: &ISymbol) -> Result<i32, String> {
+    for (i, el) in array.contents.iter().enumerate() {
+        let is_equal = symbols_eq(&el.borrow(), value)
+            .ok_or_else(|| format!("cannot compare values of type {}", array.array_type))?;
+
+        if is_equal {
+            return Ok(i as i32);
+        }
+    }
+
+    Ok(-1)
+}
+
+/// Returns true if the array has an element equal to the given value
+fn _array_contains(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let array = get_arg_array(&mut args);
+    let value = args.remove(0);
+
+    let index = array_index_of(&array, &value)?;
+    Ok(Some(ISymbol::Primitive(Bool(index != -1))))
+}
+
+/// Returns the index of the first element equal to the given value, or -1 if there's none
+fn _array_index_of(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let array = get_arg_array(&mut args);
+    let value = args.remove(0);
+
+    let index = array_index_of(&array, &value)?;
+    Ok(Some(ISymbol::Primitive(Num(index))))
+}
+
+/// Inserts an element at the given index, shifting all the elements after it
+fn _array_insert(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let array_ref = get_arg_ref(&mut args);
+    let index = get_arg_num(&mut args);
+    let value = args.remove(0);
+
+    let mut array = borrow_array_mut(&array_ref);
+
+    if index < 0 || index as usize > array.contents.len() {
+        return Err("index out of bounds".to_owned());
+    }
+
+    array
+        .contents
+        .insert(index as usize, Rc::new(RefCell::new(value)));
+    Ok(None)
+}
+
 /// Joins a str array into a str
-// TODO checks that the array is an array of str
-fn _array_join(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn _array_join(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let array = get_arg_array(&mut args);
     let delimiter = get_arg_str(&mut args);
+
+    if *array.array_type != SymbolType::Str {
+        return Err("only an array of str can be joined".to_owned());
+    }
+
     let string = array
         .contents
         .iter()
@@ -163,19 +365,101 @@ fn _array_join(mut args: Vec<ISymbol>) -> Option<ISymbol> {
         .collect::<Vec<_>>()
         .join(&delimiter);
 
-    Some(ISymbol::Primitive(Str(string)))
+    Ok(Some(ISymbol::Primitive(Str(string))))
 }
 
 /// Returns the length of an array
-fn _array_len(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn _array_len(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let array = get_arg_array(&mut args);
-    Some(ISymbol::Primitive(Fnum(array.contents.len() as f32)))
+    Ok(Some(ISymbol::Primitive(Num(array.contents.len() as i32))))
+}
+
+/// Removes the last element of an array and returns it
+fn _array_pop(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let array_ref = get_arg_ref(&mut args);
+    let mut array = borrow_array_mut(&array_ref);
+
+    let last = array
+        .contents
+        .pop()
+        .ok_or_else(|| "cannot pop an empty array".to_owned())?;
+
+    let value = last.borrow().clone();
+    Ok(Some(value))
+}
+
+/// Appends an element at the end of an array
+fn _array_push(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let array_ref = get_arg_ref(&mut args);
+    let value = args.remove(0);
+
+    borrow_array_mut(&array_ref)
+        .contents
+        .push(Rc::new(RefCell::new(value)));
+    Ok(None)
+}
+
+/// Removes the element at the given index and returns it, shifting all the elements after it
+fn _array_remove(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let array_ref = get_arg_ref(&mut args);
+    let index = get_arg_num(&mut args);
+
+    let mut array = borrow_array_mut(&array_ref);
+
+    if index < 0 || index as usize >= array.contents.len() {
+        return Err("index out of bounds".to_owned());
+    }
+
+    let removed = array.contents.remove(index as usize);
+    let value = removed.borrow().clone();
+    Ok(Some(value))
+}
+
+/// Reverses the order of the elements of an array
+fn _array_reverse(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let array_ref = get_arg_ref(&mut args);
+    borrow_array_mut(&array_ref).contents.reverse();
+    Ok(None)
+}
+
+/// Returns a new array with the elements between a start index and an end index.  
+/// similar to JavaScript Array.prototype.slice  
+/// @see https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/slice
+fn _array_slice(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let array = get_arg_array(&mut args);
+    let len = array.contents.len() as isize;
+
+    // negative indexes start from the end of the array, and all indexes are clamped to it
+    let mut bounds = [0; 2];
+    for bound in bounds.iter_mut() {
+        let mut index = get_arg_num(&mut args) as isize;
+
+        if index < 0 {
+            index += len;
+        }
+
+        *bound = index.max(0).min(len) as usize;
+    }
+
+    let [start, end] = bounds;
+    let contents = array
+        .contents
+        .iter()
+        .take(end)
+        .skip(start)
+        .map(|el| Rc::new(RefCell::new(el.borrow().clone())))
+        .collect();
+
+    Ok(Some(ISymbol::Array(Array {
+        contents,
+        array_type: array.array_type,
+    })))
 }
 
 /// Fnum methods
 
 /// Returns an array containing `times` times the number
-fn _fnum_times(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn _fnum_times(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let fnum = get_arg_fnum(&mut args);
     let times = get_arg_fnum(&mut args) as usize;
 
@@ -184,16 +468,16 @@ fn _fnum_times(mut args: Vec<ISymbol>) -> Option<ISymbol> {
         arr.push(Rc::new(RefCell::new(ISymbol::Primitive(Fnum(fnum)))));
     }
 
-    Some(ISymbol::Array(Array {
+    Ok(Some(ISymbol::Array(Array {
         contents: arr,
         array_type: Box::new(SymbolType::Fnum),
-    }))
+    })))
 }
 
 /// Num methods
 
 /// Returns an array containing `times` times the number
-fn _num_times(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn _num_times(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let num = get_arg_num(&mut args);
     let times = get_arg_fnum(&mut args) as usize;
 
@@ -202,26 +486,129 @@ fn _num_times(mut args: Vec<ISymbol>) -> Option<ISymbol> {
         arr.push(Rc::new(RefCell::new(ISymbol::Primitive(Num(num)))));
     }
 
-    Some(ISymbol::Array(Array {
+    Ok(Some(ISymbol::Array(Array {
         contents: arr,
-        array_type: Box::new(SymbolType::Fnum),
-    }))
+        array_type: Box::new(SymbolType::Num),
+    })))
 }
 
 /// Str methods
 
+/// Returns the graphemes of a str
+fn _str_chars(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let string = get_arg_str(&mut args);
+    let chars = string
+        .graphemes(true)
+        .map(|x| Rc::new(RefCell::new(ISymbol::Primitive(Str(x.to_owned())))))
+        .collect();
+
+    Ok(Some(ISymbol::Array(Array {
+        array_type: Box::new(SymbolType::Str),
+        contents: chars,
+    })))
+}
+
+/// Returns the index in graphemes of the first occurence of a str, starting on a grapheme
+fn find_grapheme(string: &str, pattern: &str) -> Option<usize> {
+    string
+        .grapheme_indices(true)
+        .map(|(pos, _)| pos)
+        .chain(std::iter::once(string.len()))
+        .position(|pos| string[pos..].starts_with(pattern))
+}
+
+/// Returns true if the str contains the first arg, starting on a grapheme
+fn _str_contains(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let string = get_arg_str(&mut args);
+    let pattern = get_arg_str(&mut args);
+    Ok(Some(ISymbol::Primitive(Bool(
+        find_grapheme(&string, &pattern).is_some(),
+    ))))
+}
+
+/// Returns true if the str ends with the first arg
+fn _str_ends_with(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let string = get_arg_str(&mut args);
+    let pattern = get_arg_str(&mut args);
+    Ok(Some(ISymbol::Primitive(Bool(string.ends_with(&pattern)))))
+}
+
+/// Returns the index in graphemes of the first occurence of the first arg, or -1 if there's none
+fn _str_find(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let string = get_arg_str(&mut args);
+    let pattern = get_arg_str(&mut args);
+    let index = find_grapheme(&string, &pattern).map_or(-1, |index| index as i32);
+    Ok(Some(ISymbol::Primitive(Num(index))))
+}
+
 /// Returns the length of a str
-fn _str_len(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn _str_len(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let string = get_arg_str(&mut args);
+    Ok(Some(ISymbol::Primitive(Fnum(
+        string.graphemes(true).count() as f32,
+    ))))
+}
+
+/// Parses a str into a fnum, or returns an error if it isn't a decimal number
+fn _str_parse_fnum(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let string = get_arg_str(&mut args);
+
+    match string.parse::<f32>() {
+        Ok(fnum) => Ok(Some(ISymbol::Primitive(Fnum(fnum)))),
+        Err(_) => Ok(Some(ISymbol::Error(format!(
+            "cannot parse \"{}\" as a fnum",
+            string
+        )))),
+    }
+}
+
+/// Parses a str into a num, or returns an error if it isn't an integer which fits in a num
+fn _str_parse_num(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let string = get_arg_str(&mut args);
+
+    match string.parse::<i32>() {
+        Ok(num) => Ok(Some(ISymbol::Primitive(Num(num)))),
+        Err(_) => Ok(Some(ISymbol::Error(format!(
+            "cannot parse \"{}\" as a num",
+            string
+        )))),
+    }
+}
+
+/// Returns the str repeated a number of times, or an empty str if it's negative
+fn _str_repeat(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let string = get_arg_str(&mut args);
-    Some(ISymbol::Primitive(Fnum(
-        string.graphemes(true).count() as f32
-    )))
+    let times = get_arg_num(&mut args).max(0);
+    Ok(Some(ISymbol::Primitive(Str(string.repeat(times as usize)))))
+}
+
+/// Replaces all the occurences of the first arg by the second arg.  
+/// An empty pattern matches between each grapheme
+fn _str_replace(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let string = get_arg_str(&mut args);
+    let from = get_arg_str(&mut args);
+    let to = get_arg_str(&mut args);
+
+    let replaced = if from.is_empty() {
+        let mut replaced = to.clone();
+
+        for grapheme in string.graphemes(true) {
+            replaced.push_str(grapheme);
+            replaced.push_str(&to);
+        }
+
+        replaced
+    } else {
+        string.replace(&from, &to)
+    };
+
+    Ok(Some(ISymbol::Primitive(Str(replaced))))
 }
 
 /// Slice a str given a start offset and an end offset.  
 /// similar to JavaScript String.prototype.slice  
 /// @see https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/slice
-fn _str_slice(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn _str_slice(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let string = get_arg_str(&mut args);
     let mut start = get_arg_num(&mut args) as isize;
     let mut end = get_arg_num(&mut args) as isize;
@@ -246,26 +633,47 @@ fn _str_slice(mut args: Vec<ISymbol>) -> Option<ISymbol> {
     }
 
     let ret: String = graphemes.skip(start as usize).take(len as usize).collect();
-    Some(ISymbol::Primitive(Str(ret)))
+    Ok(Some(ISymbol::Primitive(Str(ret))))
 }
 
 /// Returns a str array splitted with the first arg as the separator
-fn _str_split(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn _str_split(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let string = get_arg_str(&mut args);
     let delimiter = get_arg_str(&mut args);
     let arr = string
         .split(&delimiter)
         .map(|x| Rc::new(RefCell::new(ISymbol::Primitive(Str(x.to_owned())))))
         .collect();
-    Some(ISymbol::Array(Array {
+    Ok(Some(ISymbol::Array(Array {
         array_type: Box::new(SymbolType::Str),
         contents: arr,
-    }))
+    })))
+}
+
+/// Returns true if the str starts with the first arg
+fn _str_starts_with(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let string = get_arg_str(&mut args);
+    let pattern = get_arg_str(&mut args);
+    Ok(Some(ISymbol::Primitive(Bool(string.starts_with(&pattern)))))
+}
+
+/// Returns the str in lowercase
+fn _str_to_lower(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    Ok(Some(ISymbol::Primitive(Str(
+        get_arg_str(&mut args).to_lowercase()
+    ))))
+}
+
+/// Returns the str in uppercase
+fn _str_to_upper(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    Ok(Some(ISymbol::Primitive(Str(
+        get_arg_str(&mut args).to_uppercase()
+    ))))
 }
 
 /// Returns the trimmed string
-fn _str_trim(mut args: Vec<ISymbol>) -> Option<ISymbol> {
-    Some(ISymbol::Primitive(Str(get_arg_str(&mut args)
+fn _str_trim(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    Ok(Some(ISymbol::Primitive(Str(get_arg_str(&mut args)
         .trim()
-        .to_owned())))
+        .to_owned()))))
 }
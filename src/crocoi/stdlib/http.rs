@@ -1,7 +1,10 @@
 #![allow(clippy::unnecessary_wraps)]
 
 use crate::crocoi::stdlib::*;
-use crate::crocoi::symbol::{ISymbol, ISymbol::*};
+use crate::crocoi::symbol::{
+    ISymbol,
+    ISymbol::{Error, Primitive},
+};
 use crate::token::LiteralEnum::*;
 
 // module definition
@@ -9,7 +12,7 @@ pub fn get_module() -> BuiltinModule {
     let functions = vec![BuiltinFunction {
         name: "get".to_owned(),
         args: vec![SymbolType::Str],
-        return_type: Some(SymbolType::Str),
+        return_type: Some(SymbolType::Result(Some(Box::new(SymbolType::Str)))),
         pointer: get,
     }];
 
@@ -19,16 +22,26 @@ pub fn get_module() -> BuiltinModule {
 }
 
 /// returns the contents of a page given an url
-fn get(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn get(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let url = get_arg_str(&mut args);
 
-    let res = ureq::get(&url).call().into_string();
+    let res = ureq::get(&url).call();
 
-    // return an empty string if we have an error
-    // TODO: implement errors
-    if let Ok(text) = res {
-        Some(Primitive(Str(text)))
-    } else {
-        Some(Primitive(Str(String::new())))
+    // ureq turns network failures into synthetic responses
+    if let Some(e) = res.synthetic_error() {
+        return Ok(Some(Error(e.to_string())));
+    }
+
+    if !res.ok() {
+        return Ok(Some(Error(format!(
+            "{} {}",
+            res.status(),
+            res.status_text()
+        ))));
+    }
+
+    match res.into_string() {
+        Ok(text) => Ok(Some(Primitive(Str(text)))),
+        Err(e) => Ok(Some(Error(e.to_string()))),
     }
 }
@@ -1,10 +1,70 @@
-use crate::crocoi::stdlib::{BuiltinModule, BuiltinVar};
-use crate::crocoi::symbol::ISymbol::*;
-use crate::token::LiteralEnum::*;
+#![allow(clippy::unnecessary_wraps)]
+
+use crate::crocoi::stdlib::*;
+use crate::crocoi::symbol::{get_symbol_type, ISymbol, ISymbol::Primitive, OptionalValue};
+use crate::token::{LiteralEnum, LiteralEnum::*};
+
+/// The functions of the math module taking fnums, with their number of arguments.
+/// They all return a fnum.
+const FUNCTIONS: [(&str, usize, BuiltinCallback); 15] = [
+    ("acos", 1, acos),
+    ("asin", 1, asin),
+    ("atan", 1, atan),
+    ("atan2", 2, atan2),
+    ("ceil", 1, ceil),
+    ("cos", 1, cos),
+    ("exp", 1, exp),
+    ("floor", 1, floor),
+    ("hypot", 2, hypot),
+    ("ln", 1, ln),
+    ("pow", 2, pow),
+    ("round", 1, round),
+    ("sin", 1, sin),
+    ("sqrt", 1, sqrt),
+    ("tan", 1, tan),
+];
+
+/// The functions of the math module taking numbers of any type, with their number of arguments.
+/// They return a number of the same type as their arguments.
+const NUMBER_FUNCTIONS: [(&str, usize, BuiltinCallback); 4] = [
+    ("abs", 1, abs),
+    ("clamp", 3, clamp),
+    ("max", 2, max),
+    ("min", 2, min),
+];
 
 // module definition
 pub fn get_module() -> BuiltinModule {
-    let functions = Vec::new();
+    let mut functions: Vec<BuiltinFunction> = FUNCTIONS
+        .iter()
+        .map(|(name, arg_count, pointer)| BuiltinFunction {
+            name: (*name).to_owned(),
+            args: vec![SymbolType::Fnum; *arg_count],
+            return_type: Some(SymbolType::Fnum),
+            pointer: *pointer,
+        })
+        .chain(
+            NUMBER_FUNCTIONS
+                .iter()
+                .map(|(name, arg_count, pointer)| BuiltinFunction {
+                    name: (*name).to_owned(),
+                    args: vec![SymbolType::Generic("T".to_owned()); *arg_count],
+                    return_type: Some(SymbolType::Generic("T".to_owned())),
+                    pointer: *pointer,
+                }),
+        )
+        .collect();
+
+    // the base of log is optional, and defaults to 10
+    functions.push(BuiltinFunction {
+        name: "log".to_owned(),
+        args: vec![
+            SymbolType::Fnum,
+            SymbolType::Optional(Box::new(SymbolType::Fnum)),
+        ],
+        return_type: Some(SymbolType::Fnum),
+        pointer: log,
+    });
 
     let vars = vec![
         BuiltinVar {
@@ -15,7 +75,194 @@ pub fn get_module() -> BuiltinModule {
             name: "e".to_owned(),
             value: Primitive(Fnum(std::f32::consts::E)),
         },
+        BuiltinVar {
+            name: "inf".to_owned(),
+            value: Primitive(Fnum(f32::INFINITY)),
+        },
+        BuiltinVar {
+            name: "nan".to_owned(),
+            value: Primitive(Fnum(f32::NAN)),
+        },
     ];
 
     BuiltinModule { functions, vars }
 }
+
+/// Returns a fnum result
+fn fnum_result(value: f32) -> Result<Option<ISymbol>, String> {
+    Ok(Some(Primitive(Fnum(value))))
+}
+
+/// Gets the arguments of a function taking numbers of any type.
+/// They all have the same type, which may not be a number
+fn get_number_args(fn_name: &str, args: Vec<ISymbol>) -> Result<Vec<LiteralEnum>, String> {
+    let numbers: Option<Vec<LiteralEnum>> = args
+        .iter()
+        .map(|arg| match arg {
+            Primitive(literal) if literal.is_num_fnum() => Some(literal.clone()),
+            _ => None,
+        })
+        .collect();
+
+    numbers.ok_or_else(|| {
+        let type_args: Vec<String> = args
+            .iter()
+            .map(|arg| get_symbol_type(arg).to_string())
+            .collect();
+
+        format!(
+            "function math.{} can't be called with arguments of type {}",
+            fn_name,
+            type_args.join(", ")
+        )
+    })
+}
+
+/// Returns the greatest or the smallest of two numbers of the same type.
+/// For floats, nan is ignored
+fn min_max(a: LiteralEnum, b: LiteralEnum, is_max: bool) -> LiteralEnum {
+    match (a, b) {
+        (Num(a), Num(b)) => Num(if is_max { a.max(b) } else { a.min(b) }),
+        (I64(a), I64(b)) => I64(if is_max { a.max(b) } else { a.min(b) }),
+        (U64(a), U64(b)) => U64(if is_max { a.max(b) } else { a.min(b) }),
+        (U8(a), U8(b)) => U8(if is_max { a.max(b) } else { a.min(b) }),
+        (Fnum(a), Fnum(b)) => Fnum(if is_max { a.max(b) } else { a.min(b) }),
+        (F64(a), F64(b)) => F64(if is_max { a.max(b) } else { a.min(b) }),
+        _ => unreachable!(),
+    }
+}
+
+/// returns the absolute value of a number.
+/// The smallest signed integers wrap around
+fn abs(args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let value = match get_number_args("abs", args)?.remove(0) {
+        Num(x) => Num(x.wrapping_abs()),
+        I64(x) => I64(x.wrapping_abs()),
+        Fnum(x) => Fnum(x.abs()),
+        F64(x) => F64(x.abs()),
+        unsigned => unsigned,
+    };
+
+    Ok(Some(Primitive(value)))
+}
+
+/// returns the arccosine of a fnum, in radians
+fn acos(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).acos())
+}
+
+/// returns the arcsine of a fnum, in radians
+fn asin(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).asin())
+}
+
+/// returns the arctangent of a fnum, in radians
+fn atan(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).atan())
+}
+
+/// returns the angle of the point (x, y), in radians.
+/// The y coordinate comes first
+fn atan2(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let y = get_arg_fnum(&mut args);
+    let x = get_arg_fnum(&mut args);
+    fnum_result(y.atan2(x))
+}
+
+/// returns the smallest integer greater than or equal to a fnum
+fn ceil(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).ceil())
+}
+
+/// restricts a number to a range.
+/// When min is greater than max, max is returned
+fn clamp(args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let mut args = get_number_args("clamp", args)?;
+    let x = args.remove(0);
+    let min = args.remove(0);
+    let max = args.remove(0);
+    Ok(Some(Primitive(min_max(min_max(x, min, true), max, false))))
+}
+
+/// returns the cosine of an angle in radians
+fn cos(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).cos())
+}
+
+/// returns e raised to the power of a fnum
+fn exp(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).exp())
+}
+
+/// returns the largest integer less than or equal to a fnum
+fn floor(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).floor())
+}
+
+/// returns the length of the hypotenuse of a right triangle
+fn hypot(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let x = get_arg_fnum(&mut args);
+    let y = get_arg_fnum(&mut args);
+    fnum_result(x.hypot(y))
+}
+
+/// returns the natural logarithm of a fnum
+fn ln(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).ln())
+}
+
+/// returns the logarithm of a fnum in the given base, or in base 10 if it's omitted
+fn log(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let x = get_arg_fnum(&mut args);
+    let base = match args.remove(0) {
+        ISymbol::Optional(OptionalValue {
+            value: Some(base), ..
+        }) => base.into_primitive().unwrap().into_fnum().unwrap(),
+        _ => 10.,
+    };
+    // computed the same way as in crocol
+    fnum_result(x.ln() / base.ln())
+}
+
+/// returns the greatest of two numbers, ignoring nan
+fn max(args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let mut args = get_number_args("max", args)?;
+    let a = args.remove(0);
+    let b = args.remove(0);
+    Ok(Some(Primitive(min_max(a, b, true))))
+}
+
+/// returns the smallest of two numbers, ignoring nan
+fn min(args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let mut args = get_number_args("min", args)?;
+    let a = args.remove(0);
+    let b = args.remove(0);
+    Ok(Some(Primitive(min_max(a, b, false))))
+}
+
+/// raises a fnum to a power
+fn pow(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    let base = get_arg_fnum(&mut args);
+    let exp = get_arg_fnum(&mut args);
+    fnum_result(base.powf(exp))
+}
+
+/// rounds a fnum to the nearest integer, away from zero on a tie
+fn round(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).round())
+}
+
+/// returns the sine of an angle in radians
+fn sin(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).sin())
+}
+
+/// returns the square root of a fnum, or nan if it is negative
+fn sqrt(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).sqrt())
+}
+
+/// returns the tangent of an angle in radians
+fn tan(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
+    fnum_result(get_arg_fnum(&mut args).tan())
+}
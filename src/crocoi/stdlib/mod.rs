@@ -19,9 +19,11 @@ use crate::{
     crocoi::symbol::Array, crocoi::symbol::ISymbol, crocoi::symbol::Struct,
     crocoi::utils::auto_deref, symbol_type::SymbolType,
 };
+use std::{cell::RefCell, rc::Rc};
 
-/// callback to a built-in function
-pub type BuiltinCallback = fn(Vec<ISymbol>) -> Option<ISymbol>;
+/// callback to a built-in function.
+/// An error message is returned if the function failed at runtime.
+pub type BuiltinCallback = fn(Vec<ISymbol>) -> Result<Option<ISymbol>, String>;
 
 /// representation of a built-in function
 pub struct BuiltinFunction {
@@ -31,6 +33,12 @@ pub struct BuiltinFunction {
     pub pointer: BuiltinCallback,
 }
 
+/// The type parameter bound to the element type of self, for the array methods.  
+/// e.g in [num].push(), the pushed value must be a num
+pub fn self_element_type() -> SymbolType {
+    SymbolType::Generic("T".to_owned())
+}
+
 pub struct BuiltinVar {
     pub name: String,
     pub value: ISymbol,
@@ -94,7 +102,11 @@ pub fn get_arg_bool(args: &mut Vec<ISymbol>) -> bool {
 }
 
 pub fn get_arg_array(args: &mut Vec<ISymbol>) -> Array {
-    args.remove(0).into_array().unwrap()
+    auto_deref(args.remove(0)).into_array().unwrap()
+}
+
+pub fn get_arg_ref(args: &mut Vec<ISymbol>) -> Rc<RefCell<ISymbol>> {
+    args.remove(0).into_ref().unwrap()
 }
 
 pub fn _get_arg_struct(args: &mut Vec<ISymbol>) -> Struct {
@@ -1,7 +1,10 @@
 #![allow(clippy::unnecessary_wraps)]
 
 use crate::crocoi::stdlib::*;
-use crate::crocoi::symbol::{ISymbol, ISymbol::*};
+use crate::crocoi::symbol::{
+    ISymbol,
+    ISymbol::{Error, Primitive},
+};
 use crate::token::LiteralEnum::*;
 
 use std::process::Command;
@@ -11,7 +14,7 @@ pub fn get_module() -> BuiltinModule {
     let functions = vec![BuiltinFunction {
         name: "exec".to_owned(),
         args: vec![SymbolType::Str],
-        return_type: Some(SymbolType::Str),
+        return_type: Some(SymbolType::Result(Some(Box::new(SymbolType::Str)))),
         pointer: exec,
     }];
 
@@ -21,7 +24,7 @@ pub fn get_module() -> BuiltinModule {
 }
 
 /// executes a system command
-fn exec(mut args: Vec<ISymbol>) -> Option<ISymbol> {
+fn exec(mut args: Vec<ISymbol>) -> Result<Option<ISymbol>, String> {
     let command_str = get_arg_str(&mut args);
 
     let command = if cfg!(windows) {
@@ -30,14 +33,24 @@ fn exec(mut args: Vec<ISymbol>) -> Option<ISymbol> {
         Command::new("sh").args(&["-c", &command_str]).output()
     };
 
-    // whenenever an error happens, we're just returning empty strings for the moment
-    // TODO: implement error types
-    // fn exec() !str
-    // !str is either ok(str) or err
-    if let Ok(output) = command {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        Some(Primitive(Str(stdout.into_owned())))
-    } else {
-        Some(Primitive(Str(String::new())))
+    match command {
+        Ok(output) if output.status.success() => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            Ok(Some(Primitive(Str(stdout.into_owned()))))
+        }
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            // the process has no exit code if it has been killed by a signal
+            let code = output
+                .status
+                .code()
+                .map_or_else(|| "none".to_owned(), |code| code.to_string());
+            Ok(Some(Error(format!(
+                "command exited with code {}: {}",
+                code,
+                stderr.trim_end()
+            ))))
+        }
+        Err(e) => Ok(Some(Error(e.to_string()))),
     }
 }
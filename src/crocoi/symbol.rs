@@ -25,6 +25,28 @@ pub struct Struct {
     pub struct_type: String,
 }
 
+/// Trait object representation in the crocoi backend
+#[derive(Clone, Debug)]
+pub struct TraitObject {
+    // the struct behind the trait, shared so that methods can mutate it
+    pub value: Rc<RefCell<ISymbol>>,
+
+    // the corresponding type of the trait, as a TraitDecl
+    pub trait_type: String,
+}
+
+/// Enum value representation in the crocoi backend
+#[derive(Clone, Debug)]
+pub struct EnumValue {
+    // the corresponding type of the enum, as an EnumDecl
+    pub enum_type: String,
+
+    pub variant: String,
+
+    // the values held by the variant
+    pub payload: Vec<ISymbol>,
+}
+
 /// Function representation in the crocoi backend
 #[derive(Clone)]
 pub enum Function {
@@ -32,14 +54,86 @@ pub enum Function {
     Builtin(BuiltinCallback),
 }
 
+/// Function value representation in the crocoi backend
+#[derive(Clone)]
+pub struct Closure {
+    pub fn_decl: FunctionDecl,
+    pub body: Function,
+
+    // the variables captured when the closure was created, shared with the enclosing scope
+    pub captures: HashMap<String, Rc<RefCell<ISymbol>>>,
+
+    // where the function value was created, where the errors of its body are reported
+    pub code_pos: CodePos,
+}
+
+impl fmt::Debug for Closure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.fn_decl.get_type())
+    }
+}
+
+/// Hashable representation of a map key in the crocoi backend
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MapKey {
+    Bool(bool),
+    Num(i32),
+    Str(String),
+}
+
+impl MapKey {
+    /// Converts a symbol into a map key, if its type can be hashed
+    pub fn from_symbol(symbol: ISymbol) -> Result<MapKey, &'static str> {
+        match symbol {
+            ISymbol::Primitive(LiteralEnum::Bool(b)) => Ok(MapKey::Bool(b)),
+            ISymbol::Primitive(LiteralEnum::Num(n)) => Ok(MapKey::Num(n)),
+            ISymbol::Primitive(LiteralEnum::Str(s)) => Ok(MapKey::Str(s)),
+            _ => Err("map keys can only be of type num, str or bool"),
+        }
+    }
+
+    /// Converts back a map key into a symbol
+    pub fn into_symbol(self) -> ISymbol {
+        match self {
+            MapKey::Bool(b) => ISymbol::Primitive(LiteralEnum::Bool(b)),
+            MapKey::Num(n) => ISymbol::Primitive(LiteralEnum::Num(n)),
+            MapKey::Str(s) => ISymbol::Primitive(LiteralEnum::Str(s)),
+        }
+    }
+}
+
 /// Map representation in the crocoi backend
 #[derive(Clone)]
 pub struct Map {
-    pub contents: HashMap<ISymbol, ISymbol>,
+    pub contents: HashMap<MapKey, Rc<RefCell<ISymbol>>>,
+    // the keys in insertion order, so that iterating over a map is deterministic
+    pub keys: Vec<MapKey>,
     pub key_type: Box<SymbolType>,
     pub value_type: Box<SymbolType>,
 }
 
+impl Map {
+    /// Inserts a value in the map, or replaces the existing one
+    pub fn insert(&mut self, key: MapKey, value: ISymbol) -> Rc<RefCell<ISymbol>> {
+        if let Some(existing) = self.contents.get(&key) {
+            *existing.borrow_mut() = value;
+            return existing.clone();
+        }
+
+        let value = Rc::new(RefCell::new(value));
+        self.keys.push(key.clone());
+        self.contents.insert(key, value.clone());
+        value
+    }
+
+    /// Removes a value from the map, if the key exists
+    pub fn remove(&mut self, key: &MapKey) {
+        if self.contents.remove(key).is_some() {
+            self.keys.retain(|k| k != key);
+        }
+    }
+}
+
 impl fmt::Debug for Map {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Map<{:?}, {:?}>", self.key_type, self.value_type)
@@ -53,6 +147,26 @@ pub struct Array {
     pub array_type: Box<SymbolType>,
 }
 
+/// Result representation in the crocoi backend
+#[derive(Clone, Debug)]
+pub struct ResultValue {
+    // the value if there's one, or the error message
+    pub value: Result<Option<Box<ISymbol>>, String>,
+
+    // the type of the value, as in !str
+    pub ok_type: Option<Box<SymbolType>>,
+}
+
+/// Optional representation in the crocoi backend
+#[derive(Clone, Debug)]
+pub struct OptionalValue {
+    // the value, if the optional isn't empty
+    pub value: Option<Box<ISymbol>>,
+
+    // the type of the value, as in ?Node
+    pub some_type: Box<SymbolType>,
+}
+
 #[derive(Clone, Debug)]
 /// the symbol contents
 pub enum ISymbol {
@@ -62,17 +176,39 @@ pub enum ISymbol {
     /// an array such as [1, 2, 3]
     Array(Array),
 
-    // a key-value map such as { "hello" => 5, "bonjour" => 4 }
-    // Map(Map),
+    /// a key-value map such as ["hello": 5, "bonjour": 4]
+    Map(Map),
+
+    /// a function value such as "let a = b" where b is a function, or fn(x num) num { ... }
+    Function(Closure),
 
-    // a pointer to a function such as let "a = b" where b is a FunctionDecl
-    // Function(FunctionPointer),
     /// a symbol reference  
     Ref(Rc<RefCell<ISymbol>>),
 
     // a structure built from a StructDecl such as "let a = B {}"
     Struct(Struct),
 
+    // a struct seen through a TraitDecl such as "let a: Speak = B {}"
+    Trait(TraitObject),
+
+    // a variant of an EnumDecl such as "let a = Shape.Circle(2.0)"
+    Enum(EnumValue),
+
+    // the result of a fallible function such as "let a = read_file("a.txt")"
+    Result(ResultValue),
+
+    // an error such as error("file not found"), before it's turned into a result
+    Error(String),
+
+    // a value which may be absent such as "let a: ?num = 3"
+    Optional(OptionalValue),
+
+    // the none literal, before it's turned into an optional
+    None,
+
+    // a fixed group of values such as "let a = (3, "three")"
+    Tuple(Vec<Rc<RefCell<ISymbol>>>),
+
     // a croco type such as [num]
     CrocoType(SymbolType),
 }
@@ -102,6 +238,14 @@ impl ISymbol {
         }
     }
 
+    /// force cast into a map
+    pub fn into_map(self) -> Result<Map, &'static str> {
+        match self {
+            ISymbol::Map(m) => Ok(m),
+            _ => Err("expected a map"),
+        }
+    }
+
     /// force cast into a symbol reference
     pub fn into_ref(self) -> Result<Rc<RefCell<ISymbol>>, &'static str> {
         match self {
@@ -132,6 +276,8 @@ pub type ISymTable = SymTable<Rc<RefCell<ISymbol>>>;
 pub struct ICodegen {
     pub symtable: ISymTable,
     pub functions: HashMap<String, Function>,
+    /// The types bound to the type parameters of the function being run
+    pub generics: HashMap<String, SymbolType>,
 }
 
 impl ICodegen {
@@ -156,11 +302,11 @@ impl ICodegen {
             Identifier::new(function.name.clone(), module_name.to_owned()).get_namespaced_name();
 
         self.symtable
-            .register_decl(namespaced_name, Decl::FunctionDecl(builtin))
+            .register_decl(namespaced_name.clone(), Decl::FunctionDecl(builtin))
             .unwrap();
 
         self.functions
-            .insert(function.name, Function::Builtin(function.pointer));
+            .insert(namespaced_name, Function::Builtin(function.pointer));
     }
 }
 
@@ -195,13 +341,24 @@ impl INodeResult {
 /// returns the type of a symbol
 pub fn get_symbol_type(symbol: &ISymbol) -> SymbolType {
     match symbol {
-        ISymbol::Primitive(LiteralEnum::Bool(_)) => SymbolType::Bool,
-        ISymbol::Primitive(LiteralEnum::Num(_)) => SymbolType::Num,
-        ISymbol::Primitive(LiteralEnum::Fnum(_)) => SymbolType::Fnum,
-        ISymbol::Primitive(LiteralEnum::Str(_)) => SymbolType::Str,
+        ISymbol::Primitive(primitive) => primitive.get_symbol_type(),
         ISymbol::Array(arr) => SymbolType::Array(arr.array_type.clone()),
+        ISymbol::Map(map) => SymbolType::Map(map.key_type.clone(), map.value_type.clone()),
         ISymbol::Struct(s) => SymbolType::Struct(s.struct_type.clone()),
+        ISymbol::Trait(t) => SymbolType::Trait(t.trait_type.clone()),
+        ISymbol::Enum(e) => SymbolType::Enum(e.enum_type.clone()),
+        ISymbol::Function(c) => c.fn_decl.get_type(),
         ISymbol::Ref(r) => SymbolType::Ref(Box::new(get_symbol_type(&*r.borrow()))),
+        ISymbol::Result(r) => SymbolType::Result(r.ok_type.clone()),
+        ISymbol::Error(_) => SymbolType::Error,
+        ISymbol::Optional(o) => SymbolType::Optional(o.some_type.clone()),
+        ISymbol::None => SymbolType::None,
+        ISymbol::Tuple(elements) => SymbolType::Tuple(
+            elements
+                .iter()
+                .map(|el| get_symbol_type(&*el.borrow()))
+                .collect(),
+        ),
         ISymbol::CrocoType(_) => SymbolType::CrocoType,
     }
 }
@@ -217,7 +374,8 @@ pub fn import_builtin_module(codegen: &mut ICodegen, name: &str) -> bool {
         }
 
         for var in module.vars {
-            let namespaced_name = Identifier::new(var.name, name.to_owned()).get_namespaced_name();
+            let namespaced_name =
+                Identifier::new(var.name, namespace.to_owned()).get_namespaced_name();
             codegen
                 .symtable
                 .register_decl(
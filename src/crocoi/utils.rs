@@ -1,5 +1,7 @@
 use crate::ast::BackendNode;
-use crate::crocoi::symbol::{Array, ICodegen, ISymbol, Struct};
+use crate::crocoi::symbol::{
+    get_symbol_type, Array, ICodegen, ISymbol, Map, OptionalValue, ResultValue, Struct, TraitObject,
+};
 use crate::error::CrocoError;
 use crate::{
     symbol_type::SymbolType,
@@ -22,6 +24,37 @@ pub fn get_value(
         .map_err(|_| CrocoError::new(code_pos, "cannot use this type in an expression"))?)
 }
 
+/// Converts a number into another number type.
+/// Like an `as` in Rust, integers wrap around and floats are truncated.
+pub fn cast_num(value: &LiteralEnum, num_type: &SymbolType) -> Option<LiteralEnum> {
+    let (int, float) = match *value {
+        Num(n) => (n as i128, n as f64),
+        I64(n) => (n as i128, n as f64),
+        U64(n) => (n as i128, n as f64),
+        U8(n) => (n as i128, n as f64),
+        Fnum(f) => (f as i128, f as f64),
+        F64(f) => (f as i128, f),
+        _ => return None,
+    };
+
+    Some(match num_type {
+        SymbolType::Num => Num(int as i32),
+        SymbolType::I64 => I64(int as i64),
+        SymbolType::U64 => U64(int as u64),
+        SymbolType::U8 => U8(int as u8),
+        SymbolType::Fnum => Fnum(float as f32),
+        SymbolType::F64 => F64(float),
+        _ => return None,
+    })
+}
+
+/// Converts two numbers to the type they're widened to when used in the same operation,
+/// or returns None if they can't be used together
+pub fn widen_values(left: &LiteralEnum, right: &LiteralEnum) -> Option<(LiteralEnum, LiteralEnum)> {
+    let num_type = left.get_symbol_type().widen(&right.get_symbol_type())?;
+    Some((cast_num(left, &num_type)?, cast_num(right, &num_type)?))
+}
+
 /// Auto dereferences as many times as needed  
 /// e.g (&a).foo -> a.foo
 pub fn auto_deref(mut symbol: ISymbol) -> ISymbol {
@@ -40,6 +73,43 @@ pub fn auto_deref(mut symbol: ISymbol) -> ISymbol {
     symbol
 }
 
+/// Checks if two symbols of the same type are equal.  
+/// Arrays and structs are equal if all their elements are.  
+/// None is returned if the symbols can't be compared
+pub fn symbols_eq(left: &ISymbol, right: &ISymbol) -> Option<bool> {
+    match (left, right) {
+        (ISymbol::Primitive(left), ISymbol::Primitive(right)) => Some(left == right),
+
+        (ISymbol::Array(left), ISymbol::Array(right)) => {
+            if left.contents.len() != right.contents.len() {
+                return Some(false);
+            }
+
+            for (left_el, right_el) in left.contents.iter().zip(&right.contents) {
+                if !symbols_eq(&left_el.borrow(), &right_el.borrow())? {
+                    return Some(false);
+                }
+            }
+
+            Some(true)
+        }
+
+        (ISymbol::Struct(left), ISymbol::Struct(right)) => {
+            for (field_name, left_field) in &left.fields {
+                let right_field = right.fields.get(field_name)?;
+
+                if !symbols_eq(&left_field.borrow(), &right_field.borrow())? {
+                    return Some(false);
+                }
+            }
+
+            Some(true)
+        }
+
+        _ => None,
+    }
+}
+
 /// initializes recursively a symbol to its default value
 pub fn init_default(
     symbol_type: &SymbolType,
@@ -49,6 +119,10 @@ pub fn init_default(
     Ok(match symbol_type {
         SymbolType::Num => ISymbol::Primitive(Num(0)),
         SymbolType::Fnum => ISymbol::Primitive(Fnum(0.)),
+        SymbolType::I64 => ISymbol::Primitive(I64(0)),
+        SymbolType::U64 => ISymbol::Primitive(U64(0)),
+        SymbolType::U8 => ISymbol::Primitive(U8(0)),
+        SymbolType::F64 => ISymbol::Primitive(F64(0.)),
         SymbolType::Bool => ISymbol::Primitive(LiteralEnum::Bool(false)),
         SymbolType::Str => ISymbol::Primitive(Str(String::new())),
         SymbolType::Array(array_type) => ISymbol::Array(Array {
@@ -78,10 +152,132 @@ pub fn init_default(
                 struct_type: struct_type.clone(),
             })
         }
-        SymbolType::Map(_, _) => todo!(),
-        SymbolType::Function(_) => {
-            return Err(CrocoError::new(code_pos, "dangling function pointer"))
+        SymbolType::Map(key_type, value_type) => ISymbol::Map(Map {
+            contents: HashMap::new(),
+            keys: Vec::new(),
+            key_type: key_type.clone(),
+            value_type: value_type.clone(),
+        }),
+        SymbolType::Function(_) => return Err(CrocoError::function_init_error(code_pos)),
+        SymbolType::Trait(_) => return Err(CrocoError::trait_init_error(code_pos)),
+        SymbolType::Enum(_) => return Err(CrocoError::enum_init_error(code_pos)),
+        SymbolType::Result(ok_type) => {
+            let value = match ok_type {
+                Some(ok_type) => Some(Box::new(init_default(ok_type, codegen, code_pos)?)),
+                None => None,
+            };
+
+            ISymbol::Result(ResultValue {
+                value: Ok(value),
+                ok_type: ok_type.clone(),
+            })
+        }
+        SymbolType::Error => ISymbol::Error(String::new()),
+        SymbolType::Optional(some_type) => ISymbol::Optional(OptionalValue {
+            value: None,
+            some_type: some_type.clone(),
+        }),
+        SymbolType::None => ISymbol::None,
+        SymbolType::Tuple(types) => {
+            let mut elements = Vec::with_capacity(types.len());
+            for el_type in types {
+                elements.push(Rc::new(RefCell::new(init_default(
+                    el_type, codegen, code_pos,
+                )?)));
+            }
+
+            ISymbol::Tuple(elements)
         }
         SymbolType::CrocoType => ISymbol::CrocoType(SymbolType::CrocoType),
+        // type parameters are always resolved at the call site
+        SymbolType::Generic(_) => unreachable!(),
     })
 }
+
+/// Converts a symbol into the expected type when it can be done implicitly  
+/// e.g let a: Speak = Dog {}, returning a str from a !str function, assigning none to a ?str
+/// or assigning a num to an i64.
+/// The elements of a tuple are converted one by one.
+pub fn coerce_to_type(
+    symbol: ISymbol,
+    expected_type: &SymbolType,
+    codegen: &mut ICodegen,
+    code_pos: &CodePos,
+) -> Result<ISymbol, CrocoError> {
+    match (expected_type, symbol) {
+        (SymbolType::Trait(trait_name), ISymbol::Struct(s)) => {
+            codegen
+                .symtable
+                .check_trait_impl(&s.struct_type, trait_name)
+                .map_err(|e| CrocoError::new(code_pos, e))?;
+
+            Ok(ISymbol::Trait(TraitObject {
+                value: Rc::new(RefCell::new(ISymbol::Struct(s))),
+                trait_type: trait_name.clone(),
+            }))
+        }
+
+        (SymbolType::Result(ok_type), ISymbol::Error(message)) => {
+            Ok(ISymbol::Result(ResultValue {
+                value: Err(message),
+                ok_type: ok_type.clone(),
+            }))
+        }
+
+        (SymbolType::Optional(some_type), ISymbol::None) => Ok(ISymbol::Optional(OptionalValue {
+            value: None,
+            some_type: some_type.clone(),
+        })),
+
+        (SymbolType::Optional(some_type), symbol) if !matches!(symbol, ISymbol::Optional(_)) => {
+            let value = coerce_to_type(symbol, some_type, codegen, code_pos)?;
+
+            // let the caller report the type mismatch
+            if get_symbol_type(&value) != **some_type {
+                return Ok(value);
+            }
+
+            Ok(ISymbol::Optional(OptionalValue {
+                value: Some(Box::new(value)),
+                some_type: some_type.clone(),
+            }))
+        }
+
+        (SymbolType::Tuple(types), ISymbol::Tuple(elements)) if types.len() == elements.len() => {
+            let mut coerced = Vec::with_capacity(elements.len());
+            for (el_type, el) in types.iter().zip(elements) {
+                let el = el.borrow().clone();
+                coerced.push(Rc::new(RefCell::new(coerce_to_type(
+                    el, el_type, codegen, code_pos,
+                )?)));
+            }
+
+            Ok(ISymbol::Tuple(coerced))
+        }
+
+        (SymbolType::Result(Some(ok_type)), symbol) if !matches!(symbol, ISymbol::Result(_)) => {
+            let value = coerce_to_type(symbol, ok_type, codegen, code_pos)?;
+
+            // let the caller report the type mismatch
+            if get_symbol_type(&value) != **ok_type {
+                return Ok(value);
+            }
+
+            Ok(ISymbol::Result(ResultValue {
+                value: Ok(Some(Box::new(value))),
+                ok_type: Some(ok_type.clone()),
+            }))
+        }
+
+        // a number is widened to a bigger number type, e.g let a i64 = 3
+        (_, ISymbol::Primitive(primitive))
+            if primitive.get_symbol_type().widen(expected_type).as_ref() == Some(expected_type) =>
+        {
+            Ok(ISymbol::Primitive(
+                cast_num(&primitive, expected_type).unwrap(),
+            ))
+        }
+
+        (_, symbol) => Ok(symbol),
+    }
+}
@@ -1,4 +1,5 @@
 pub mod node;
+pub mod stdlib;
 pub mod symbol;
 pub mod utils;
 
@@ -7,7 +8,7 @@ pub use self::symbol::LNodeResult;
 pub use self::symbol::LSymbol;
 use self::utils::insert_builtin_functions;
 
-use std::fs;
+use std::{collections::HashMap, fs};
 
 use crate::lexer::Lexer;
 use crate::symbol::{Decl, SymTable};
@@ -22,7 +23,7 @@ use inkwell::{
     module::Module,
     passes::{PassManager, PassManagerBuilder},
     targets::{CodeModel, FileType, InitializationConfig, RelocMode, Target, TargetMachine},
-    OptimizationLevel,
+    AddressSpace, OptimizationLevel,
 };
 use utils::strip_ext;
 #[derive(PartialEq)]
@@ -168,20 +169,56 @@ impl Crocol {
                 )
             })?;
 
-        let ptr_size = context.ptr_sized_int_type(&target_machine.get_target_data(), None);
+        let target_data = target_machine.get_target_data();
+        let ptr_size = context.ptr_sized_int_type(&target_data, None);
+
+        // a trait object is a fat pointer: the struct data and the vtable of its methods
+        let void_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+        let trait_type = context.opaque_struct_type("CrocoTrait");
+        trait_type.set_body(
+            &[
+                void_ptr_type.into(),
+                void_ptr_type.ptr_type(AddressSpace::Generic).into(),
+            ],
+            false,
+        );
+
+        // a function value is a pointer to the function and a pointer to its captured variables
+        let closure_type = context.opaque_struct_type("CrocoClosure");
+        closure_type.set_body(&[void_ptr_type.into(), void_ptr_type.into()], false);
+
+        // the global variables are initialized by a function called at the start of main
+        let init_fn = module.add_function(
+            "__croco_init",
+            context.void_type().fn_type(&[], false),
+            None,
+        );
+        context.append_basic_block(init_fn, "entry");
 
         let mut codegen = LCodegen {
             array_type: module.get_struct_type("struct.CrocoStr").unwrap(),
             str_type: module.get_struct_type("struct.CrocoStr").unwrap(),
+            map_type: module.get_struct_type("struct.CrocoMap").unwrap(),
+            trait_type,
+            closure_type,
             context: &context,
             module,
             builder: context.create_builder(),
             symtable: SymTable::new(),
             ptr_size,
+            target_data,
             current_fn: None,
             current_loop_block: None,
             current_loop_end_block: None,
             sret_ptr: None,
+            return_type: None,
+            generic_fns: HashMap::new(),
+            pending_instances: Vec::new(),
+            generics: HashMap::new(),
+            pending_closures: Vec::new(),
+            pending_fns: Vec::new(),
+            init_fn,
+            decl_stores: HashMap::new(),
         };
 
         // insert all the built-in functions from the std
@@ -202,7 +239,24 @@ impl Crocol {
                 .unwrap();
         }
 
-        if let Err(mut e) = tree.crocol(&mut codegen) {
+        for (trait_name, trait_decl) in parser.take_trait_decls() {
+            codegen
+                .symtable
+                .register_decl(trait_name, Decl::TraitDecl(trait_decl))
+                .unwrap();
+        }
+
+        for (enum_name, enum_decl) in parser.take_enum_decls() {
+            codegen
+                .symtable
+                .register_decl(enum_name, Decl::EnumDecl(enum_decl))
+                .unwrap();
+        }
+
+        if let Err(mut e) = tree
+            .crocol(&mut codegen)
+            .and_then(|_| codegen.build_pending_functions())
+        {
             e.set_kind(CrocoErrorKind::Compilation);
             return Err(e);
         }
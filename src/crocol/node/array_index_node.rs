@@ -3,7 +3,7 @@ use inkwell::{types::BasicType, AddressSpace, IntPredicate};
 use crate::{
     ast::node::ArrayIndexNode,
     crocol::{
-        utils::{get_llvm_type, throw_runtime_error},
+        utils::{build_map_key_ptr, get_llvm_type, throw_runtime_error},
         CrocolNode, LCodegen, LNodeResult, LSymbol,
     },
     symbol_type::SymbolType,
@@ -15,11 +15,10 @@ impl CrocolNode for ArrayIndexNode {
         &mut self,
         codegen: &mut LCodegen<'ctx>,
     ) -> Result<LNodeResult<'ctx>, CrocoError> {
-        let index = self
+        let index_symbol = self
             .index
             .crocol(codegen)?
-            .into_symbol(codegen, &self.code_pos)?
-            .into_num(&self.code_pos)?;
+            .into_symbol(codegen, &self.code_pos)?;
 
         let array_ptr = self
             .array
@@ -28,12 +27,76 @@ impl CrocolNode for ArrayIndexNode {
             .crocol(codegen)?
             .into_pointer(codegen, &self.code_pos)?;
 
+        let current_fn = codegen.current_fn.unwrap();
+
         let el_type = match array_ptr.symbol_type {
             SymbolType::Array(el_type) => el_type,
+
+            SymbolType::Map(key_type, value_type) => {
+                if index_symbol.symbol_type != *key_type {
+                    return Err(CrocoError::new(
+                        &self.code_pos,
+                        format!("expected a key of type {}", key_type),
+                    ));
+                }
+
+                let key_ptr = build_map_key_ptr(&index_symbol, codegen);
+
+                // the key is inserted only if we're assigning to it
+                let map_fn_name = if self.is_assigned {
+                    "_croco_map_slot"
+                } else {
+                    "_croco_map_get"
+                };
+                let map_fn = codegen.module.get_function(map_fn_name).unwrap();
+
+                let value_ptr = codegen
+                    .builder
+                    .build_call(
+                        map_fn,
+                        &[array_ptr.value.into(), key_ptr.into()],
+                        "mapvalue",
+                    )
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_pointer_value();
+
+                if !self.is_assigned {
+                    let missing_key_block =
+                        codegen.context.append_basic_block(current_fn, "missingkey");
+                    let found_key_block =
+                        codegen.context.append_basic_block(current_fn, "foundkey");
+
+                    let missing_key_cmp = codegen.builder.build_is_null(value_ptr, "cmpmissingkey");
+                    codegen.builder.build_conditional_branch(
+                        missing_key_cmp,
+                        missing_key_block,
+                        found_key_block,
+                    );
+
+                    codegen.builder.position_at_end(missing_key_block);
+                    throw_runtime_error(CrocoError::map_missing_key_error(&self.code_pos), codegen);
+
+                    codegen.builder.position_at_end(found_key_block);
+                }
+
+                let value_ptr = codegen.builder.build_bitcast(
+                    value_ptr,
+                    get_llvm_type(&value_type, codegen).ptr_type(AddressSpace::Generic),
+                    "mapvalueptr",
+                );
+
+                return Ok(LNodeResult::Variable(LSymbol {
+                    value: value_ptr,
+                    symbol_type: *value_type,
+                }));
+            }
+
             _ => return Err(CrocoError::wrong_type_indexing(&self.code_pos)),
         };
 
-        let current_fn = codegen.current_fn.unwrap();
+        let index = index_symbol.into_num(&self.code_pos)?;
 
         // we may need to cast the i32 index to i64 for comparisons
         let ptr_sized_index = codegen
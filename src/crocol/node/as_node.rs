@@ -1,6 +1,6 @@
 use inkwell::FloatPredicate;
 
-use crate::crocol::CrocolNode;
+use crate::crocol::{utils::build_num_cast, CrocolNode};
 use crate::symbol_type::SymbolType;
 use crate::{ast::node::AsNode, error::CrocoError};
 
@@ -21,42 +21,31 @@ impl CrocolNode for AsNode {
             .crocol(codegen)?
             .into_symbol(codegen, &self.code_pos)?;
 
-        let casted = match (val.symbol_type, &self.as_type) {
-            (SymbolType::Bool, SymbolType::Bool)
-            | (SymbolType::Str, SymbolType::Str)
-            | (SymbolType::Num, SymbolType::Num)
-            | (SymbolType::Fnum, SymbolType::Fnum) => {
-                return Err(CrocoError::cast_redundant_error(&self.code_pos))
+        // useless cast
+        if val.symbol_type == self.as_type {
+            if self.implicit {
+                return Ok(LNodeResult::Value(val));
             }
 
-            (SymbolType::Bool, SymbolType::Fnum) => {
-                let zero = codegen.context.f32_type().const_zero();
-                let one = codegen.context.f32_type().const_float(1.);
+            return Err(CrocoError::cast_redundant_error(&self.code_pos));
+        }
 
-                LSymbol {
-                    value: codegen.builder.build_select(
-                        val.value.into_int_value(),
-                        one,
-                        zero,
-                        "boolfnumcast",
-                    ),
-                    symbol_type: SymbolType::Fnum,
-                }
-            }
-
-            (SymbolType::Bool, SymbolType::Num) => {
+        let casted = match (&val.symbol_type, &self.as_type) {
+            (SymbolType::Bool, as_type) if as_type.is_number() => {
                 let zero = codegen.context.i32_type().const_zero();
                 let one = codegen.context.i32_type().const_int(1, true);
 
-                LSymbol {
+                let num = LSymbol {
                     value: codegen.builder.build_select(
                         val.value.into_int_value(),
                         one,
                         zero,
                         "boolnumcast",
                     ),
-                    symbol_type: SymbolType::Fnum,
-                }
+                    symbol_type: SymbolType::Num,
+                };
+
+                build_num_cast(num, as_type, codegen)
             }
 
             (SymbolType::Bool, SymbolType::Str) => {
@@ -77,89 +66,104 @@ impl CrocolNode for AsNode {
                 }
             }
 
-            (SymbolType::Num, SymbolType::Fnum) => {
-                let cast = codegen.builder.build_signed_int_to_float(
-                    val.value.into_int_value(),
-                    codegen.context.f32_type(),
-                    "castnumfnum",
-                );
-                LSymbol {
-                    value: cast.into(),
-                    symbol_type: SymbolType::Fnum,
-                }
-            }
+            (SymbolType::Str, SymbolType::Bool) => {
+                let zero = codegen.context.bool_type().const_zero();
+                let one = codegen.context.bool_type().const_int(1, false);
 
-            (SymbolType::Num, SymbolType::Str) => {
-                let num_as_str_fn = codegen.module.get_function("_as_num_str").unwrap();
-                let str_res = codegen.alloc_str("").into();
-                codegen
+                let len_ptr = codegen
                     .builder
-                    .build_call(num_as_str_fn, &[val.value, str_res], "callcast");
-
-                LSymbol {
-                    symbol_type: SymbolType::Str,
-                    value: codegen
-                        .builder
-                        .build_load(str_res.into_pointer_value(), "loadstrptr"),
-                }
-            }
+                    .build_struct_gep(val.value.into_pointer_value(), 1, "geplen")
+                    .unwrap();
+                let len = codegen.builder.build_load(len_ptr, "loadlen");
 
-            (SymbolType::Num, SymbolType::Bool) => {
                 let cmp = codegen.builder.build_int_compare(
                     IntPredicate::EQ,
-                    val.value.into_int_value(),
-                    codegen.context.i32_type().const_zero(),
-                    "cmpnum",
-                );
-
-                let cast = codegen.builder.build_select(
-                    cmp,
-                    codegen.context.bool_type().const_zero(),
-                    codegen.context.bool_type().const_int(1, false),
-                    "castnumbool",
+                    len.into_int_value(),
+                    zero,
+                    "cmplen",
                 );
 
                 LSymbol {
-                    value: cast,
+                    value: codegen.builder.build_select(cmp, zero, one, "selectbool"),
                     symbol_type: SymbolType::Bool,
                 }
             }
 
-            (SymbolType::Fnum, SymbolType::Num) => {
-                let cast = codegen.builder.build_float_to_signed_int(
-                    val.value.into_float_value(),
-                    codegen.context.i32_type(),
-                    "castfnumnum",
-                );
+            (SymbolType::Str, as_type) if as_type.is_number() => {
+                // a u8 is parsed as a num before being truncated
+                let (fn_name, parsed_type) = match as_type {
+                    SymbolType::Fnum => ("_as_str_fnum", SymbolType::Fnum),
+                    SymbolType::I64 => ("_as_str_i64", SymbolType::I64),
+                    SymbolType::U64 => ("_as_str_u64", SymbolType::U64),
+                    SymbolType::F64 => ("_as_str_f64", SymbolType::F64),
+                    _ => ("_as_str_num", SymbolType::Num),
+                };
+
+                let str_as_num_fn = codegen.module.get_function(fn_name).unwrap();
+                let num_res = codegen
+                    .builder
+                    .build_call(str_as_num_fn, &[val.value], "callcast")
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap();
+
+                let num = LSymbol {
+                    value: num_res,
+                    symbol_type: parsed_type,
+                };
+                build_num_cast(num, as_type, codegen)
+            }
+
+            (num_type, SymbolType::Bool) if num_type.is_float() => {
+                let float_value = val.value.into_float_value();
 
                 LSymbol {
-                    value: cast.into(),
-                    symbol_type: SymbolType::Num,
+                    value: codegen
+                        .builder
+                        .build_float_compare(
+                            FloatPredicate::ONE,
+                            float_value,
+                            float_value.get_type().const_zero(),
+                            "castfloatbool",
+                        )
+                        .into(),
+                    symbol_type: SymbolType::Bool,
                 }
             }
 
-            (SymbolType::Fnum, SymbolType::Bool) => {
-                let zero = codegen.context.bool_type().const_zero();
-                let one = codegen.context.bool_type().const_int(1, false);
-
-                let cmp = codegen.builder.build_float_compare(
-                    FloatPredicate::UEQ,
-                    val.value.into_float_value(),
-                    codegen.context.f32_type().const_zero(),
-                    "cmpfnumbool",
-                );
+            (num_type, SymbolType::Bool) if num_type.is_integer() => {
+                let int_value = val.value.into_int_value();
 
                 LSymbol {
-                    value: codegen.builder.build_select(cmp, zero, one, "castfnumbool"),
+                    value: codegen
+                        .builder
+                        .build_int_compare(
+                            IntPredicate::NE,
+                            int_value,
+                            int_value.get_type().const_zero(),
+                            "castintbool",
+                        )
+                        .into(),
                     symbol_type: SymbolType::Bool,
                 }
             }
-            (SymbolType::Fnum, SymbolType::Str) => {
-                let fnum_as_str_fn = codegen.module.get_function("_as_fnum_str").unwrap();
+
+            (num_type, SymbolType::Str) if num_type.is_number() => {
+                // a u8 is formatted as a num
+                let (fn_name, arg_type) = match num_type {
+                    SymbolType::Fnum => ("_as_fnum_str", SymbolType::Fnum),
+                    SymbolType::I64 => ("_as_i64_str", SymbolType::I64),
+                    SymbolType::U64 => ("_as_u64_str", SymbolType::U64),
+                    SymbolType::F64 => ("_as_f64_str", SymbolType::F64),
+                    _ => ("_as_num_str", SymbolType::Num),
+                };
+
+                let num_as_str_fn = codegen.module.get_function(fn_name).unwrap();
+                let arg = build_num_cast(val.clone(), &arg_type, codegen);
                 let str_res = codegen.alloc_str("").into();
                 codegen
                     .builder
-                    .build_call(fnum_as_str_fn, &[val.value, str_res], "callcast");
+                    .build_call(num_as_str_fn, &[arg.value, str_res], "callcast");
 
                 LSymbol {
                     symbol_type: SymbolType::Str,
@@ -169,56 +173,8 @@ impl CrocolNode for AsNode {
                 }
             }
 
-            (SymbolType::Str, SymbolType::Fnum) => {
-                let str_as_fnum_fn = codegen.module.get_function("_as_str_fnum").unwrap();
-                let fnum_res = codegen
-                    .builder
-                    .build_call(str_as_fnum_fn, &[val.value], "callcast")
-                    .try_as_basic_value()
-                    .left()
-                    .unwrap();
-
-                LSymbol {
-                    value: fnum_res,
-                    symbol_type: SymbolType::Fnum,
-                }
-            }
-
-            (SymbolType::Str, SymbolType::Num) => {
-                let str_as_num_fn = codegen.module.get_function("_as_str_num").unwrap();
-                let num_res = codegen
-                    .builder
-                    .build_call(str_as_num_fn, &[val.value], "callcast")
-                    .try_as_basic_value()
-                    .left()
-                    .unwrap();
-                LSymbol {
-                    value: num_res,
-                    symbol_type: SymbolType::Num,
-                }
-            }
-
-            (SymbolType::Str, SymbolType::Bool) => {
-                let zero = codegen.context.bool_type().const_zero();
-                let one = codegen.context.bool_type().const_int(1, false);
-
-                let len_ptr = codegen
-                    .builder
-                    .build_struct_gep(val.value.into_pointer_value(), 1, "geplen")
-                    .unwrap();
-                let len = codegen.builder.build_load(len_ptr, "loadlen");
-
-                let cmp = codegen.builder.build_int_compare(
-                    IntPredicate::EQ,
-                    len.into_int_value(),
-                    zero,
-                    "cmplen",
-                );
-
-                LSymbol {
-                    value: codegen.builder.build_select(cmp, zero, one, "selectbool"),
-                    symbol_type: SymbolType::Bool,
-                }
+            (num_type, as_type) if num_type.is_number() && as_type.is_number() => {
+                build_num_cast(val.clone(), as_type, codegen)
             }
 
             _ => {
@@ -1,5 +1,5 @@
 use {
-    crate::crocol::{LCodegen, LNodeResult},
+    crate::crocol::{utils::coerce_to_type, LCodegen, LNodeResult},
     inkwell::values::BasicValueEnum,
 };
 
@@ -17,6 +17,7 @@ impl CrocolNode for AssignmentNode {
             .expr
             .crocol(codegen)?
             .into_symbol(codegen, &self.code_pos)?;
+        let expr = coerce_to_type(expr, &var_ptr.symbol_type, codegen, &self.code_pos)?;
 
         if expr.symbol_type != var_ptr.symbol_type {
             return Err(CrocoError::type_change_error(&self.code_pos));
@@ -0,0 +1,92 @@
+use crate::{
+    ast::node::BitwiseNode,
+    crocol::{
+        utils::{build_num_cast, build_widened_values},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    token::OperatorEnum,
+    CrocoError,
+};
+
+impl CrocolNode for BitwiseNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let left = self
+            .left
+            .as_mut()
+            .unwrap()
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+
+        let right = self
+            .right
+            .as_mut()
+            .unwrap()
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+
+        if !left.symbol_type.is_integer() || !right.symbol_type.is_integer() {
+            return Err(CrocoError::bitwise_error(&self.code_pos));
+        }
+
+        let symbol = match self.bitwise_kind {
+            // a shift keeps the type of the shifted number, and the shift amount wraps around its size
+            OperatorEnum::ShiftLeft | OperatorEnum::ShiftRight => {
+                let left_int = left.value.into_int_value();
+                let int_type = left_int.get_type();
+
+                let shift = build_num_cast(right, &left.symbol_type, codegen);
+                let shift = codegen.builder.build_and(
+                    shift.value.into_int_value(),
+                    int_type.const_int(int_type.get_bit_width() as u64 - 1, false),
+                    "maskshift",
+                );
+
+                let value = if self.bitwise_kind == OperatorEnum::ShiftLeft {
+                    codegen.builder.build_left_shift(left_int, shift, "shl")
+                } else {
+                    // signed numbers keep their sign when shifted right
+                    codegen.builder.build_right_shift(
+                        left_int,
+                        shift,
+                        !left.symbol_type.is_unsigned(),
+                        "shr",
+                    )
+                };
+
+                LSymbol {
+                    value: value.into(),
+                    symbol_type: left.symbol_type,
+                }
+            }
+
+            _ => {
+                let (left, right) = build_widened_values(left, right, codegen)
+                    .ok_or_else(|| CrocoError::bitwise_error(&self.code_pos))?;
+
+                let left_int = left.value.into_int_value();
+                let right_int = right.value.into_int_value();
+
+                let value = match self.bitwise_kind {
+                    OperatorEnum::BitwiseAnd => {
+                        codegen.builder.build_and(left_int, right_int, "and")
+                    }
+                    OperatorEnum::BitwiseOr => codegen.builder.build_or(left_int, right_int, "or"),
+                    OperatorEnum::BitwiseXor => {
+                        codegen.builder.build_xor(left_int, right_int, "xor")
+                    }
+                    _ => unreachable!(),
+                };
+
+                LSymbol {
+                    value: value.into(),
+                    symbol_type: left.symbol_type,
+                }
+            }
+        };
+
+        Ok(LNodeResult::Value(symbol))
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    ast::node::BitwiseNotNode,
+    crocol::{CrocolNode, LCodegen, LNodeResult, LSymbol},
+    CrocoError,
+};
+
+impl CrocolNode for BitwiseNotNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let bottom = self
+            .bottom
+            .as_mut()
+            .unwrap()
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+
+        if !bottom.symbol_type.is_integer() {
+            return Err(CrocoError::bitwise_not_error(&self.code_pos));
+        }
+
+        Ok(LNodeResult::Value(LSymbol {
+            value: codegen
+                .builder
+                .build_not(bottom.value.into_int_value(), "not")
+                .into(),
+            symbol_type: bottom.symbol_type,
+        }))
+    }
+}
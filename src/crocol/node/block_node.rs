@@ -1,7 +1,8 @@
 use crate::error::CrocoError;
 use crate::{
     ast::node::BlockNode,
-    crocol::{LCodegen, LNodeResult},
+    crocol::{utils::build_result_value, LCodegen, LNodeResult},
+    symbol_type::SymbolType,
 };
 use crate::{ast::BlockScope, crocol::CrocolNode};
 
@@ -47,6 +48,14 @@ impl CrocolNode for BlockNode {
             match &value {
                 LNodeResult::Return(_) => (),
                 _ => {
+                    // a function giving an empty result succeeds when it reaches its end
+                    if let (Some(SymbolType::Result(None)), Some(sret_ptr)) =
+                        (&codegen.return_type, codegen.sret_ptr)
+                    {
+                        let ok = build_result_value(&None, None, None, codegen);
+                        codegen.builder.build_store(sret_ptr, ok.value);
+                    }
+
                     codegen.builder.build_return(None);
                 }
             }
@@ -0,0 +1,151 @@
+use crate::{
+    ast::node::{CatchHandler, CatchNode},
+    crocol::{
+        utils::{auto_deref, get_llvm_type},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    symbol_type::SymbolType,
+    CrocoError,
+};
+
+impl CrocolNode for CatchNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let current_fn = codegen.current_fn.unwrap();
+
+        let result = self
+            .bottom
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+        let result = auto_deref(result, codegen);
+
+        let ok_type = match result.symbol_type {
+            SymbolType::Result(ok_type) => ok_type,
+            _ => return Err(CrocoError::result_error(&self.code_pos)),
+        };
+
+        // here is the intended result for reference:
+        //
+        // entry:
+        //   %iserr = extractvalue ...
+        //   br %iserr catcherr catchok
+        //
+        // catchok:
+        //   store %value %catchres
+        //   br endcatch
+        //
+        // catcherr:
+        //   ...
+        //   store %fallback %catchres
+        //   br endcatch
+        let result_ptr = ok_type.as_ref().map(|ok_type| {
+            codegen.create_block_alloca(get_llvm_type(ok_type, codegen), "catchres")
+        });
+
+        let result_value = result.value.into_struct_value();
+        let is_err = codegen
+            .builder
+            .build_extract_value(result_value, 0, "iserr")
+            .unwrap()
+            .into_int_value();
+
+        let ok_block = codegen.context.append_basic_block(current_fn, "catchok");
+        let err_block = codegen.context.append_basic_block(current_fn, "catcherr");
+        let end_block = codegen.context.append_basic_block(current_fn, "endcatch");
+        codegen
+            .builder
+            .build_conditional_branch(is_err, err_block, ok_block);
+
+        // the value of the result is kept
+        codegen.builder.position_at_end(ok_block);
+        if let Some(result_ptr) = result_ptr {
+            let value = codegen
+                .builder
+                .build_extract_value(result_value, 2, "okvalue")
+                .unwrap();
+            codegen.builder.build_store(result_ptr, value);
+        }
+        codegen.builder.build_unconditional_branch(end_block);
+
+        // the error is handled
+        codegen.builder.position_at_end(err_block);
+        let message = codegen
+            .builder
+            .build_extract_value(result_value, 1, "errmessage")
+            .unwrap();
+
+        // the catch only has a value if the error is replaced by another value
+        let has_value = match &mut self.handler {
+            CatchHandler::Fallback(fallback) => {
+                let fallback = fallback
+                    .crocol(codegen)?
+                    .into_symbol(codegen, &self.code_pos)?;
+
+                match (&ok_type, result_ptr) {
+                    (Some(ok_type), Some(result_ptr)) if **ok_type == fallback.symbol_type => {
+                        codegen.builder.build_store(result_ptr, fallback.value);
+                    }
+                    _ => {
+                        return Err(CrocoError::fallback_type_error(
+                            &self.code_pos,
+                            ok_type.as_deref(),
+                        ))
+                    }
+                }
+
+                codegen.builder.build_unconditional_branch(end_block);
+                true
+            }
+
+            CatchHandler::Block { var_name, body } => {
+                // the error message lives in its own scope, around the body scope
+                let message_ptr = codegen.create_block_alloca(codegen.str_type.into(), var_name);
+                codegen.build_decl_store(message_ptr, message);
+
+                codegen.symtable.add_scope();
+                codegen
+                    .symtable
+                    .insert_symbol(
+                        var_name,
+                        LSymbol {
+                            value: message_ptr.into(),
+                            symbol_type: SymbolType::Str,
+                        },
+                    )
+                    .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+
+                let value = body.crocol(codegen)?;
+                codegen.symtable.drop_scope();
+
+                match value {
+                    // llvm doesn't like when two terminators are in the same block.
+                    LNodeResult::Return(_) | LNodeResult::Continue | LNodeResult::Break => true,
+                    _ => {
+                        codegen.builder.build_unconditional_branch(end_block);
+                        false
+                    }
+                }
+            }
+        };
+
+        // move the endcatch block at the end of all our created blocks
+        end_block
+            .move_after(current_fn.get_last_basic_block().unwrap())
+            .unwrap();
+        codegen.builder.position_at_end(end_block);
+
+        match (ok_type, result_ptr) {
+            (Some(ok_type), Some(result_ptr)) if has_value => {
+                let value = codegen.builder.build_load(result_ptr, "loadcatch");
+
+                Ok(LNodeResult::Value(LSymbol {
+                    value,
+                    symbol_type: *ok_type,
+                }))
+            }
+            _ => Ok(LNodeResult::Void),
+        }
+    }
+}
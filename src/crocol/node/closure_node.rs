@@ -0,0 +1,125 @@
+use inkwell::{values::InstructionOpcode, AddressSpace};
+
+use crate::{
+    ast::node::ClosureNode,
+    crocol::{
+        symbol::PendingClosure,
+        utils::{build_closure_value, get_env_type, get_llvm_type, get_or_define_closure_fn},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    symbol::Decl,
+    CrocoError,
+};
+
+impl CrocolNode for ClosureNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let fn_name = format!("_closure_{}", codegen.module.get_functions().count());
+
+        codegen
+            .symtable
+            .register_decl(fn_name.clone(), Decl::FunctionDecl(self.fn_decl.clone()))
+            .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+
+        let function = get_or_define_closure_fn(&fn_name, &self.fn_decl, codegen);
+
+        // all the visible variables are captured by reference, except the ones shadowed by the arguments.
+        // they are sorted so the environment layout doesn't depend on the symtable order.
+        let mut captures: Vec<(String, LSymbol)> = codegen
+            .symtable
+            .get_visible_symbols()
+            .into_iter()
+            .filter(|(name, _)| !self.fn_decl.args.iter().any(|arg| &arg.arg_name == name))
+            .collect();
+        captures.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (name, symbol) in captures.iter_mut() {
+            box_captured_variable(name, symbol, codegen)
+                .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+        }
+
+        let capture_types: Vec<_> = captures
+            .iter()
+            .map(|(name, symbol)| (name.clone(), symbol.symbol_type.clone()))
+            .collect();
+
+        let env_ptr = if captures.is_empty() {
+            codegen
+                .context
+                .i8_type()
+                .ptr_type(AddressSpace::Generic)
+                .const_null()
+        } else {
+            // the environment can outlive the current function, so it lives on the heap,
+            // as well as the variables it points to
+            let env_type = get_env_type(&capture_types, codegen);
+            let env_ptr = codegen.builder.build_malloc(env_type, "mallocenv").unwrap();
+
+            for (i, (_, symbol)) in captures.iter().enumerate() {
+                let capture_ptr = codegen
+                    .builder
+                    .build_struct_gep(env_ptr, i as u32, "gepcapture")
+                    .unwrap();
+                codegen.builder.build_store(capture_ptr, symbol.value);
+            }
+
+            env_ptr
+        };
+
+        // the body is built once the whole tree is built
+        codegen.pending_closures.push(PendingClosure {
+            fn_name,
+            fn_body: self.fn_body.clone(),
+            captures: capture_types,
+            code_pos: self.code_pos.clone(),
+        });
+
+        let fn_ptr = function.as_global_value().as_pointer_value();
+
+        Ok(LNodeResult::Value(LSymbol {
+            value: build_closure_value(fn_ptr, env_ptr, codegen),
+            symbol_type: self.fn_decl.get_type(),
+        }))
+    }
+}
+
+/// Moves a variable living on the stack of the current function to the heap,
+/// so that a closure capturing it can outlive the function.
+/// The enclosing function and the closure then share the same heap variable.
+fn box_captured_variable<'ctx>(
+    name: &str,
+    symbol: &mut LSymbol<'ctx>,
+    codegen: &mut LCodegen<'ctx>,
+) -> Result<(), String> {
+    let var_ptr = symbol.value.into_pointer_value();
+
+    // globals and variables already captured by an enclosing closure don't live on the stack
+    let alloca = match var_ptr.as_instruction() {
+        Some(instr) if instr.get_opcode() == InstructionOpcode::Alloca => instr,
+        _ => return Ok(()),
+    };
+
+    // allocate the box where the variable is declared, so each declaration in a loop gets a new one.
+    // the arguments have no declaration store, their box is allocated once per call right after them
+    let insert_block = codegen.builder.get_insert_block().unwrap();
+    match (
+        codegen.decl_stores.get(&var_ptr),
+        alloca.get_next_instruction(),
+    ) {
+        (Some(decl_store), _) => codegen.builder.position_before(decl_store),
+        (None, Some(next_instr)) => codegen.builder.position_before(&next_instr),
+        (None, None) => codegen
+            .builder
+            .position_at_end(alloca.get_parent().unwrap()),
+    }
+
+    let var_type = get_llvm_type(&symbol.symbol_type, codegen);
+    let box_ptr = codegen.builder.build_malloc(var_type, name).unwrap();
+    codegen.builder.position_at_end(insert_block);
+
+    var_ptr.replace_all_uses_with(box_ptr);
+    symbol.value = box_ptr.into();
+    codegen.symtable.replace_local_symbol(name, symbol.clone())
+}
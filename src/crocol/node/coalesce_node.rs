@@ -0,0 +1,100 @@
+use crate::{
+    ast::node::CoalesceNode,
+    crocol::{
+        utils::{auto_deref, coerce_to_type, get_llvm_type},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    symbol_type::SymbolType,
+    CrocoError,
+};
+
+impl CrocolNode for CoalesceNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let current_fn = codegen.current_fn.unwrap();
+
+        let optional = self
+            .left
+            .as_mut()
+            .unwrap()
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+        let optional = auto_deref(optional, codegen);
+
+        let some_type = match optional.symbol_type {
+            SymbolType::Optional(some_type) => *some_type,
+            _ => return Err(CrocoError::optional_error(&self.code_pos)),
+        };
+
+        // here is the intended result for reference:
+        //
+        // entry:
+        //   %issome = icmp ne %optional null
+        //   br %issome coalescesome coalescenone
+        //
+        // coalescesome:
+        //   %value = load %optional
+        //   store %value %coalesceres
+        //   br endcoalesce
+        //
+        // coalescenone:
+        //   ...
+        //   store %default %coalesceres
+        //   br endcoalesce
+        let result_ptr =
+            codegen.create_block_alloca(get_llvm_type(&some_type, codegen), "coalesceres");
+
+        let optional_ptr = optional.value.into_pointer_value();
+        let is_some = codegen.builder.build_is_not_null(optional_ptr, "issome");
+
+        let some_block = codegen
+            .context
+            .append_basic_block(current_fn, "coalescesome");
+        let none_block = codegen
+            .context
+            .append_basic_block(current_fn, "coalescenone");
+        let end_block = codegen
+            .context
+            .append_basic_block(current_fn, "endcoalesce");
+        codegen
+            .builder
+            .build_conditional_branch(is_some, some_block, none_block);
+
+        // the value of the optional is kept
+        codegen.builder.position_at_end(some_block);
+        let value = codegen.builder.build_load(optional_ptr, "somevalue");
+        codegen.builder.build_store(result_ptr, value);
+        codegen.builder.build_unconditional_branch(end_block);
+
+        // the default value is only evaluated if the optional is empty
+        codegen.builder.position_at_end(none_block);
+        let default = self
+            .right
+            .as_mut()
+            .unwrap()
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+        let default = coerce_to_type(default, &some_type, codegen, &self.code_pos)?;
+
+        if default.symbol_type != some_type {
+            return Err(CrocoError::default_type_error(&self.code_pos, &some_type));
+        }
+
+        codegen.builder.build_store(result_ptr, default.value);
+        codegen.builder.build_unconditional_branch(end_block);
+
+        // move the endcoalesce block at the end of all our created blocks
+        end_block
+            .move_after(current_fn.get_last_basic_block().unwrap())
+            .unwrap();
+        codegen.builder.position_at_end(end_block);
+
+        let value = codegen.builder.build_load(result_ptr, "loadcoalesce");
+        Ok(LNodeResult::Value(LSymbol {
+            value,
+            symbol_type: some_type,
+        }))
+    }
+}
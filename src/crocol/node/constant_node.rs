@@ -30,6 +30,26 @@ impl CrocolNode for ConstantNode {
                 symbol_type: SymbolType::Fnum,
             },
 
+            LiteralEnum::I64(n) => LSymbol {
+                value: codegen.context.i64_type().const_int(*n as u64, true).into(),
+                symbol_type: SymbolType::I64,
+            },
+
+            LiteralEnum::U64(n) => LSymbol {
+                value: codegen.context.i64_type().const_int(*n, false).into(),
+                symbol_type: SymbolType::U64,
+            },
+
+            LiteralEnum::U8(n) => LSymbol {
+                value: codegen.context.i8_type().const_int(*n as u64, false).into(),
+                symbol_type: SymbolType::U8,
+            },
+
+            LiteralEnum::F64(n) => LSymbol {
+                value: codegen.context.f64_type().const_float(*n).into(),
+                symbol_type: SymbolType::F64,
+            },
+
             LiteralEnum::Str(s) => {
                 let alloca = codegen.alloc_str(s);
                 let load = codegen.builder.build_load(alloca, "loadstr");
@@ -0,0 +1,76 @@
+use crate::{
+    ast::node::DestructureNode,
+    crocol::{
+        utils::{auto_deref, get_llvm_type},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    symbol_type::SymbolType,
+    CrocoError,
+};
+
+impl CrocolNode for DestructureNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        // the variables destructured at the top level of a file are global
+        let is_global = codegen.builder.get_insert_block().is_none();
+        if is_global {
+            codegen.enter_init_fn();
+        }
+
+        let value = self
+            .right
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+        let value = auto_deref(value, codegen);
+
+        let types = match value.symbol_type {
+            SymbolType::Tuple(types) if types.len() == self.var_names.len() => types,
+            value_type => {
+                return Err(CrocoError::destructure_error(
+                    &self.code_pos,
+                    &value_type,
+                    self.var_names.len(),
+                ))
+            }
+        };
+
+        let tuple_value = value.value.into_struct_value();
+
+        for (i, (var_name, el_type)) in self.var_names.iter().zip(types).enumerate() {
+            if let Some(var_name) = var_name {
+                // the variables are copies of the tuple elements
+                let el = codegen
+                    .builder
+                    .build_extract_value(tuple_value, i as u32, "extracttuple")
+                    .unwrap();
+
+                let llvm_type = get_llvm_type(&el_type, codegen);
+                let ptr = if is_global {
+                    codegen.create_global(llvm_type, var_name)
+                } else {
+                    codegen.create_block_alloca(llvm_type, var_name)
+                };
+                codegen.build_decl_store(ptr, el);
+
+                codegen
+                    .symtable
+                    .insert_symbol(
+                        var_name,
+                        LSymbol {
+                            value: ptr.into(),
+                            symbol_type: el_type,
+                        },
+                    )
+                    .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+            }
+        }
+
+        if is_global {
+            codegen.leave_init_fn();
+        }
+
+        Ok(LNodeResult::Void)
+    }
+}
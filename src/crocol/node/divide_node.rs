@@ -1,9 +1,6 @@
-use crate::crocol::CrocolNode;
+use crate::crocol::{utils::build_widened_values, CrocolNode};
+use crate::crocol::{LCodegen, LNodeResult, LSymbol};
 use crate::{ast::node::DivideNode, error::CrocoError};
-use crate::{
-    crocol::{LCodegen, LNodeResult, LSymbol},
-    symbol_type::SymbolType,
-};
 
 impl CrocolNode for DivideNode {
     fn crocol<'ctx>(
@@ -24,33 +21,42 @@ impl CrocolNode for DivideNode {
             .crocol(codegen)?
             .into_symbol(codegen, &self.code_pos)?;
 
-        let symbol = match (left.symbol_type, right.symbol_type) {
-            (SymbolType::Fnum, SymbolType::Fnum) => LSymbol {
-                value: codegen
-                    .builder
-                    .build_float_div(
-                        left.value.into_float_value(),
-                        right.value.into_float_value(),
-                        "fdiv",
-                    )
-                    .into(),
-                symbol_type: SymbolType::Fnum,
-            },
+        // numbers of different sizes are widened to the same type
+        let (left, right) = build_widened_values(left, right, codegen)
+            .ok_or_else(|| CrocoError::divide_error(&self.code_pos))?;
 
-            (SymbolType::Num, SymbolType::Num) => LSymbol {
-                value: codegen
-                    .builder
-                    .build_int_signed_div(
-                        left.value.into_int_value(),
-                        right.value.into_int_value(),
-                        "div",
-                    )
-                    .into(),
-                symbol_type: SymbolType::Num,
-            },
-
-            _ => return Err(CrocoError::divide_error(&self.code_pos)),
+        let value = if left.symbol_type.is_float() {
+            codegen
+                .builder
+                .build_float_div(
+                    left.value.into_float_value(),
+                    right.value.into_float_value(),
+                    "fdiv",
+                )
+                .into()
+        } else if left.symbol_type.is_unsigned() {
+            codegen
+                .builder
+                .build_int_unsigned_div(
+                    left.value.into_int_value(),
+                    right.value.into_int_value(),
+                    "udiv",
+                )
+                .into()
+        } else {
+            codegen
+                .builder
+                .build_int_signed_div(
+                    left.value.into_int_value(),
+                    right.value.into_int_value(),
+                    "div",
+                )
+                .into()
         };
-        Ok(LNodeResult::Value(symbol))
+
+        Ok(LNodeResult::Value(LSymbol {
+            value,
+            symbol_type: left.symbol_type,
+        }))
     }
 }
@@ -1,6 +1,9 @@
 use crate::{
     ast::node::DotFieldNode,
-    crocol::{utils::auto_deref, CrocolNode, LCodegen, LNodeResult, LSymbol},
+    crocol::{
+        utils::{auto_deref, build_optional_value, get_llvm_type},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
     symbol_type::SymbolType,
     CrocoError,
 };
@@ -19,6 +22,10 @@ impl CrocolNode for DotFieldNode {
 
         struct_ptr = auto_deref(struct_ptr, codegen);
 
+        if self.optional {
+            return self.optional_field(struct_ptr, codegen);
+        }
+
         match struct_ptr.symbol_type {
             SymbolType::Struct(struct_name) => {
                 let struct_decl = codegen
@@ -47,7 +54,143 @@ impl CrocolNode for DotFieldNode {
                 }))
             }
 
+            SymbolType::Tuple(types) => {
+                let index = self
+                    .field_name
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|index| *index < types.len())
+                    .ok_or_else(|| CrocoError::no_field_error(&self.field_name, &self.code_pos))?;
+
+                let el_ptr = codegen
+                    .builder
+                    .build_struct_gep(
+                        struct_ptr.value.into_pointer_value(),
+                        index as u32,
+                        "geptuple",
+                    )
+                    .unwrap();
+
+                Ok(LNodeResult::Variable(LSymbol {
+                    value: el_ptr.into(),
+                    symbol_type: types[index].clone(),
+                }))
+            }
+
+            // the struct behind a trait is only reachable through the trait methods
+            SymbolType::Trait(trait_name) => Err(CrocoError::new(
+                &self.code_pos,
+                format!(
+                    "can't access the field {} through the trait {}",
+                    self.field_name, trait_name
+                ),
+            )),
+
+            SymbolType::Optional(_) => Err(CrocoError::optional_field_error(
+                &self.code_pos,
+                &self.field_name,
+            )),
+
             _ => unimplemented!(),
         }
     }
 }
+
+impl DotFieldNode {
+    /// Accesses a field through an optional struct, e.g node?.next
+    fn optional_field<'ctx>(
+        &self,
+        optional_ptr: LSymbol<'ctx>,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let current_fn = codegen.current_fn.unwrap();
+
+        let struct_name = match optional_ptr.symbol_type {
+            SymbolType::Optional(some_type) => match *some_type {
+                SymbolType::Struct(struct_name) => struct_name,
+                _ => return Err(CrocoError::no_field_error(&self.field_name, &self.code_pos)),
+            },
+            _ => return Err(CrocoError::optional_error(&self.code_pos)),
+        };
+
+        let struct_decl = codegen
+            .symtable
+            .get_struct_decl(&struct_name)
+            .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+
+        let index = struct_decl
+            .fields
+            .keys()
+            .position(|field_name| field_name == &self.field_name)
+            .ok_or_else(|| CrocoError::no_field_error(&self.field_name, &self.code_pos))?;
+
+        // an optional field isn't wrapped in another optional
+        let field_type = struct_decl.fields.get(&self.field_name).unwrap().clone();
+        let result_type = match field_type {
+            SymbolType::Optional(_) => field_type.clone(),
+            _ => SymbolType::Optional(Box::new(field_type.clone())),
+        };
+
+        // here is the intended result for reference:
+        //
+        // entry:
+        //   %struct = load %optional
+        //   %issome = icmp ne %struct null
+        //   br %issome dotsome dotnone
+        //
+        // dotsome:
+        //   %field = gep %struct ...
+        //   store %field %dotres
+        //   br enddot
+        //
+        // dotnone:
+        //   store null %dotres
+        //   br enddot
+        let result_llvm_type = get_llvm_type(&result_type, codegen);
+        let result_ptr = codegen.create_block_alloca(result_llvm_type, "dotres");
+
+        let struct_ptr = codegen
+            .builder
+            .build_load(optional_ptr.value.into_pointer_value(), "loadoptional")
+            .into_pointer_value();
+        let is_some = codegen.builder.build_is_not_null(struct_ptr, "issome");
+
+        let some_block = codegen.context.append_basic_block(current_fn, "dotsome");
+        let none_block = codegen.context.append_basic_block(current_fn, "dotnone");
+        let end_block = codegen.context.append_basic_block(current_fn, "enddot");
+        codegen
+            .builder
+            .build_conditional_branch(is_some, some_block, none_block);
+
+        codegen.builder.position_at_end(some_block);
+        let field_ptr = codegen
+            .builder
+            .build_struct_gep(struct_ptr, index as u32, "gepdotfield")
+            .unwrap();
+        let field = LSymbol {
+            value: codegen.builder.build_load(field_ptr, "loadfield"),
+            symbol_type: field_type,
+        };
+        let field = match field.symbol_type {
+            SymbolType::Optional(_) => field,
+            _ => build_optional_value(field, codegen),
+        };
+        codegen.builder.build_store(result_ptr, field.value);
+        codegen.builder.build_unconditional_branch(end_block);
+
+        codegen.builder.position_at_end(none_block);
+        codegen.builder.build_store(
+            result_ptr,
+            result_llvm_type.into_pointer_type().const_null(),
+        );
+        codegen.builder.build_unconditional_branch(end_block);
+
+        codegen.builder.position_at_end(end_block);
+        let value = codegen.builder.build_load(result_ptr, "loaddot");
+
+        Ok(LNodeResult::Value(LSymbol {
+            value,
+            symbol_type: result_type,
+        }))
+    }
+}
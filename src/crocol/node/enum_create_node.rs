@@ -0,0 +1,92 @@
+use inkwell::AddressSpace;
+
+use crate::{
+    ast::node::EnumCreateNode,
+    crocol::{
+        utils::{coerce_to_type, get_or_define_enum, get_or_define_variant_struct},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    symbol_type::SymbolType,
+    CrocoError,
+};
+
+impl CrocolNode for EnumCreateNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let enum_decl = codegen
+            .symtable
+            .get_enum_decl(&self.enum_type)
+            .map_err(|e| CrocoError::new(&self.code_pos, e))?
+            .clone();
+
+        let (tag, payload_types) = enum_decl.get_variant(&self.variant).ok_or_else(|| {
+            CrocoError::no_variant_error(&self.code_pos, &self.enum_type, &self.variant)
+        })?;
+
+        if payload_types.len() != self.payload.len() {
+            return Err(CrocoError::variant_payload_error(
+                &self.code_pos,
+                &self.variant,
+            ));
+        }
+
+        let enum_ty = get_or_define_enum(&self.enum_type, &enum_decl, codegen);
+        let alloca = codegen.create_block_alloca(enum_ty.into(), "allocaenum");
+
+        let tag_ptr = codegen
+            .builder
+            .build_struct_gep(alloca, 0, "geptag")
+            .unwrap();
+        codegen.builder.build_store(
+            tag_ptr,
+            codegen.context.i32_type().const_int(tag as u64, false),
+        );
+
+        if !payload_types.is_empty() {
+            // the values are stored in the room after the tag, laid out as the variant struct
+            let variant_ty = get_or_define_variant_struct(
+                &self.enum_type,
+                &self.variant,
+                payload_types,
+                codegen,
+            );
+            let payload_ptr = codegen
+                .builder
+                .build_struct_gep(alloca, 1, "geppayload")
+                .unwrap();
+            let variant_ptr = codegen
+                .builder
+                .build_bitcast(
+                    payload_ptr,
+                    variant_ty.ptr_type(AddressSpace::Generic),
+                    "variantptr",
+                )
+                .into_pointer_value();
+
+            for (i, (node, value_type)) in self.payload.iter_mut().zip(payload_types).enumerate() {
+                let value = node.crocol(codegen)?.into_symbol(codegen, &self.code_pos)?;
+                let value = coerce_to_type(value, value_type, codegen, &self.code_pos)?;
+
+                if value.symbol_type != *value_type {
+                    return Err(CrocoError::variant_payload_error(
+                        &self.code_pos,
+                        &self.variant,
+                    ));
+                }
+
+                let value_ptr = codegen
+                    .builder
+                    .build_struct_gep(variant_ptr, i as u32, "gepvalue")
+                    .unwrap();
+                codegen.builder.build_store(value_ptr, value.value);
+            }
+        }
+
+        Ok(LNodeResult::Variable(LSymbol {
+            value: alloca.into(),
+            symbol_type: SymbolType::Enum(self.enum_type.clone()),
+        }))
+    }
+}
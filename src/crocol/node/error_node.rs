@@ -0,0 +1,29 @@
+use crate::{
+    ast::node::ErrorNode,
+    crocol::{utils::auto_deref, CrocolNode, LCodegen, LNodeResult, LSymbol},
+    symbol_type::SymbolType,
+    CrocoError,
+};
+
+impl CrocolNode for ErrorNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let message = self
+            .message
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+        let message = auto_deref(message, codegen);
+
+        if message.symbol_type != SymbolType::Str {
+            return Err(CrocoError::error_message_error(&self.code_pos));
+        }
+
+        // an error is represented by its message until it's turned into a result
+        Ok(LNodeResult::Value(LSymbol {
+            value: message.value,
+            symbol_type: SymbolType::Error,
+        }))
+    }
+}
@@ -0,0 +1,191 @@
+use inkwell::{types::BasicType, AddressSpace, IntPredicate};
+
+use crate::{
+    ast::node::{ForIterable, ForNode},
+    crocol::{
+        utils::{auto_deref, get_llvm_type},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    symbol_type::SymbolType,
+    CrocoError,
+};
+
+impl CrocolNode for ForNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let current_fn = codegen.current_fn.unwrap();
+
+        // a hidden counter drives the loop, so the loop variable can be mutated freely in the body.
+        // for ranges it holds the current num, for arrays the current index.
+        let counter_ptr;
+        let end_value;
+        let predicate;
+        let var_type;
+        let mut array_ptr = None;
+
+        match &mut self.iterable {
+            ForIterable::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                let start = start
+                    .crocol(codegen)?
+                    .into_symbol(codegen, &self.code_pos)?
+                    .into_num(&self.code_pos)
+                    .map_err(|_| CrocoError::range_error(&self.code_pos))?;
+
+                end_value = end
+                    .crocol(codegen)?
+                    .into_symbol(codegen, &self.code_pos)?
+                    .into_num(&self.code_pos)
+                    .map_err(|_| CrocoError::range_error(&self.code_pos))?;
+
+                counter_ptr =
+                    codegen.create_block_alloca(codegen.context.i32_type().into(), "forcounter");
+                codegen.builder.build_store(counter_ptr, start);
+
+                predicate = if *inclusive {
+                    IntPredicate::SLE
+                } else {
+                    IntPredicate::SLT
+                };
+                var_type = SymbolType::Num;
+            }
+
+            ForIterable::Array(array) => {
+                let array_symbol = array
+                    .crocol(codegen)?
+                    .into_pointer(codegen, &self.code_pos)?;
+                let array_symbol = auto_deref(array_symbol, codegen);
+
+                var_type = match array_symbol.symbol_type {
+                    SymbolType::Array(el_type) => *el_type,
+                    _ => return Err(CrocoError::iterable_error(&self.code_pos)),
+                };
+
+                let array_len_ptr = codegen
+                    .builder
+                    .build_struct_gep(array_symbol.value.into_pointer_value(), 1, "arraylenptr")
+                    .unwrap();
+                end_value = codegen
+                    .builder
+                    .build_load(array_len_ptr, "arraylen")
+                    .into_int_value();
+
+                counter_ptr = codegen.create_block_alloca(codegen.ptr_size.into(), "forindex");
+                codegen
+                    .builder
+                    .build_store(counter_ptr, codegen.ptr_size.const_zero());
+
+                predicate = IntPredicate::ULT;
+                array_ptr = Some(array_symbol.value.into_pointer_value());
+            }
+        }
+
+        let var_llvm_type = get_llvm_type(&var_type, codegen);
+        let var_ptr = codegen.create_block_alloca(var_llvm_type, &self.var_name);
+
+        let cond_block = codegen.context.append_basic_block(current_fn, "forcond");
+        let for_block = codegen.context.append_basic_block(current_fn, "for");
+        let incr_block = codegen.context.append_basic_block(current_fn, "forincr");
+        let end_block = codegen.context.append_basic_block(current_fn, "endfor");
+
+        // set in the codegen the current loop, and restore the enclosing one afterwards.
+        // continue jumps to the increment block so the counter is always updated.
+        let outer_loop_block = codegen.current_loop_block.replace(incr_block);
+        let outer_loop_end_block = codegen.current_loop_end_block.replace(end_block);
+
+        // build the condition block
+        codegen.builder.build_unconditional_branch(cond_block);
+        codegen.builder.position_at_end(cond_block);
+
+        let counter = codegen
+            .builder
+            .build_load(counter_ptr, "loadcounter")
+            .into_int_value();
+        let cond = codegen
+            .builder
+            .build_int_compare(predicate, counter, end_value, "cmpfor");
+        codegen
+            .builder
+            .build_conditional_branch(cond, for_block, end_block);
+
+        // build the for body
+        codegen.builder.position_at_end(for_block);
+
+        let var_value = match array_ptr {
+            None => counter.into(),
+
+            Some(array_ptr) => {
+                let heap_ptr_ptr = codegen
+                    .builder
+                    .build_struct_gep(array_ptr, 0, "arrayheapptrptr")
+                    .unwrap();
+                let heap_ptr = codegen.builder.build_load(heap_ptr_ptr, "heapvoidptr");
+                let bitcast_ptr = codegen.builder.build_bitcast(
+                    heap_ptr,
+                    var_llvm_type.ptr_type(AddressSpace::Generic),
+                    "heapelptr",
+                );
+
+                // SAFETY: the counter is always lower than the array length
+                let el_ptr = unsafe {
+                    codegen
+                        .builder
+                        .build_gep(bitcast_ptr.into_pointer_value(), &[counter], "elptr")
+                };
+
+                codegen.builder.build_load(el_ptr, "loadel")
+            }
+        };
+
+        codegen.build_decl_store(var_ptr, var_value);
+
+        // the loop variable lives in its own scope, around the body scope
+        codegen.symtable.add_scope();
+        codegen
+            .symtable
+            .insert_symbol(
+                &self.var_name,
+                LSymbol {
+                    value: var_ptr.into(),
+                    symbol_type: var_type,
+                },
+            )
+            .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+
+        let value = self.body.crocol(codegen)?;
+        codegen.symtable.drop_scope();
+
+        match value {
+            // as in the while node, do not put two terminators next to each other.
+            LNodeResult::Return(_) | LNodeResult::Continue | LNodeResult::Break => (),
+            _ => {
+                codegen.builder.build_unconditional_branch(incr_block);
+            }
+        }
+
+        // build the increment block
+        codegen.builder.position_at_end(incr_block);
+        let counter = codegen
+            .builder
+            .build_load(counter_ptr, "loadcounter")
+            .into_int_value();
+        let incremented = codegen.builder.build_int_add(
+            counter,
+            counter.get_type().const_int(1, false),
+            "incrcounter",
+        );
+        codegen.builder.build_store(counter_ptr, incremented);
+        codegen.builder.build_unconditional_branch(cond_block);
+
+        codegen.current_loop_block = outer_loop_block;
+        codegen.current_loop_end_block = outer_loop_end_block;
+
+        codegen.builder.position_at_end(end_block);
+        Ok(LNodeResult::Void)
+    }
+}
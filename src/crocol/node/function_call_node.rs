@@ -1,9 +1,25 @@
+use std::{collections::HashMap, convert::TryFrom};
+
+use inkwell::{
+    types::BasicType,
+    values::{BasicValueEnum, CallableValue, FunctionValue, IntValue, PointerValue},
+    AddressSpace, IntPredicate,
+};
+
+use crate::ast::BackendNode;
 use crate::crocol::{
-    utils::{auto_deref, get_or_define_function, get_or_define_struct},
+    utils::{
+        auto_deref, build_compare, build_map_key_ptr, coerce_to_type, get_llvm_closure_fn_type,
+        get_llvm_fn_type, get_llvm_type, get_or_define_function, get_or_define_struct,
+        throw_runtime_error,
+    },
     LCodegen, LNodeResult, LSymbol,
 };
 use crate::error::CrocoError;
+use crate::parser::TypedArg;
+use crate::symbol::FunctionDecl;
 use crate::symbol_type::SymbolType;
+use crate::token::{CodePos, OperatorEnum};
 use crate::{ast::node::*, crocol::CrocolNode};
 
 impl CrocolNode for FunctionCallNode {
@@ -12,7 +28,13 @@ impl CrocolNode for FunctionCallNode {
         codegen: &mut LCodegen<'ctx>,
     ) -> Result<LNodeResult<'ctx>, CrocoError> {
         let mut visited_args = Vec::with_capacity(self.args.len());
-        let fn_name;
+        let mut fn_name;
+        let mut fn_decl;
+        // the function pointer to call when dispatching a trait method or calling a function value
+        let mut fn_ptr = None;
+        // the environment passed to a function value
+        let mut closure_env = None;
+
         // if we're dealing with a method, inject self as the first argument
         if let Some(method_self) = self.method.as_mut() {
             let mut method_symbol = method_self
@@ -21,32 +43,154 @@ impl CrocolNode for FunctionCallNode {
 
             method_symbol = auto_deref(method_symbol, codegen);
 
-            fn_name = match method_symbol.symbol_type {
-                SymbolType::Struct(struct_name) => format!("_{}_{}", struct_name, self.fn_name),
-                SymbolType::Str => format!("_str_{}", &self.fn_name),
-                SymbolType::Fnum => format!("_num_{}", &self.fn_name),
-                SymbolType::Bool => format!("_bool_{}", &self.fn_name),
-                _ => unimplemented!(),
-            };
+            // the signatures of the map methods depend on the map types, so they're not declared
+            if let SymbolType::Map(_, _) = method_symbol.symbol_type {
+                return build_map_method(
+                    &self.fn_name,
+                    method_symbol,
+                    &mut self.args,
+                    codegen,
+                    &self.code_pos,
+                );
+            }
+
+            // same for the array methods, which depend on the element type
+            if let SymbolType::Array(_) = method_symbol.symbol_type {
+                return build_array_method(
+                    &self.fn_name,
+                    method_symbol,
+                    &mut self.args,
+                    codegen,
+                    &self.code_pos,
+                );
+            }
+
+            if let SymbolType::Trait(trait_name) = &method_symbol.symbol_type {
+                let (decl, data_ptr, method_ptr) = build_trait_method_ptr(
+                    trait_name,
+                    &self.fn_name,
+                    method_symbol.value.into_pointer_value(),
+                    codegen,
+                    &self.code_pos,
+                )?;
 
-            visited_args.push(method_symbol.value);
+                fn_name = self.fn_name.clone();
+                fn_decl = decl;
+                fn_ptr = Some(method_ptr);
+                visited_args.push(data_ptr.into());
+            } else {
+                let no_method_error = || {
+                    CrocoError::new(
+                        &self.code_pos,
+                        &format!("no method called {}", self.fn_name),
+                    )
+                };
+
+                fn_name = match &method_symbol.symbol_type {
+                    SymbolType::Struct(struct_name) => {
+                        format!("_{}_{}", struct_name, self.fn_name)
+                    }
+                    // the methods of the primitives are prefixed by their type, e.g _str_len
+                    primitive => format!("_{}_{}", primitive, self.fn_name),
+                };
+
+                fn_decl = codegen
+                    .symtable
+                    .get_function_decl(&fn_name)
+                    .map_err(|_| no_method_error())?
+                    .clone();
+
+                // the primitives other than strs are passed by value
+                let self_value = if method_symbol.symbol_type.is_number()
+                    || method_symbol.symbol_type == SymbolType::Bool
+                {
+                    codegen
+                        .builder
+                        .build_load(method_symbol.value.into_pointer_value(), "loadself")
+                } else {
+                    method_symbol.value
+                };
+
+                visited_args.push(self_value);
+            }
         } else {
             fn_name = self.fn_name.clone();
+
+            // a variable holding a function value shadows the named functions
+            let fn_value = match codegen.symtable.get_symbol(&fn_name) {
+                Ok(symbol) => {
+                    let symbol = LNodeResult::Variable(symbol.clone())
+                        .into_symbol(codegen, &self.code_pos)?;
+                    Some(auto_deref(symbol, codegen))
+                }
+                Err(_) => None,
+            };
+
+            match fn_value {
+                Some(LSymbol {
+                    value,
+                    symbol_type: SymbolType::Function(fn_type),
+                }) => {
+                    fn_decl = FunctionDecl::from_type(&fn_type);
+
+                    let closure_value = value.into_struct_value();
+                    let void_fn_ptr = codegen
+                        .builder
+                        .build_extract_value(closure_value, 0, "closurefn")
+                        .unwrap();
+                    closure_env =
+                        codegen
+                            .builder
+                            .build_extract_value(closure_value, 1, "closureenv");
+
+                    let fn_ty = get_llvm_closure_fn_type(&fn_decl, codegen);
+                    fn_ptr = Some(
+                        codegen
+                            .builder
+                            .build_bitcast(
+                                void_fn_ptr,
+                                fn_ty.ptr_type(AddressSpace::Generic),
+                                "fnptr",
+                            )
+                            .into_pointer_value(),
+                    );
+                }
+
+                _ => {
+                    fn_decl = codegen
+                        .symtable
+                        .get_function_decl(&fn_name)
+                        .map_err(|e| match fn_value {
+                            Some(_) => CrocoError::not_callable_error(&self.code_pos, &fn_name),
+                            None => CrocoError::new(&self.code_pos, e),
+                        })?
+                        .clone();
+                }
+            }
         };
 
-        let fn_decl = codegen
-            .symtable
-            .get_function_decl(&fn_name)
-            .map_err(|e| CrocoError::new(&self.code_pos, e))?
-            .clone();
+        // the self argument is already resolved
+        let self_args = visited_args.len();
 
-        for (i, (arg, arg_decl)) in self.args.iter_mut().zip(&fn_decl.args).enumerate() {
+        // the type parameters are bound to the types of the arguments
+        let mut generics = HashMap::new();
+
+        for (i, (arg, arg_decl)) in self
+            .args
+            .iter_mut()
+            .zip(fn_decl.args.iter().skip(self_args))
+            .enumerate()
+        {
             let mut value = arg.crocol(codegen)?.into_symbol(codegen, &self.code_pos)?;
+            value = coerce_to_type(value, &arg_decl.arg_type, codegen, &self.code_pos)?;
 
-            if value.symbol_type != arg_decl.arg_type {
+            if !arg_decl
+                .arg_type
+                .bind_generics(&value.symbol_type, &mut generics)
+            {
                 return Err(CrocoError::parameter_error(
                     &self.code_pos,
-                    i,
+                    i + self_args,
                     self.method.is_some(),
                 ));
             }
@@ -54,7 +198,14 @@ impl CrocolNode for FunctionCallNode {
             // To comply with the C ABI we pass struct values as pointers
             // It gets memcpy'ied later in the callee.
             value = match value.symbol_type {
-                SymbolType::Struct(_) | SymbolType::Str => {
+                SymbolType::Struct(_)
+                | SymbolType::Str
+                | SymbolType::Array(_)
+                | SymbolType::Map(_, _)
+                | SymbolType::Trait(_)
+                | SymbolType::Enum(_)
+                | SymbolType::Result(_)
+                | SymbolType::Tuple(_) => {
                     let alloca = codegen.create_block_alloca(value.value.get_type(), "tmpstruct");
                     codegen.builder.build_store(alloca, value.value);
 
@@ -64,13 +215,33 @@ impl CrocolNode for FunctionCallNode {
                     }
                 }
 
-                SymbolType::Bool | SymbolType::Fnum | SymbolType::Num => value,
+                SymbolType::Bool
+                | SymbolType::Fnum
+                | SymbolType::Num
+                | SymbolType::I64
+                | SymbolType::U64
+                | SymbolType::U8
+                | SymbolType::F64
+                | SymbolType::Optional(_)
+                | SymbolType::Function(_) => value,
                 _ => unimplemented!(),
             };
 
             visited_args.push(value.value);
         }
 
+        // the trailing optional arguments can be omitted, and are then none
+        while let Some(TypedArg {
+            arg_type: SymbolType::Optional(some_type),
+            ..
+        }) = fn_decl.args.get(visited_args.len())
+        {
+            let none_value = get_llvm_type(some_type, codegen)
+                .ptr_type(AddressSpace::Generic)
+                .const_null();
+            visited_args.push(none_value.into());
+        }
+
         if visited_args.len() != fn_decl.args.len() {
             return Err(CrocoError::mismatched_number_of_arguments_error(
                 &self.code_pos,
@@ -79,23 +250,50 @@ impl CrocolNode for FunctionCallNode {
             ));
         }
 
-        let function = get_or_define_function(&fn_name, &fn_decl, codegen);
+        // the environment of a function value is always the last argument
+        if let Some(env) = closure_env {
+            visited_args.push(env);
+        }
+
+        // a generic function is called through its instance for these types
+        if fn_decl.is_generic() {
+            fn_decl = fn_decl.resolve_generics(&generics);
+            fn_name = codegen.instantiate_generic_fn(&fn_name, fn_decl.clone(), &self.code_pos)?;
+        }
+
+        let function = match fn_ptr {
+            Some(fn_ptr) => CallableValue::try_from(fn_ptr).unwrap(),
+            None => get_or_define_function(&fn_name, &fn_decl, codegen).into(),
+        };
 
         // to conform to the "C ABI",
         // we change `Struct fn()` to `void fn(Struct*)`
         // TODO: don't do it for small structs
         let maybe_ret_alloca = match fn_decl.return_type {
-            Some(SymbolType::Str) | Some(SymbolType::Struct(_)) => {
-                let ty = if let SymbolType::Struct(struct_name) =
-                    fn_decl.return_type.as_ref().unwrap()
-                {
-                    let struct_ty = codegen
-                        .symtable
-                        .get_struct_decl(&struct_name)
-                        .map_err(|e| CrocoError::new(&self.code_pos, e))?;
-                    get_or_define_struct(&struct_name, struct_ty, codegen)
-                } else {
-                    codegen.str_type
+            Some(SymbolType::Str)
+            | Some(SymbolType::Array(_))
+            | Some(SymbolType::Map(_, _))
+            | Some(SymbolType::Struct(_))
+            | Some(SymbolType::Trait(_))
+            | Some(SymbolType::Enum(_))
+            | Some(SymbolType::Result(_))
+            | Some(SymbolType::Tuple(_)) => {
+                let ty = match fn_decl.return_type.as_ref().unwrap() {
+                    SymbolType::Struct(struct_name) => {
+                        let struct_ty = codegen
+                            .symtable
+                            .get_struct_decl(&struct_name)
+                            .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+                        get_or_define_struct(&struct_name, struct_ty, codegen)
+                    }
+                    SymbolType::Trait(_) => codegen.trait_type,
+                    SymbolType::Map(_, _) => codegen.map_type,
+                    ret_type @ SymbolType::Result(_)
+                    | ret_type @ SymbolType::Enum(_)
+                    | ret_type @ SymbolType::Tuple(_) => {
+                        get_llvm_type(ret_type, codegen).into_struct_type()
+                    }
+                    _ => codegen.str_type,
                 };
 
                 let alloca = codegen.create_block_alloca(ty.into(), "sret");
@@ -103,10 +301,32 @@ impl CrocolNode for FunctionCallNode {
                 visited_args.insert(0, alloca.into());
                 Some(alloca)
             }
-            None | Some(SymbolType::Bool) | Some(SymbolType::Fnum) | Some(SymbolType::Num) => None,
+            None
+            | Some(SymbolType::Bool)
+            | Some(SymbolType::Fnum)
+            | Some(SymbolType::Num)
+            | Some(SymbolType::I64)
+            | Some(SymbolType::U64)
+            | Some(SymbolType::U8)
+            | Some(SymbolType::F64)
+            | Some(SymbolType::Optional(_))
+            | Some(SymbolType::Function(_)) => None,
             _ => unimplemented!(),
         };
 
+        // the std knows the arrays as CrocoArray, while crocol uses the same type for the arrays and the strs
+        if fn_ptr.is_none() {
+            let llvm_fn = get_or_define_function(&fn_name, &fn_decl, codegen);
+
+            for (arg, param) in visited_args.iter_mut().zip(llvm_fn.get_param_iter()) {
+                if arg.is_pointer_value() && arg.get_type() != param.get_type() {
+                    *arg = codegen
+                        .builder
+                        .build_bitcast(*arg, param.get_type(), "castarg");
+                }
+            }
+        }
+
         let res = codegen
             .builder
             .build_call(function, &visited_args, "callfn");
@@ -138,3 +358,664 @@ impl CrocolNode for FunctionCallNode {
         }
     }
 }
+
+/// Looks up a trait method in the vtable of a trait object.
+/// Returns the method declaration, the self pointer and the function pointer to call.
+fn build_trait_method_ptr<'ctx>(
+    trait_name: &str,
+    method_name: &str,
+    trait_ptr: PointerValue<'ctx>,
+    codegen: &mut LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<(FunctionDecl, PointerValue<'ctx>, PointerValue<'ctx>), CrocoError> {
+    let trait_decl = codegen
+        .symtable
+        .get_trait_decl(trait_name)
+        .map_err(|e| CrocoError::new(code_pos, e))?;
+
+    // the vtable is ordered like the trait methods
+    let method_index = trait_decl
+        .methods
+        .keys()
+        .position(|name| name == method_name)
+        .ok_or_else(|| CrocoError::new(code_pos, format!("no method called {}", method_name)))?;
+
+    // the struct behind the trait is erased, so the callee receives an opaque self pointer
+    let mut fn_decl = trait_decl.methods[method_name].clone();
+    fn_decl.args.insert(
+        0,
+        TypedArg {
+            arg_name: "self".to_owned(),
+            arg_type: SymbolType::Ref(Box::new(SymbolType::Trait(trait_name.to_owned()))),
+        },
+    );
+
+    let data_ptr_ptr = codegen
+        .builder
+        .build_struct_gep(trait_ptr, 0, "geptraitdata")
+        .unwrap();
+    let data_ptr = codegen.builder.build_load(data_ptr_ptr, "traitdata");
+    let self_ptr = codegen
+        .builder
+        .build_bitcast(
+            data_ptr,
+            codegen.trait_type.ptr_type(AddressSpace::Generic),
+            "traitself",
+        )
+        .into_pointer_value();
+
+    let vtable_ptr_ptr = codegen
+        .builder
+        .build_struct_gep(trait_ptr, 1, "geptraitvtable")
+        .unwrap();
+    let vtable_ptr = codegen
+        .builder
+        .build_load(vtable_ptr_ptr, "traitvtable")
+        .into_pointer_value();
+
+    // SAFETY: the index comes from the trait declaration, which has been used to build the vtable
+    let method_ptr_ptr = unsafe {
+        codegen.builder.build_gep(
+            vtable_ptr,
+            &[codegen.ptr_size.const_int(method_index as u64, false)],
+            "gepmethod",
+        )
+    };
+    let method_ptr = codegen.builder.build_load(method_ptr_ptr, "loadmethod");
+
+    let fn_ty = get_llvm_fn_type(&fn_decl, codegen);
+    let fn_ptr = codegen
+        .builder
+        .build_bitcast(
+            method_ptr,
+            fn_ty.ptr_type(AddressSpace::Generic),
+            "methodptr",
+        )
+        .into_pointer_value();
+
+    Ok((fn_decl, self_ptr, fn_ptr))
+}
+
+/// Builds a call to one of the builtin map methods
+fn build_map_method<'ctx>(
+    fn_name: &str,
+    map: LSymbol<'ctx>,
+    args: &mut [Box<dyn BackendNode>],
+    codegen: &mut LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<LNodeResult<'ctx>, CrocoError> {
+    let (key_type, value_type) = match map.symbol_type {
+        SymbolType::Map(key_type, value_type) => (key_type, value_type),
+        _ => unreachable!(),
+    };
+
+    let args_len = match fn_name {
+        "len" | "keys" | "values" => 0,
+        "contains" | "remove" => 1,
+        _ => {
+            return Err(CrocoError::new(
+                code_pos,
+                &format!("no method called {}", fn_name),
+            ))
+        }
+    };
+
+    if args.len() != args_len {
+        return Err(CrocoError::mismatched_number_of_arguments_error(
+            code_pos,
+            args_len,
+            args.len(),
+        ));
+    }
+
+    let map_ptr = map.value.into_pointer_value();
+
+    // the only argument a map method can take is a key
+    let key_ptr = match args.first_mut() {
+        Some(arg) => {
+            let key = arg.crocol(codegen)?.into_symbol(codegen, code_pos)?;
+
+            if key.symbol_type != *key_type {
+                return Err(CrocoError::parameter_error(code_pos, 1, true));
+            }
+
+            Some(build_map_key_ptr(&key, codegen))
+        }
+        None => None,
+    };
+
+    let value = match fn_name {
+        "len" => {
+            let len_ptr = codegen
+                .builder
+                .build_struct_gep(map_ptr, 3, "maplenptr")
+                .unwrap();
+            let len = codegen.builder.build_load(len_ptr, "maplen");
+
+            LSymbol {
+                value: codegen
+                    .builder
+                    .build_int_cast(len.into_int_value(), codegen.context.i32_type(), "lencast")
+                    .into(),
+                symbol_type: SymbolType::Num,
+            }
+        }
+
+        "contains" => {
+            let get_fn = codegen.module.get_function("_croco_map_get").unwrap();
+            let value_ptr = codegen
+                .builder
+                .build_call(
+                    get_fn,
+                    &[map_ptr.into(), key_ptr.unwrap().into()],
+                    "mapvalue",
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_pointer_value();
+
+            LSymbol {
+                value: codegen
+                    .builder
+                    .build_is_not_null(value_ptr, "cmpcontains")
+                    .into(),
+                symbol_type: SymbolType::Bool,
+            }
+        }
+
+        "remove" => {
+            let remove_fn = codegen.module.get_function("_croco_map_remove").unwrap();
+            codegen.builder.build_call(
+                remove_fn,
+                &[map_ptr.into(), key_ptr.unwrap().into()],
+                "mapremove",
+            );
+
+            return Ok(LNodeResult::Void);
+        }
+
+        "keys" | "values" => {
+            let (runtime_fn_name, el_type) = if fn_name == "keys" {
+                ("_croco_map_keys", key_type)
+            } else {
+                ("_croco_map_values", value_type)
+            };
+
+            let runtime_fn = codegen.module.get_function(runtime_fn_name).unwrap();
+            let array_alloca = codegen.create_block_alloca(codegen.array_type.into(), "array");
+
+            // the runtime knows the array as a CrocoArray
+            let array_ptr = codegen.builder.build_bitcast(
+                array_alloca,
+                runtime_fn.get_first_param().unwrap().get_type(),
+                "arrayptr",
+            );
+
+            codegen.builder.build_call(
+                runtime_fn,
+                &[array_ptr.into(), map_ptr.into()],
+                "mapentries",
+            );
+
+            LSymbol {
+                value: codegen.builder.build_load(array_alloca, "loadarr"),
+                symbol_type: SymbolType::Array(el_type),
+            }
+        }
+
+        _ => unreachable!(),
+    };
+
+    Ok(LNodeResult::Value(value))
+}
+
+/// Builds a call to one of the builtin array methods
+fn build_array_method<'ctx>(
+    fn_name: &str,
+    array: LSymbol<'ctx>,
+    args: &mut [Box<dyn BackendNode>],
+    codegen: &mut LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<LNodeResult<'ctx>, CrocoError> {
+    let el_type = match array.symbol_type {
+        SymbolType::Array(el_type) => *el_type,
+        _ => unreachable!(),
+    };
+
+    // the type of each argument, the element type being the type of the pushed or inserted values
+    let args_types = match fn_name {
+        "len" | "pop" | "reverse" => vec![],
+        "concat" => vec![SymbolType::Array(Box::new(el_type.clone()))],
+        "contains" | "index_of" | "push" => vec![el_type.clone()],
+        "join" => vec![SymbolType::Str],
+        "insert" => vec![SymbolType::Num, el_type.clone()],
+        "remove" => vec![SymbolType::Num],
+        "slice" => vec![SymbolType::Num, SymbolType::Num],
+        _ => {
+            return Err(CrocoError::new(
+                code_pos,
+                &format!("no method called {}", fn_name),
+            ))
+        }
+    };
+
+    if args.len() != args_types.len() {
+        return Err(CrocoError::mismatched_number_of_arguments_error(
+            code_pos,
+            args_types.len(),
+            args.len(),
+        ));
+    }
+
+    let mut visited_args = Vec::with_capacity(args.len());
+
+    for (i, (arg, arg_type)) in args.iter_mut().zip(args_types.iter()).enumerate() {
+        let mut value = arg.crocol(codegen)?.into_symbol(codegen, code_pos)?;
+        value = coerce_to_type(value, arg_type, codegen, code_pos)?;
+
+        if value.symbol_type != *arg_type {
+            return Err(CrocoError::parameter_error(code_pos, i + 1, true));
+        }
+
+        visited_args.push(value);
+    }
+
+    let el_llvm_type = get_llvm_type(&el_type, codegen);
+    let el_size =
+        codegen
+            .builder
+            .build_int_cast(el_llvm_type.size_of().unwrap(), codegen.ptr_size, "elsize");
+
+    let void_ptr_type = codegen.context.i8_type().ptr_type(AddressSpace::Generic);
+
+    // the runtime knows the array as a CrocoArray, which is the param at the given index
+    let array_ptr = |runtime_fn: FunctionValue<'ctx>, index: u32| {
+        codegen.builder.build_bitcast(
+            array.value,
+            runtime_fn.get_nth_param(index).unwrap().get_type(),
+            "arrayptr",
+        )
+    };
+
+    let value = match fn_name {
+        "len" => {
+            let len_ptr = codegen
+                .builder
+                .build_struct_gep(array.value.into_pointer_value(), 1, "arraylenptr")
+                .unwrap();
+            let len = codegen.builder.build_load(len_ptr, "arraylen");
+
+            LSymbol {
+                value: codegen
+                    .builder
+                    .build_int_cast(len.into_int_value(), codegen.context.i32_type(), "lencast")
+                    .into(),
+                symbol_type: SymbolType::Num,
+            }
+        }
+
+        "contains" | "index_of" => {
+            let el = visited_args.remove(0);
+            let index = build_array_index_of(&array, el, codegen, code_pos)?;
+
+            if fn_name == "contains" {
+                let found = codegen.builder.build_int_compare(
+                    IntPredicate::NE,
+                    index,
+                    index.get_type().const_all_ones(),
+                    "found",
+                );
+
+                LSymbol {
+                    value: found.into(),
+                    symbol_type: SymbolType::Bool,
+                }
+            } else {
+                LSymbol {
+                    value: index.into(),
+                    symbol_type: SymbolType::Num,
+                }
+            }
+        }
+
+        "reverse" => {
+            let reverse_fn = codegen.module.get_function("_croco_array_reverse").unwrap();
+
+            codegen.builder.build_call(
+                reverse_fn,
+                &[array_ptr(reverse_fn, 0), el_size.into()],
+                "arrayreverse",
+            );
+
+            return Ok(LNodeResult::Void);
+        }
+
+        "concat" | "slice" => {
+            let array_alloca = codegen.create_block_alloca(codegen.array_type.into(), "arrayres");
+
+            // the runtime sees the result as a CrocoArray too
+            let array_res_ptr = |runtime_fn: FunctionValue<'ctx>| {
+                codegen.builder.build_bitcast(
+                    array_alloca,
+                    runtime_fn.get_nth_param(0).unwrap().get_type(),
+                    "arrayresptr",
+                )
+            };
+
+            let (runtime_fn, runtime_args) = if fn_name == "concat" {
+                let concat_fn = codegen.module.get_function("_croco_array_concat").unwrap();
+                let other = visited_args.remove(0);
+                let other_alloca = codegen.create_block_alloca(other.value.get_type(), "other");
+                codegen.builder.build_store(other_alloca, other.value);
+
+                let other_ptr = codegen.builder.build_bitcast(
+                    other_alloca,
+                    concat_fn.get_nth_param(2).unwrap().get_type(),
+                    "otherptr",
+                );
+
+                (
+                    concat_fn,
+                    vec![
+                        array_res_ptr(concat_fn),
+                        array_ptr(concat_fn, 1),
+                        other_ptr,
+                        el_size.into(),
+                    ],
+                )
+            } else {
+                let slice_fn = codegen.module.get_function("_croco_array_slice").unwrap();
+                let start = visited_args.remove(0);
+                let end = visited_args.remove(0);
+
+                (
+                    slice_fn,
+                    vec![
+                        array_res_ptr(slice_fn),
+                        array_ptr(slice_fn, 1),
+                        start.value,
+                        end.value,
+                        el_size.into(),
+                    ],
+                )
+            };
+
+            codegen
+                .builder
+                .build_call(runtime_fn, &runtime_args, "arraycopy");
+
+            LSymbol {
+                value: codegen.builder.build_load(array_alloca, "loadarr"),
+                symbol_type: SymbolType::Array(Box::new(el_type)),
+            }
+        }
+
+        "join" => {
+            if el_type != SymbolType::Str {
+                return Err(CrocoError::new(
+                    code_pos,
+                    "only an array of str can be joined",
+                ));
+            }
+
+            let join_fn = codegen.module.get_function("_croco_array_join").unwrap();
+            let str_alloca = codegen.create_block_alloca(codegen.str_type.into(), "joined");
+            let delimiter = visited_args.remove(0);
+            let delimiter_ptr =
+                codegen.create_block_alloca(delimiter.value.get_type(), "delimiter");
+            codegen.builder.build_store(delimiter_ptr, delimiter.value);
+
+            codegen.builder.build_call(
+                join_fn,
+                &[
+                    str_alloca.into(),
+                    array_ptr(join_fn, 1),
+                    delimiter_ptr.into(),
+                ],
+                "arrayjoin",
+            );
+
+            LSymbol {
+                value: codegen.builder.build_load(str_alloca, "loadjoined"),
+                symbol_type: SymbolType::Str,
+            }
+        }
+
+        "push" => {
+            let push_fn = codegen.module.get_function("_croco_array_push").unwrap();
+            let el = visited_args.remove(0);
+            let el_ptr = build_void_el_ptr(&el, codegen);
+
+            codegen.builder.build_call(
+                push_fn,
+                &[array_ptr(push_fn, 0), el_ptr.into(), el_size.into()],
+                "arraypush",
+            );
+
+            return Ok(LNodeResult::Void);
+        }
+
+        "insert" => {
+            let insert_fn = codegen.module.get_function("_croco_array_insert").unwrap();
+            let index = visited_args.remove(0);
+            let el = visited_args.remove(0);
+            let el_ptr = build_void_el_ptr(&el, codegen);
+
+            let in_bounds = codegen
+                .builder
+                .build_call(
+                    insert_fn,
+                    &[
+                        array_ptr(insert_fn, 0),
+                        index.value,
+                        el_ptr.into(),
+                        el_size.into(),
+                    ],
+                    "arrayinsert",
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap();
+
+            build_runtime_check(
+                in_bounds,
+                CrocoError::index_out_of_bounds_error(code_pos),
+                codegen,
+            );
+
+            return Ok(LNodeResult::Void);
+        }
+
+        "pop" | "remove" => {
+            let el_alloca = codegen.create_block_alloca(el_llvm_type, "removed");
+            let el_ptr = codegen
+                .builder
+                .build_bitcast(el_alloca, void_ptr_type, "voidelptr");
+
+            let (runtime_fn, runtime_args, error) = if fn_name == "pop" {
+                let pop_fn = codegen.module.get_function("_croco_array_pop").unwrap();
+                (
+                    pop_fn,
+                    vec![array_ptr(pop_fn, 0), el_ptr, el_size.into()],
+                    CrocoError::new(code_pos, "cannot pop an empty array"),
+                )
+            } else {
+                let remove_fn = codegen.module.get_function("_croco_array_remove").unwrap();
+                let index = visited_args.remove(0);
+                (
+                    remove_fn,
+                    vec![array_ptr(remove_fn, 0), index.value, el_ptr, el_size.into()],
+                    CrocoError::index_out_of_bounds_error(code_pos),
+                )
+            };
+
+            let success = codegen
+                .builder
+                .build_call(runtime_fn, &runtime_args, "arrayremove")
+                .try_as_basic_value()
+                .left()
+                .unwrap();
+
+            build_runtime_check(success, error, codegen);
+
+            LSymbol {
+                value: codegen.builder.build_load(el_alloca, "loadremoved"),
+                symbol_type: el_type,
+            }
+        }
+
+        _ => unreachable!(),
+    };
+
+    Ok(LNodeResult::Value(value))
+}
+
+/// Builds a loop looking for the index of the first element of an array equal to a value.
+/// The index is -1 if there's none
+fn build_array_index_of<'ctx>(
+    array: &LSymbol<'ctx>,
+    value: LSymbol<'ctx>,
+    codegen: &LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<IntValue<'ctx>, CrocoError> {
+    let current_fn = codegen.current_fn.unwrap();
+    let array_ptr = array.value.into_pointer_value();
+    let not_found = codegen.context.i32_type().const_all_ones();
+
+    let len_ptr = codegen
+        .builder
+        .build_struct_gep(array_ptr, 1, "arraylenptr")
+        .unwrap();
+    let len = codegen
+        .builder
+        .build_load(len_ptr, "arraylen")
+        .into_int_value();
+
+    let heap_ptr_ptr = codegen
+        .builder
+        .build_struct_gep(array_ptr, 0, "arrayheapptrptr")
+        .unwrap();
+    let heap_ptr = codegen.builder.build_load(heap_ptr_ptr, "heapvoidptr");
+    let heap_ptr = codegen
+        .builder
+        .build_bitcast(
+            heap_ptr,
+            get_llvm_type(&value.symbol_type, codegen).ptr_type(AddressSpace::Generic),
+            "heapelptr",
+        )
+        .into_pointer_value();
+
+    let res_ptr = codegen.create_block_alloca(codegen.context.i32_type().into(), "indexofres");
+    let index_ptr = codegen.create_block_alloca(codegen.ptr_size.into(), "indexofindex");
+    codegen.builder.build_store(res_ptr, not_found);
+    codegen
+        .builder
+        .build_store(index_ptr, codegen.ptr_size.const_zero());
+
+    let loop_block = codegen
+        .context
+        .append_basic_block(current_fn, "indexofloop");
+    let body_block = codegen
+        .context
+        .append_basic_block(current_fn, "indexofbody");
+    let end_block = codegen.context.append_basic_block(current_fn, "indexofend");
+
+    codegen.builder.build_unconditional_branch(loop_block);
+
+    // stop at the end of the array or at the first element found
+    codegen.builder.position_at_end(loop_block);
+    let index = codegen
+        .builder
+        .build_load(index_ptr, "index")
+        .into_int_value();
+    let res = codegen.builder.build_load(res_ptr, "res").into_int_value();
+    let in_bounds = codegen
+        .builder
+        .build_int_compare(IntPredicate::ULT, index, len, "inbounds");
+    let searching =
+        codegen
+            .builder
+            .build_int_compare(IntPredicate::EQ, res, not_found, "searching");
+    let keep_looping = codegen
+        .builder
+        .build_and(in_bounds, searching, "keeplooping");
+    codegen
+        .builder
+        .build_conditional_branch(keep_looping, body_block, end_block);
+
+    codegen.builder.position_at_end(body_block);
+
+    // SAFETY: the index is inferior to the length of the array
+    let el_ptr = unsafe { codegen.builder.build_gep(heap_ptr, &[index], "elptr") };
+    let el = LSymbol {
+        value: codegen.builder.build_load(el_ptr, "el"),
+        symbol_type: value.symbol_type.clone(),
+    };
+
+    let is_equal = build_compare(el, value, &OperatorEnum::Equals, codegen, code_pos)?;
+    let index_num = codegen
+        .builder
+        .build_int_cast(index, codegen.context.i32_type(), "indexcast");
+    let new_res = codegen
+        .builder
+        .build_select(is_equal, index_num, not_found, "newres");
+    codegen.builder.build_store(res_ptr, new_res);
+
+    let next_index =
+        codegen
+            .builder
+            .build_int_add(index, codegen.ptr_size.const_int(1, false), "nextindex");
+    codegen.builder.build_store(index_ptr, next_index);
+    codegen.builder.build_unconditional_branch(loop_block);
+
+    codegen.builder.position_at_end(end_block);
+    Ok(codegen
+        .builder
+        .build_load(res_ptr, "indexof")
+        .into_int_value())
+}
+
+/// Stores an array element to pass it to the runtime as a void pointer
+fn build_void_el_ptr<'ctx>(el: &LSymbol<'ctx>, codegen: &LCodegen<'ctx>) -> PointerValue<'ctx> {
+    let el_alloca = codegen.create_block_alloca(el.value.get_type(), "el");
+    codegen.builder.build_store(el_alloca, el.value);
+    codegen
+        .builder
+        .build_bitcast(
+            el_alloca,
+            codegen.context.i8_type().ptr_type(AddressSpace::Generic),
+            "voidelptr",
+        )
+        .into_pointer_value()
+}
+
+/// Throws a runtime error if the bool returned by a runtime function is false
+fn build_runtime_check<'ctx>(
+    success: BasicValueEnum<'ctx>,
+    error: CrocoError,
+    codegen: &LCodegen<'ctx>,
+) {
+    let current_fn = codegen.current_fn.unwrap();
+    let error_block = codegen
+        .context
+        .append_basic_block(current_fn, "runtimeerror");
+    let ok_block = codegen.context.append_basic_block(current_fn, "runtimeok");
+
+    // the C bool is an i8
+    let success_cmp = codegen.builder.build_int_compare(
+        IntPredicate::NE,
+        success.into_int_value(),
+        success.into_int_value().get_type().const_zero(),
+        "cmpsuccess",
+    );
+
+    codegen
+        .builder
+        .build_conditional_branch(success_cmp, ok_block, error_block);
+
+    codegen.builder.position_at_end(error_block);
+    throw_runtime_error(error, codegen);
+
+    codegen.builder.position_at_end(ok_block);
+}
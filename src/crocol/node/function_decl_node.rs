@@ -7,7 +7,25 @@ impl CrocolNode for FunctionDeclNode {
         &mut self,
         codegen: &mut LCodegen<'ctx>,
     ) -> Result<LNodeResult<'ctx>, CrocoError> {
-        codegen.build_function(&self.name, self.fn_body.take().unwrap(), &self.code_pos)?;
+        let fn_body = self.fn_body.take().unwrap();
+
+        let is_generic = codegen
+            .symtable
+            .get_function_decl(&self.name)
+            .map_err(|e| CrocoError::new(&self.code_pos, e))?
+            .is_generic();
+
+        // generic functions are monomorphized, they are built for each call with different types
+        if is_generic {
+            codegen
+                .generic_fns
+                .insert(self.name.clone(), (fn_body, self.code_pos.clone()));
+        } else {
+            codegen
+                .pending_fns
+                .push((self.name.clone(), fn_body, self.code_pos.clone()));
+        }
+
         Ok(LNodeResult::Void)
     }
 }
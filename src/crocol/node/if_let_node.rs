@@ -0,0 +1,110 @@
+use crate::{
+    ast::node::IfLetNode,
+    crocol::{
+        utils::{auto_deref, get_llvm_type},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    symbol_type::SymbolType,
+    CrocoError,
+};
+
+impl CrocolNode for IfLetNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let current_fn = codegen.current_fn.unwrap();
+
+        let optional = self
+            .value
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+        let optional = auto_deref(optional, codegen);
+
+        let some_type = match optional.symbol_type {
+            SymbolType::Optional(some_type) => *some_type,
+            _ => return Err(CrocoError::optional_error(&self.code_pos)),
+        };
+
+        // here is the intended result for reference:
+        //
+        // entry:
+        //   %issome = icmp ne %optional null
+        //   br %issome iflet ifletelse
+        //
+        // iflet:
+        //   %value = load %optional
+        //   ...
+        //   br endiflet
+        //
+        // ifletelse:
+        //   ...
+        //   br endiflet
+        let optional_ptr = optional.value.into_pointer_value();
+        let is_some = codegen.builder.build_is_not_null(optional_ptr, "issome");
+
+        let some_block = codegen.context.append_basic_block(current_fn, "iflet");
+        let else_block = codegen.context.append_basic_block(current_fn, "ifletelse");
+        let end_block = codegen.context.append_basic_block(current_fn, "endiflet");
+        codegen
+            .builder
+            .build_conditional_branch(is_some, some_block, else_block);
+
+        // the unwrapped value lives in its own scope, around the body scope
+        codegen.builder.position_at_end(some_block);
+        let value = codegen.builder.build_load(optional_ptr, "somevalue");
+        let value_ptr =
+            codegen.create_block_alloca(get_llvm_type(&some_type, codegen), &self.var_name);
+        codegen.build_decl_store(value_ptr, value);
+
+        codegen.symtable.add_scope();
+        codegen
+            .symtable
+            .insert_symbol(
+                &self.var_name,
+                LSymbol {
+                    value: value_ptr.into(),
+                    symbol_type: some_type,
+                },
+            )
+            .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+
+        let value = self.body.crocol(codegen)?;
+        codegen.symtable.drop_scope();
+
+        // llvm doesn't like when two terminators are in the same block.
+        // if we have a early return, we don't want to build a branch.
+        match value {
+            LNodeResult::Return(_) | LNodeResult::Continue | LNodeResult::Break => (),
+            _ => {
+                codegen.builder.build_unconditional_branch(end_block);
+            }
+        }
+
+        // the else block is taken if the optional is empty
+        else_block
+            .move_after(current_fn.get_last_basic_block().unwrap())
+            .unwrap();
+        codegen.builder.position_at_end(else_block);
+
+        let value = match &mut self.else_body {
+            Some(else_body) => else_body.crocol(codegen)?,
+            None => LNodeResult::Void,
+        };
+
+        match value {
+            LNodeResult::Return(_) | LNodeResult::Continue | LNodeResult::Break => (),
+            _ => {
+                codegen.builder.build_unconditional_branch(end_block);
+            }
+        }
+
+        // move the endiflet block at the end of all our created blocks
+        end_block
+            .move_after(current_fn.get_last_basic_block().unwrap())
+            .unwrap();
+        codegen.builder.position_at_end(end_block);
+
+        Ok(LNodeResult::Void)
+    }
+}
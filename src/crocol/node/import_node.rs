@@ -1,3 +1,27 @@
-use crate::{ast::node::ImportNode, crocol::CrocolNode};
+use crate::crocol::{stdlib::import_builtin_module, CrocolNode, LCodegen, LNodeResult};
+use crate::{ast::node::ImportNode, error::CrocoError};
 
-impl CrocolNode for ImportNode {}
+impl CrocolNode for ImportNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        // we have a relative path e.g import "./my_module"
+        // the module has already been parsed and its declarations registered,
+        // we just have to build it in the same LLVM module
+        if let Some(bottom) = &mut self.bottom {
+            bottom.crocol(codegen)?;
+            Ok(LNodeResult::Void)
+
+        // we have an absolute path e.g import "math"
+        // we are looking for a builtin module with this name
+        } else if import_builtin_module(codegen, &self.name) {
+            Ok(LNodeResult::Void)
+        } else {
+            Err(CrocoError::new(
+                &self.code_pos,
+                &format!("the builtin module {} is not supported yet", self.name),
+            ))
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use inkwell::{types::BasicType, AddressSpace};
+
+use crate::{
+    ast::node::MapCreateNode,
+    crocol::{
+        utils::{build_map_key_ptr, get_llvm_type, init_default},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    symbol_type::SymbolType,
+    CrocoError,
+};
+
+impl CrocolNode for MapCreateNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let mut visited = Vec::with_capacity(self.contents.len());
+
+        for (key, value) in &mut self.contents {
+            let key = key.crocol(codegen)?.into_symbol(codegen, &self.code_pos)?;
+            let value = value
+                .crocol(codegen)?
+                .into_symbol(codegen, &self.code_pos)?;
+            visited.push((key, value));
+        }
+
+        let key_type = visited[0].0.symbol_type.clone();
+        let value_type = visited[0].1.symbol_type.clone();
+
+        match key_type {
+            SymbolType::Num | SymbolType::Str | SymbolType::Bool => (),
+            _ => return Err(CrocoError::map_key_error(&self.code_pos)),
+        }
+
+        for (key, value) in visited.iter().skip(1) {
+            if key.symbol_type != key_type || value.symbol_type != value_type {
+                return Err(CrocoError::mixed_type_map(&self.code_pos));
+            }
+        }
+
+        let map_type = SymbolType::Map(Box::new(key_type), Box::new(value_type.clone()));
+        let alloca = codegen.create_block_alloca(codegen.map_type.into(), "map");
+
+        init_default(
+            &LSymbol {
+                value: alloca.into(),
+                symbol_type: map_type.clone(),
+            },
+            codegen,
+        );
+
+        // insert all the entries with the runtime
+        let slot_fn = codegen.module.get_function("_croco_map_slot").unwrap();
+        let value_ptr_type = get_llvm_type(&value_type, codegen).ptr_type(AddressSpace::Generic);
+
+        for (key, value) in visited {
+            let key_ptr = build_map_key_ptr(&key, codegen);
+
+            let slot_ptr = codegen
+                .builder
+                .build_call(slot_fn, &[alloca.into(), key_ptr.into()], "mapslot")
+                .try_as_basic_value()
+                .left()
+                .unwrap();
+
+            let value_ptr = codegen
+                .builder
+                .build_bitcast(slot_ptr, value_ptr_type, "mapvalueptr");
+
+            codegen
+                .builder
+                .build_store(value_ptr.into_pointer_value(), value.value);
+        }
+
+        let load = codegen.builder.build_load(alloca, "loadmap");
+        Ok(LNodeResult::Value(LSymbol {
+            value: load,
+            symbol_type: map_type,
+        }))
+    }
+}
@@ -0,0 +1,466 @@
+use inkwell::{
+    values::{IntValue, PointerValue},
+    AddressSpace, FloatPredicate, IntPredicate,
+};
+
+use crate::{
+    ast::node::{ConstantNode, MatchNode, MatchPattern},
+    crocol::{
+        utils::{auto_deref, get_or_define_variant_struct},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    symbol_type::SymbolType,
+    token::{CodePos, LiteralEnum, OperatorEnum},
+    CrocoError,
+};
+
+impl CrocolNode for MatchNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let current_fn = codegen.current_fn.unwrap();
+
+        let value = self
+            .bottom
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+        let value = auto_deref(value, codegen);
+
+        // a copy of the enum matched, which the variables of the arms point into
+        let mut enum_ptr = None;
+
+        match &value.symbol_type {
+            SymbolType::Num | SymbolType::Fnum | SymbolType::Str | SymbolType::Bool => (),
+
+            // the variant patterns must exist and all the variants must be handled
+            SymbolType::Enum(enum_name) => {
+                let enum_decl = codegen
+                    .symtable
+                    .get_enum_decl(enum_name)
+                    .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+
+                for pattern in self.arms.iter().flat_map(|arm| arm.patterns.iter()) {
+                    if let MatchPattern::Variant {
+                        enum_type,
+                        variant,
+                        bindings,
+                    } = pattern
+                    {
+                        if enum_type != enum_name {
+                            return Err(CrocoError::match_pattern_error(&self.code_pos));
+                        }
+
+                        let (_, payload_types) =
+                            enum_decl.get_variant(variant).ok_or_else(|| {
+                                CrocoError::no_variant_error(&self.code_pos, enum_type, variant)
+                            })?;
+
+                        if !bindings.is_empty() && bindings.len() != payload_types.len() {
+                            return Err(CrocoError::variant_payload_error(&self.code_pos, variant));
+                        }
+                    }
+                }
+
+                let missing = self.missing_variants(enum_decl);
+                if !missing.is_empty() {
+                    return Err(CrocoError::non_exhaustive_match_error(
+                        &self.code_pos,
+                        &missing,
+                    ));
+                }
+
+                let alloca = codegen.create_block_alloca(value.value.get_type(), "matchenum");
+                codegen.builder.build_store(alloca, value.value);
+                enum_ptr = Some(alloca);
+            }
+
+            _ => return Err(CrocoError::match_pattern_error(&self.code_pos)),
+        }
+
+        // an enum match handles all the variants, so it always takes an arm
+        let exhaustive = enum_ptr.is_some();
+
+        // here is the intended result for reference:
+        //
+        // entry:
+        //   %cond = cmp ...
+        //   br %cond matcharm1 matchtest2
+        //
+        // matcharm1:
+        //   ...
+        //   br endmatch
+        //
+        // matchtest2:
+        //   %cond = cmp ...
+        //   br %cond matcharm2 endmatch
+        //
+        // matcharm2:
+        //   ...
+        //   br endmatch
+        let before_match_block = codegen.builder.get_insert_block().unwrap();
+        let end_block = codegen.context.append_basic_block(current_fn, "endmatch");
+
+        // the pointer holding the value of the taken arm
+        let mut result: Option<LSymbol> = None;
+        // the match only has a value if all the arms give one
+        let mut all_values = true;
+        let mut has_wildcard = false;
+
+        let mut test_block = before_match_block;
+        let arms_len = self.arms.len();
+
+        for (i, arm) in self.arms.iter_mut().enumerate() {
+            codegen.builder.position_at_end(test_block);
+
+            let arm_block = codegen.context.append_basic_block(current_fn, "matcharm");
+            let next_block = if i == arms_len - 1 {
+                end_block
+            } else {
+                codegen.context.append_basic_block(current_fn, "matchtest")
+            };
+
+            // the arm is taken if any of its patterns matches
+            let mut cond: Option<IntValue> = None;
+
+            for pattern in &arm.patterns {
+                if let MatchPattern::Wildcard = pattern {
+                    has_wildcard = true;
+                }
+
+                let pattern_cond = build_pattern_cond(pattern, &value, codegen, &self.code_pos)?;
+
+                cond = Some(match cond {
+                    Some(cond) => codegen.builder.build_or(cond, pattern_cond, "orpattern"),
+                    None => pattern_cond,
+                });
+            }
+
+            codegen
+                .builder
+                .build_conditional_branch(cond.unwrap(), arm_block, next_block);
+
+            // populate the arm block
+            codegen.builder.position_at_end(arm_block);
+
+            // the values held by the variant live in their own scope, around the arm body
+            codegen.symtable.add_scope();
+
+            if let (
+                [MatchPattern::Variant {
+                    enum_type,
+                    variant,
+                    bindings,
+                }],
+                Some(enum_ptr),
+            ) = (arm.patterns.as_slice(), enum_ptr)
+            {
+                bind_variant_values(
+                    enum_ptr,
+                    enum_type,
+                    variant,
+                    bindings,
+                    codegen,
+                    &self.code_pos,
+                )?;
+            }
+
+            let arm_value = arm.body.crocol(codegen)?;
+            codegen.symtable.drop_scope();
+
+            match arm_value {
+                // llvm doesn't like when two terminators are in the same block.
+                LNodeResult::Return(_) | LNodeResult::Continue | LNodeResult::Break => (),
+
+                LNodeResult::Void => {
+                    all_values = false;
+                    codegen.builder.build_unconditional_branch(end_block);
+                }
+
+                arm_value => {
+                    let arm_symbol = arm_value.into_symbol(codegen, &self.code_pos)?;
+
+                    let result_ptr = match &result {
+                        Some(result) => {
+                            if result.symbol_type != arm_symbol.symbol_type {
+                                return Err(CrocoError::match_arms_type_error(
+                                    &self.code_pos,
+                                    &result.symbol_type,
+                                    &arm_symbol.symbol_type,
+                                ));
+                            }
+
+                            result.value.into_pointer_value()
+                        }
+
+                        // the result must be allocated before the first test so it is valid in every arm
+                        None => {
+                            let current_block = codegen.builder.get_insert_block().unwrap();
+                            codegen.builder.position_at_end(before_match_block);
+                            let alloca = codegen
+                                .create_block_alloca(arm_symbol.value.get_type(), "matchres");
+                            codegen.builder.position_at_end(current_block);
+
+                            result = Some(LSymbol {
+                                value: alloca.into(),
+                                symbol_type: arm_symbol.symbol_type.clone(),
+                            });
+
+                            alloca
+                        }
+                    };
+
+                    codegen.builder.build_store(result_ptr, arm_symbol.value);
+                    codegen.builder.build_unconditional_branch(end_block);
+                }
+            }
+
+            test_block = next_block;
+        }
+
+        // a match without arms does nothing
+        if arms_len == 0 {
+            codegen.builder.build_unconditional_branch(end_block);
+        }
+
+        // move the endmatch block at the end of all our created blocks
+        end_block
+            .move_after(current_fn.get_last_basic_block().unwrap())
+            .unwrap();
+        codegen.builder.position_at_end(end_block);
+
+        match result {
+            Some(result) if all_values && (has_wildcard || exhaustive) => {
+                let value = codegen
+                    .builder
+                    .build_load(result.value.into_pointer_value(), "loadmatch");
+
+                Ok(LNodeResult::Value(LSymbol {
+                    value,
+                    symbol_type: result.symbol_type,
+                }))
+            }
+            _ => Ok(LNodeResult::Void),
+        }
+    }
+}
+
+/// Builds the condition checking if a value matches the pattern of an arm
+fn build_pattern_cond<'ctx>(
+    pattern: &MatchPattern,
+    value: &LSymbol<'ctx>,
+    codegen: &mut LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<IntValue<'ctx>, CrocoError> {
+    match pattern {
+        MatchPattern::Wildcard => Ok(codegen.context.bool_type().const_int(1, false)),
+
+        MatchPattern::Literal(literal) => {
+            build_literal_cmp(OperatorEnum::Equals, value, literal, codegen, code_pos)
+        }
+
+        MatchPattern::Variant {
+            enum_type, variant, ..
+        } => {
+            if !matches!(value.symbol_type, SymbolType::Enum(_)) {
+                return Err(CrocoError::match_pattern_error(code_pos));
+            }
+
+            // the variant has already been checked against the enum declaration
+            let (tag, _) = codegen
+                .symtable
+                .get_enum_decl(enum_type)
+                .unwrap()
+                .get_variant(variant)
+                .unwrap();
+
+            let value_tag = codegen
+                .builder
+                .build_extract_value(value.value.into_struct_value(), 0, "enumtag")
+                .unwrap()
+                .into_int_value();
+
+            Ok(codegen.builder.build_int_compare(
+                IntPredicate::EQ,
+                value_tag,
+                codegen.context.i32_type().const_int(tag as u64, false),
+                "cmptag",
+            ))
+        }
+
+        MatchPattern::Range {
+            start,
+            end,
+            inclusive,
+        } => {
+            let end_op = if *inclusive {
+                OperatorEnum::LowerOrEqual
+            } else {
+                OperatorEnum::LowerThan
+            };
+
+            let above_start = build_literal_cmp(
+                OperatorEnum::GreaterOrEqual,
+                value,
+                start,
+                codegen,
+                code_pos,
+            )?;
+            let below_end = build_literal_cmp(end_op, value, end, codegen, code_pos)?;
+
+            Ok(codegen.builder.build_and(above_start, below_end, "inrange"))
+        }
+    }
+}
+
+/// Binds the values held by the matched variant to the variables of its pattern
+fn bind_variant_values<'ctx>(
+    enum_ptr: PointerValue<'ctx>,
+    enum_type: &str,
+    variant: &str,
+    bindings: &[Option<String>],
+    codegen: &mut LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<(), CrocoError> {
+    if bindings.is_empty() {
+        return Ok(());
+    }
+
+    let payload_types = codegen
+        .symtable
+        .get_enum_decl(enum_type)
+        .unwrap()
+        .get_variant(variant)
+        .unwrap()
+        .1
+        .to_vec();
+
+    let variant_ty = get_or_define_variant_struct(enum_type, variant, &payload_types, codegen);
+    let payload_ptr = codegen
+        .builder
+        .build_struct_gep(enum_ptr, 1, "geppayload")
+        .unwrap();
+    let variant_ptr = codegen
+        .builder
+        .build_bitcast(
+            payload_ptr,
+            variant_ty.ptr_type(AddressSpace::Generic),
+            "variantptr",
+        )
+        .into_pointer_value();
+
+    for (i, (binding, value_type)) in bindings.iter().zip(payload_types).enumerate() {
+        if let Some(var_name) = binding {
+            let payload_ptr = codegen
+                .builder
+                .build_struct_gep(variant_ptr, i as u32, "geppayloadvalue")
+                .unwrap();
+
+            // the variables hold a copy of the payload, like in crocoi
+            let value = codegen.builder.build_load(payload_ptr, "loadpayloadvalue");
+            let value_ptr = codegen.create_block_alloca(value.get_type(), var_name);
+            codegen.build_decl_store(value_ptr, value);
+
+            codegen
+                .symtable
+                .insert_symbol(
+                    var_name,
+                    LSymbol {
+                        value: value_ptr.into(),
+                        symbol_type: value_type,
+                    },
+                )
+                .map_err(|e| CrocoError::new(code_pos, e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Compares a value to a pattern literal
+fn build_literal_cmp<'ctx>(
+    compare_kind: OperatorEnum,
+    value: &LSymbol<'ctx>,
+    literal: &LiteralEnum,
+    codegen: &mut LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<IntValue<'ctx>, CrocoError> {
+    let literal = ConstantNode::new(literal.clone(), code_pos.clone())
+        .crocol(codegen)?
+        .into_symbol(codegen, code_pos)?;
+
+    if literal.symbol_type != value.symbol_type {
+        return Err(CrocoError::match_pattern_error(code_pos));
+    }
+
+    let cmp = match value.symbol_type {
+        SymbolType::Fnum => {
+            let op = match compare_kind {
+                OperatorEnum::Equals => FloatPredicate::OEQ,
+                OperatorEnum::GreaterOrEqual => FloatPredicate::OGE,
+                OperatorEnum::LowerThan => FloatPredicate::OLT,
+                OperatorEnum::LowerOrEqual => FloatPredicate::OLE,
+                _ => unreachable!(),
+            };
+
+            codegen.builder.build_float_compare(
+                op,
+                value.value.into_float_value(),
+                literal.value.into_float_value(),
+                "cmpfnum",
+            )
+        }
+
+        SymbolType::Num => {
+            let op = match compare_kind {
+                OperatorEnum::Equals => IntPredicate::EQ,
+                OperatorEnum::GreaterOrEqual => IntPredicate::SGE,
+                OperatorEnum::LowerThan => IntPredicate::SLT,
+                OperatorEnum::LowerOrEqual => IntPredicate::SLE,
+                _ => unreachable!(),
+            };
+
+            codegen.builder.build_int_compare(
+                op,
+                value.value.into_int_value(),
+                literal.value.into_int_value(),
+                "cmpnum",
+            )
+        }
+
+        SymbolType::Bool => codegen.builder.build_int_compare(
+            IntPredicate::EQ,
+            value.value.into_int_value(),
+            literal.value.into_int_value(),
+            "cmpbool",
+        ),
+
+        SymbolType::Str => {
+            let cmp_fn = codegen.module.get_function("_croco_str_cmp").unwrap();
+
+            let left_ptr = codegen.create_block_alloca(codegen.str_type.into(), "tmpstr");
+            let right_ptr = codegen.create_block_alloca(codegen.str_type.into(), "tmpstr");
+
+            codegen.builder.build_store(left_ptr, value.value);
+            codegen.builder.build_store(right_ptr, literal.value);
+
+            let cmp_res = codegen
+                .builder
+                .build_call(cmp_fn, &[left_ptr.into(), right_ptr.into()], "cmpstr")
+                .try_as_basic_value()
+                .left()
+                .unwrap();
+
+            codegen.builder.build_int_compare(
+                IntPredicate::EQ,
+                cmp_res.into_int_value(),
+                codegen.context.i8_type().const_zero(),
+                "cmpstr",
+            )
+        }
+
+        _ => unreachable!(),
+    };
+
+    Ok(cmp)
+}
@@ -1,9 +1,6 @@
-use crate::crocol::CrocolNode;
+use crate::crocol::{utils::build_widened_values, CrocolNode};
+use crate::crocol::{LCodegen, LNodeResult, LSymbol};
 use crate::{ast::node::MinusNode, error::CrocoError};
-use crate::{
-    crocol::{LCodegen, LNodeResult, LSymbol},
-    symbol_type::SymbolType,
-};
 
 impl CrocolNode for MinusNode {
     fn crocol<'ctx>(
@@ -24,33 +21,33 @@ impl CrocolNode for MinusNode {
             .crocol(codegen)?
             .into_symbol(codegen, &self.code_pos)?;
 
-        let symbol = match (left.symbol_type, right.symbol_type) {
-            (SymbolType::Fnum, SymbolType::Fnum) => LSymbol {
-                value: codegen
-                    .builder
-                    .build_float_sub(
-                        left.value.into_float_value(),
-                        right.value.into_float_value(),
-                        "fsub",
-                    )
-                    .into(),
-                symbol_type: SymbolType::Fnum,
-            },
+        // numbers of different sizes are widened to the same type
+        let (left, right) = build_widened_values(left, right, codegen)
+            .ok_or_else(|| CrocoError::minus_error(&self.code_pos))?;
 
-            (SymbolType::Num, SymbolType::Num) => LSymbol {
-                value: codegen
-                    .builder
-                    .build_int_sub(
-                        left.value.into_int_value(),
-                        right.value.into_int_value(),
-                        "sub",
-                    )
-                    .into(),
-                symbol_type: SymbolType::Num,
-            },
-
-            _ => return Err(CrocoError::minus_error(&self.code_pos)),
+        let value = if left.symbol_type.is_float() {
+            codegen
+                .builder
+                .build_float_sub(
+                    left.value.into_float_value(),
+                    right.value.into_float_value(),
+                    "fsub",
+                )
+                .into()
+        } else {
+            codegen
+                .builder
+                .build_int_sub(
+                    left.value.into_int_value(),
+                    right.value.into_int_value(),
+                    "sub",
+                )
+                .into()
         };
-        Ok(LNodeResult::Value(symbol))
+
+        Ok(LNodeResult::Value(LSymbol {
+            value,
+            symbol_type: left.symbol_type,
+        }))
     }
 }
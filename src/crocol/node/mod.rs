@@ -2,20 +2,34 @@ mod array_create_node;
 mod array_index_node;
 mod as_node;
 mod assignment_node;
+mod bitwise_node;
+mod bitwise_not_node;
 mod block_node;
 mod break_node;
+mod catch_node;
+mod closure_node;
+mod coalesce_node;
 mod compare_node;
 mod constant_node;
 mod continue_node;
 mod deref_node;
+mod destructure_node;
 mod divide_node;
 mod dot_field_node;
+mod enum_create_node;
+mod error_node;
+mod for_node;
 mod function_call_node;
 mod function_decl_node;
+mod if_let_node;
 mod if_node;
 mod import_node;
+mod map_create_node;
+mod match_node;
 mod minus_node;
+mod modulo_node;
 mod multiplicate_node;
+mod none_node;
 mod not_node;
 mod plus_node;
 mod power_node;
@@ -23,6 +37,8 @@ mod ref_node;
 mod return_node;
 mod struct_create_node;
 mod struct_decl_node;
+mod try_node;
+mod tuple_create_node;
 mod type_node;
 mod unary_minus_node;
 mod var_call_node;
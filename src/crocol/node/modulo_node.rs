@@ -0,0 +1,95 @@
+use inkwell::IntPredicate;
+
+use crate::crocol::{
+    utils::{build_widened_values, throw_runtime_error},
+    CrocolNode,
+};
+use crate::crocol::{LCodegen, LNodeResult, LSymbol};
+use crate::{ast::node::ModuloNode, error::CrocoError};
+
+impl CrocolNode for ModuloNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let left = self
+            .left
+            .as_mut()
+            .unwrap()
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+
+        let right = self
+            .right
+            .as_mut()
+            .unwrap()
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+
+        // numbers of different sizes are widened to the same type
+        let (left, right) = build_widened_values(left, right, codegen)
+            .ok_or_else(|| CrocoError::modulo_error(&self.code_pos))?;
+
+        // integers can't be divided by zero
+        if !left.symbol_type.is_float() {
+            let current_fn = codegen.current_fn.unwrap();
+            let zero_block = codegen
+                .context
+                .append_basic_block(current_fn, "zerodivisor");
+            let non_zero_block = codegen
+                .context
+                .append_basic_block(current_fn, "nonzerodivisor");
+
+            let right_value = right.value.into_int_value();
+            let zero_cmp = codegen.builder.build_int_compare(
+                IntPredicate::EQ,
+                right_value,
+                right_value.get_type().const_zero(),
+                "cmpzerodivisor",
+            );
+
+            codegen
+                .builder
+                .build_conditional_branch(zero_cmp, zero_block, non_zero_block);
+
+            codegen.builder.position_at_end(zero_block);
+            throw_runtime_error(CrocoError::modulo_by_zero_error(&self.code_pos), codegen);
+
+            codegen.builder.position_at_end(non_zero_block);
+        }
+
+        let value = if left.symbol_type.is_float() {
+            codegen
+                .builder
+                .build_float_rem(
+                    left.value.into_float_value(),
+                    right.value.into_float_value(),
+                    "frem",
+                )
+                .into()
+        } else if left.symbol_type.is_unsigned() {
+            codegen
+                .builder
+                .build_int_unsigned_rem(
+                    left.value.into_int_value(),
+                    right.value.into_int_value(),
+                    "urem",
+                )
+                .into()
+        } else {
+            codegen
+                .builder
+                .build_int_signed_rem(
+                    left.value.into_int_value(),
+                    right.value.into_int_value(),
+                    "rem",
+                )
+                .into()
+        };
+
+        Ok(LNodeResult::Value(LSymbol {
+            value,
+            symbol_type: left.symbol_type,
+        }))
+    }
+}
@@ -1,10 +1,6 @@
-use crate::{
-    crocol::{CrocolNode, LCodegen, LNodeResult, LSymbol},
-    symbol_type::SymbolType,
-};
-
-use crate::ast::node::MultiplicateNode;
-use crate::error::CrocoError;
+use crate::crocol::{utils::build_widened_values, CrocolNode};
+use crate::crocol::{LCodegen, LNodeResult, LSymbol};
+use crate::{ast::node::MultiplicateNode, error::CrocoError};
 
 impl CrocolNode for MultiplicateNode {
     fn crocol<'ctx>(
@@ -25,33 +21,33 @@ impl CrocolNode for MultiplicateNode {
             .crocol(codegen)?
             .into_symbol(codegen, &self.code_pos)?;
 
-        let symbol = match (left.symbol_type, right.symbol_type) {
-            (SymbolType::Fnum, SymbolType::Fnum) => LSymbol {
-                value: codegen
-                    .builder
-                    .build_float_mul(
-                        left.value.into_float_value(),
-                        right.value.into_float_value(),
-                        "fmul",
-                    )
-                    .into(),
-                symbol_type: SymbolType::Fnum,
-            },
-
-            (SymbolType::Num, SymbolType::Num) => LSymbol {
-                value: codegen
-                    .builder
-                    .build_int_mul(
-                        left.value.into_int_value(),
-                        right.value.into_int_value(),
-                        "mul",
-                    )
-                    .into(),
-                symbol_type: SymbolType::Num,
-            },
+        // numbers of different sizes are widened to the same type
+        let (left, right) = build_widened_values(left, right, codegen)
+            .ok_or_else(|| CrocoError::multiplicate_error(&self.code_pos))?;
 
-            _ => return Err(CrocoError::minus_error(&self.code_pos)),
+        let value = if left.symbol_type.is_float() {
+            codegen
+                .builder
+                .build_float_mul(
+                    left.value.into_float_value(),
+                    right.value.into_float_value(),
+                    "fmul",
+                )
+                .into()
+        } else {
+            codegen
+                .builder
+                .build_int_mul(
+                    left.value.into_int_value(),
+                    right.value.into_int_value(),
+                    "mul",
+                )
+                .into()
         };
-        Ok(LNodeResult::Value(symbol))
+
+        Ok(LNodeResult::Value(LSymbol {
+            value,
+            symbol_type: left.symbol_type,
+        }))
     }
 }
@@ -0,0 +1,26 @@
+use crate::{
+    ast::node::NoneNode,
+    crocol::{CrocolNode, LCodegen, LNodeResult, LSymbol},
+    symbol_type::SymbolType,
+    CrocoError,
+};
+use inkwell::AddressSpace;
+
+impl CrocolNode for NoneNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        // none is a null pointer until it's turned into an optional
+        let null_ptr = codegen
+            .context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .const_null();
+
+        Ok(LNodeResult::Value(LSymbol {
+            value: null_ptr.into(),
+            symbol_type: SymbolType::None,
+        }))
+    }
+}
@@ -2,7 +2,7 @@ use crate::error::CrocoError;
 use crate::symbol_type::SymbolType;
 use crate::{
     ast::node::PlusNode,
-    crocol::{CrocolNode, LCodegen, LNodeResult, LSymbol},
+    crocol::{utils::build_widened_values, CrocolNode, LCodegen, LNodeResult, LSymbol},
 };
 
 impl CrocolNode for PlusNode {
@@ -23,33 +23,7 @@ impl CrocolNode for PlusNode {
             .crocol(codegen)?
             .into_symbol(codegen, &self.code_pos)?;
 
-        match (left_val.symbol_type, right_val.symbol_type) {
-            (SymbolType::Fnum, SymbolType::Fnum) => {
-                let left_float = left_val.value.into_float_value();
-                let right_float = right_val.value.into_float_value();
-
-                let res = codegen
-                    .builder
-                    .build_float_add(left_float, right_float, "fadd");
-
-                Ok(LNodeResult::Value(LSymbol {
-                    value: res.into(),
-                    symbol_type: SymbolType::Fnum,
-                }))
-            }
-
-            (SymbolType::Num, SymbolType::Num) => {
-                let left_int = left_val.value.into_int_value();
-                let right_int = right_val.value.into_int_value();
-
-                let res = codegen.builder.build_int_add(left_int, right_int, "add");
-
-                Ok(LNodeResult::Value(LSymbol {
-                    value: res.into(),
-                    symbol_type: SymbolType::Num,
-                }))
-            }
-
+        match (&left_val.symbol_type, &right_val.symbol_type) {
             (SymbolType::Str, SymbolType::Str) => {
                 let left_str = codegen.builder.build_alloca(codegen.str_type, "tmpstr");
                 codegen.builder.build_store(left_str, left_val.value);
@@ -154,7 +128,36 @@ impl CrocolNode for PlusNode {
                     symbol_type: SymbolType::Str,
                 }))
             }
-            _ => Err(CrocoError::add_error(&self.code_pos)),
+            _ => {
+                // numbers of different sizes are widened to the same type
+                let (left_val, right_val) = build_widened_values(left_val, right_val, codegen)
+                    .ok_or_else(|| CrocoError::add_error(&self.code_pos))?;
+
+                let res = if left_val.symbol_type.is_float() {
+                    codegen
+                        .builder
+                        .build_float_add(
+                            left_val.value.into_float_value(),
+                            right_val.value.into_float_value(),
+                            "fadd",
+                        )
+                        .into()
+                } else {
+                    codegen
+                        .builder
+                        .build_int_add(
+                            left_val.value.into_int_value(),
+                            right_val.value.into_int_value(),
+                            "add",
+                        )
+                        .into()
+                };
+
+                Ok(LNodeResult::Value(LSymbol {
+                    value: res,
+                    symbol_type: left_val.symbol_type,
+                }))
+            }
         }
     }
 }
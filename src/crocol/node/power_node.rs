@@ -1,3 +1,154 @@
-use crate::{ast::node::PowerNode, crocol::CrocolNode};
+use inkwell::IntPredicate;
 
-impl CrocolNode for PowerNode {}
+use crate::crocol::{
+    utils::{build_widened_values, throw_runtime_error},
+    CrocolNode,
+};
+use crate::crocol::{LCodegen, LNodeResult, LSymbol};
+use crate::{ast::node::PowerNode, error::CrocoError, symbol_type::SymbolType};
+
+impl CrocolNode for PowerNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let left = self
+            .left
+            .as_mut()
+            .unwrap()
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+
+        let right = self
+            .right
+            .as_mut()
+            .unwrap()
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+
+        // numbers of different sizes are widened to the same type
+        let (left, right) = build_widened_values(left, right, codegen)
+            .ok_or_else(|| CrocoError::power_error(&self.code_pos))?;
+
+        if left.symbol_type.is_float() {
+            let float_type = left.value.into_float_value().get_type();
+            let intrinsic_name = if left.symbol_type == SymbolType::Fnum {
+                "llvm.pow.f32"
+            } else {
+                "llvm.pow.f64"
+            };
+
+            let pow_fn = codegen
+                .module
+                .get_function(intrinsic_name)
+                .unwrap_or_else(|| {
+                    let fn_type =
+                        float_type.fn_type(&[float_type.into(), float_type.into()], false);
+                    codegen.module.add_function(intrinsic_name, fn_type, None)
+                });
+
+            let value = codegen
+                .builder
+                .build_call(pow_fn, &[left.value, right.value], "fpow")
+                .try_as_basic_value()
+                .left()
+                .unwrap();
+
+            return Ok(LNodeResult::Value(LSymbol {
+                value,
+                symbol_type: left.symbol_type,
+            }));
+        }
+
+        // integers are powered by squaring, wrapping around on overflow
+        let current_fn = codegen.current_fn.unwrap();
+        let int_type = left.value.into_int_value().get_type();
+        let zero = int_type.const_zero();
+        let one = int_type.const_int(1, false);
+
+        if !left.symbol_type.is_unsigned() {
+            let negative_exp_block = codegen.context.append_basic_block(current_fn, "negexp");
+            let positive_exp_block = codegen.context.append_basic_block(current_fn, "posexp");
+
+            let negative_exp_cmp = codegen.builder.build_int_compare(
+                IntPredicate::SLT,
+                right.value.into_int_value(),
+                zero,
+                "cmpnegexp",
+            );
+
+            codegen.builder.build_conditional_branch(
+                negative_exp_cmp,
+                negative_exp_block,
+                positive_exp_block,
+            );
+
+            codegen.builder.position_at_end(negative_exp_block);
+            throw_runtime_error(CrocoError::negative_exponent_error(&self.code_pos), codegen);
+
+            codegen.builder.position_at_end(positive_exp_block);
+        }
+
+        let result_ptr = codegen.create_block_alloca(int_type.into(), "powresult");
+        let base_ptr = codegen.create_block_alloca(int_type.into(), "powbase");
+        let exp_ptr = codegen.create_block_alloca(int_type.into(), "powexp");
+
+        codegen.builder.build_store(result_ptr, one);
+        codegen.builder.build_store(base_ptr, left.value);
+        codegen.builder.build_store(exp_ptr, right.value);
+
+        let loop_block = codegen.context.append_basic_block(current_fn, "powloop");
+        let body_block = codegen.context.append_basic_block(current_fn, "powbody");
+        let end_block = codegen.context.append_basic_block(current_fn, "powend");
+
+        codegen.builder.build_unconditional_branch(loop_block);
+
+        // loop while the exponent isn't zero
+        codegen.builder.position_at_end(loop_block);
+        let exp = codegen.builder.build_load(exp_ptr, "exp").into_int_value();
+        let exp_cmp = codegen
+            .builder
+            .build_int_compare(IntPredicate::NE, exp, zero, "cmpexp");
+        codegen
+            .builder
+            .build_conditional_branch(exp_cmp, body_block, end_block);
+
+        // multiply the result by the base when the exponent is odd, then square the base
+        codegen.builder.position_at_end(body_block);
+        let result = codegen
+            .builder
+            .build_load(result_ptr, "result")
+            .into_int_value();
+        let base = codegen
+            .builder
+            .build_load(base_ptr, "base")
+            .into_int_value();
+
+        let odd_bit = codegen.builder.build_and(exp, one, "oddbit");
+        let is_odd = codegen
+            .builder
+            .build_int_compare(IntPredicate::NE, odd_bit, zero, "isodd");
+        let multiplied = codegen.builder.build_int_mul(result, base, "mulresult");
+        let new_result = codegen
+            .builder
+            .build_select(is_odd, multiplied, result, "newresult");
+        codegen.builder.build_store(result_ptr, new_result);
+
+        let squared = codegen.builder.build_int_mul(base, base, "squarebase");
+        codegen.builder.build_store(base_ptr, squared);
+
+        let halved = codegen
+            .builder
+            .build_right_shift(exp, one, false, "halfexp");
+        codegen.builder.build_store(exp_ptr, halved);
+        codegen.builder.build_unconditional_branch(loop_block);
+
+        codegen.builder.position_at_end(end_block);
+        let value = codegen.builder.build_load(result_ptr, "powresult");
+
+        Ok(LNodeResult::Value(LSymbol {
+            value,
+            symbol_type: left.symbol_type,
+        }))
+    }
+}
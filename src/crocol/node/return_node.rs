@@ -1,6 +1,10 @@
 use crate::{
     ast::node::ReturnNode,
-    crocol::{CrocolNode, LCodegen, LNodeResult},
+    crocol::{
+        utils::{build_result_value, coerce_to_type},
+        CrocolNode, LCodegen, LNodeResult,
+    },
+    symbol_type::SymbolType,
 };
 use crate::{crocol::LSymbol, error::CrocoError};
 
@@ -10,7 +14,12 @@ impl CrocolNode for ReturnNode {
         codegen: &mut LCodegen<'ctx>,
     ) -> Result<LNodeResult<'ctx>, CrocoError> {
         match self.bottom.crocol(codegen)? {
-            LNodeResult::Value(val) => {
+            LNodeResult::Value(mut val) => {
+                // a function returning a trait can return any struct implementing it
+                if let Some(return_type) = codegen.return_type.clone() {
+                    val = coerce_to_type(val, &return_type, codegen, &self.code_pos)?;
+                }
+
                 // if we have a struct return, update the pointer and return void
                 if let Some(sret_ptr) = codegen.sret_ptr {
                     codegen.builder.build_store(sret_ptr, val.value);
@@ -22,18 +31,31 @@ impl CrocolNode for ReturnNode {
                 let val = codegen
                     .builder
                     .build_load(var.value.into_pointer_value(), "loadret");
+                let mut val = LSymbol {
+                    value: val,
+                    symbol_type: var.symbol_type,
+                };
+
+                if let Some(return_type) = codegen.return_type.clone() {
+                    val = coerce_to_type(val, &return_type, codegen, &self.code_pos)?;
+                }
 
                 // if we have a struct return, update the pointer and return void
                 if let Some(sret_ptr) = codegen.sret_ptr {
-                    codegen.builder.build_store(sret_ptr, val);
+                    codegen.builder.build_store(sret_ptr, val.value);
                 }
-                Ok(LNodeResult::Return(Some(LSymbol {
-                    value: val,
-                    symbol_type: var.symbol_type,
-                })))
+                Ok(LNodeResult::Return(Some(val)))
             }
 
-            LNodeResult::Void => Ok(LNodeResult::Return(None)),
+            LNodeResult::Void => match (&codegen.return_type, codegen.sret_ptr) {
+                // returning from a function giving an empty result succeeds
+                (Some(SymbolType::Result(None)), Some(sret_ptr)) => {
+                    let ok = build_result_value(&None, None, None, codegen);
+                    codegen.builder.build_store(sret_ptr, ok.value);
+                    Ok(LNodeResult::Return(Some(ok)))
+                }
+                _ => Ok(LNodeResult::Return(None)),
+            },
             _ => Err(CrocoError::invalid_return_value(&self.code_pos)),
         }
     }
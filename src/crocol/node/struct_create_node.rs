@@ -3,7 +3,7 @@ use symbol_type::SymbolType;
 use crate::{
     ast::node::StructCreateNode,
     crocol::{
-        utils::{get_or_define_struct, init_default},
+        utils::{coerce_to_type, get_or_define_struct, init_default},
         CrocolNode, LCodegen, LNodeResult, LSymbol,
     },
     symbol_type, CrocoError,
@@ -34,6 +34,7 @@ impl CrocolNode for StructCreateNode {
             // the field is present
             if let Some(node) = self.fields.get_mut(field_name) {
                 let field_value = node.crocol(codegen)?.into_symbol(codegen, &self.code_pos)?;
+                let field_value = coerce_to_type(field_value, field_type, codegen, &self.code_pos)?;
 
                 if field_value.symbol_type != *field_type {
                     return Err(CrocoError::field_type_error(field_name, &self.code_pos));
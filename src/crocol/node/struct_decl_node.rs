@@ -10,7 +10,9 @@ impl CrocolNode for StructDeclNode {
         codegen: &mut LCodegen<'ctx>,
     ) -> Result<LNodeResult<'ctx>, CrocoError> {
         for (method_name, method_body) in self.methods.drain() {
-            codegen.build_function(&method_name, method_body, &self.code_pos)?;
+            codegen
+                .pending_fns
+                .push((method_name, method_body, self.code_pos.clone()));
         }
 
         Ok(LNodeResult::Void)
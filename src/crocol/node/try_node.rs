@@ -0,0 +1,83 @@
+use crate::{
+    ast::node::TryNode,
+    crocol::{
+        utils::{auto_deref, build_result_value, throw_runtime_str_error},
+        CrocolNode, LCodegen, LNodeResult, LSymbol,
+    },
+    symbol_type::SymbolType,
+    CrocoError,
+};
+
+impl CrocolNode for TryNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let current_fn = codegen.current_fn.unwrap();
+
+        let result = self
+            .bottom
+            .as_mut()
+            .unwrap()
+            .crocol(codegen)?
+            .into_symbol(codegen, &self.code_pos)?;
+        let result = auto_deref(result, codegen);
+
+        let ok_type = match result.symbol_type {
+            SymbolType::Result(ok_type) => ok_type,
+            _ => return Err(CrocoError::result_error(&self.code_pos)),
+        };
+
+        let result_value = result.value.into_struct_value();
+        let is_err = codegen
+            .builder
+            .build_extract_value(result_value, 0, "iserr")
+            .unwrap()
+            .into_int_value();
+
+        let err_block = codegen.context.append_basic_block(current_fn, "tryerr");
+        let ok_block = codegen.context.append_basic_block(current_fn, "tryok");
+        codegen
+            .builder
+            .build_conditional_branch(is_err, err_block, ok_block);
+
+        codegen.builder.position_at_end(err_block);
+        let message = codegen
+            .builder
+            .build_extract_value(result_value, 1, "errmessage")
+            .unwrap();
+
+        match (codegen.return_type.clone(), codegen.sret_ptr) {
+            // give the error back to the caller
+            (Some(SymbolType::Result(ret_ok_type)), Some(sret_ptr)) => {
+                let err = build_result_value(&ret_ok_type, None, Some(message), codegen);
+                codegen.builder.build_store(sret_ptr, err.value);
+                codegen.builder.build_return(None);
+            }
+
+            // the error can't be handled anymore
+            _ => throw_runtime_str_error(
+                message,
+                CrocoError::propagated_error(&self.code_pos, ""),
+                codegen,
+            ),
+        }
+
+        codegen.builder.position_at_end(ok_block);
+
+        match ok_type {
+            Some(ok_type) => {
+                let value = codegen
+                    .builder
+                    .build_extract_value(result_value, 2, "okvalue")
+                    .unwrap();
+
+                Ok(LNodeResult::Value(LSymbol {
+                    value,
+                    symbol_type: *ok_type,
+                }))
+            }
+            None => Ok(LNodeResult::Void),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    ast::node::TupleCreateNode,
+    crocol::{utils::get_llvm_type, CrocolNode, LCodegen, LNodeResult, LSymbol},
+    symbol_type::SymbolType,
+    CrocoError,
+};
+
+impl CrocolNode for TupleCreateNode {
+    fn crocol<'ctx>(
+        &mut self,
+        codegen: &mut LCodegen<'ctx>,
+    ) -> Result<LNodeResult<'ctx>, CrocoError> {
+        let mut visited = Vec::with_capacity(self.elements.len());
+
+        for el in &mut self.elements {
+            visited.push(el.crocol(codegen)?.into_symbol(codegen, &self.code_pos)?);
+        }
+
+        let tuple_type =
+            SymbolType::Tuple(visited.iter().map(|el| el.symbol_type.clone()).collect());
+        let mut tuple = get_llvm_type(&tuple_type, codegen)
+            .into_struct_type()
+            .get_undef();
+
+        for (i, el) in visited.into_iter().enumerate() {
+            tuple = codegen
+                .builder
+                .build_insert_value(tuple, el.value, i as u32, "inserttuple")
+                .unwrap()
+                .into_struct_value();
+        }
+
+        Ok(LNodeResult::Value(LSymbol {
+            value: tuple.into(),
+            symbol_type: tuple_type,
+        }))
+    }
+}
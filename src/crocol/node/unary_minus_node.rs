@@ -42,6 +42,31 @@ impl CrocolNode for UnaryMinusNode {
                 symbol_type: SymbolType::Num,
             },
 
+            SymbolType::F64 => LSymbol {
+                value: codegen
+                    .builder
+                    .build_float_sub(
+                        codegen.context.f64_type().const_zero(),
+                        bottom.value.into_float_value(),
+                        "unaryfsub",
+                    )
+                    .into(),
+                symbol_type: SymbolType::F64,
+            },
+
+            SymbolType::I64 => LSymbol {
+                value: codegen
+                    .builder
+                    .build_int_sub(
+                        codegen.context.i64_type().const_zero(),
+                        bottom.value.into_int_value(),
+                        "unarysub",
+                    )
+                    .into(),
+                symbol_type: SymbolType::I64,
+            },
+
+            // unsigned numbers can't be negated
             _ => return Err(CrocoError::unary_minus_error(&self.code_pos)),
         };
 
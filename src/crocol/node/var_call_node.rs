@@ -1,4 +1,9 @@
-use crate::crocol::{LCodegen, LNodeResult};
+use inkwell::AddressSpace;
+
+use crate::crocol::{
+    utils::{build_closure_value, get_or_define_fn_thunk},
+    LCodegen, LNodeResult, LSymbol,
+};
 use crate::error::CrocoError;
 use crate::{ast::node::VarCallNode, crocol::CrocolNode};
 
@@ -7,11 +12,37 @@ impl CrocolNode for VarCallNode {
         &mut self,
         codegen: &mut LCodegen<'ctx>,
     ) -> Result<LNodeResult<'ctx>, CrocoError> {
-        let symbol = codegen
-            .symtable
-            .get_symbol(&self.name)
-            .map_err(|e| CrocoError::new(&self.code_pos, e))?;
+        let err = match codegen.symtable.get_symbol(&self.name) {
+            Ok(symbol) => return Ok(LNodeResult::Variable(symbol.clone())),
+            Err(e) => e,
+        };
+
+        // a named function can also be used as a value
+        let fn_decl = match codegen.symtable.get_function_decl(&self.name) {
+            Ok(fn_decl) => fn_decl.clone(),
+            Err(_) => return Err(CrocoError::new(&self.code_pos, &err)),
+        };
+
+        if fn_decl.is_generic() {
+            return Err(CrocoError::generic_fn_value_error(
+                &self.code_pos,
+                &self.name,
+            ));
+        }
+
+        // a named function doesn't capture anything
+        let thunk = get_or_define_fn_thunk(&self.name, &fn_decl, codegen);
+        let env_ptr = codegen
+            .context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .const_null();
+        let value =
+            build_closure_value(thunk.as_global_value().as_pointer_value(), env_ptr, codegen);
 
-        Ok(LNodeResult::Variable(symbol.clone()))
+        Ok(LNodeResult::Value(LSymbol {
+            value,
+            symbol_type: fn_decl.get_type(),
+        }))
     }
 }
@@ -1,9 +1,15 @@
+use inkwell::{module::Linkage, values::BasicValueEnum};
+
 use crate::ast::node::VarDeclNode;
 use crate::{crocol::CrocolNode, error::CrocoError};
 
 use crate::{
     crocol::LSymbol,
-    crocol::{utils::get_llvm_type, LCodegen, LNodeResult},
+    crocol::{
+        utils::{coerce_to_type, const_zero, get_llvm_type},
+        LCodegen, LNodeResult,
+    },
+    symbol_type::SymbolType,
 };
 
 impl CrocolNode for VarDeclNode {
@@ -13,11 +19,26 @@ impl CrocolNode for VarDeclNode {
     ) -> Result<LNodeResult<'ctx>, CrocoError> {
         let symbol: LSymbol;
 
+        // in a generic instance, the annotation can use the type parameters
+        let var_type = self
+            .var_type
+            .as_ref()
+            .map(|var_type| var_type.resolve_generics(&codegen.generics));
+
+        // a variable declared at the top level of a file is global,
+        // its value is computed by the init function
+        let is_global = codegen.builder.get_insert_block().is_none();
+        if is_global {
+            codegen.enter_init_fn();
+        }
+
         match &mut self.right {
             Some(node) => {
-                let right = node.crocol(codegen)?.into_symbol(codegen, &self.code_pos)?;
+                let mut right = node.crocol(codegen)?.into_symbol(codegen, &self.code_pos)?;
+
+                if let Some(var_type) = &var_type {
+                    right = coerce_to_type(right, var_type, codegen, &self.code_pos)?;
 
-                if let Some(var_type) = &self.var_type {
                     if *var_type != right.symbol_type {
                         return Err(CrocoError::type_annotation_error(
                             &self.code_pos,
@@ -26,26 +47,74 @@ impl CrocolNode for VarDeclNode {
                     }
                 }
 
+                // none alone doesn't tell which optional it is
+                if right.symbol_type == SymbolType::None {
+                    return Err(CrocoError::infer_error(&self.code_pos, &self.left));
+                }
+
                 let llvm_type = get_llvm_type(&right.symbol_type, codegen);
-                let alloca = codegen.create_block_alloca(llvm_type, &self.left);
 
-                codegen.builder.build_store(alloca, right.value);
+                let ptr = if is_global {
+                    let global = codegen.module.add_global(llvm_type, None, &self.left);
+                    global.set_linkage(Linkage::Internal);
+
+                    // constant values don't need to be computed at runtime
+                    match right.value {
+                        BasicValueEnum::IntValue(int) if int.is_const() => {
+                            global.set_initializer(&int)
+                        }
+                        BasicValueEnum::FloatValue(float) if float.is_const() => {
+                            global.set_initializer(&float)
+                        }
+                        _ => {
+                            global.set_initializer(&const_zero(llvm_type));
+                            codegen
+                                .builder
+                                .build_store(global.as_pointer_value(), right.value);
+                        }
+                    }
+
+                    global.as_pointer_value()
+                } else {
+                    let alloca = codegen.create_block_alloca(llvm_type, &self.left);
+                    codegen.build_decl_store(alloca, right.value);
+                    alloca
+                };
 
                 symbol = LSymbol {
-                    value: alloca.into(),
+                    value: ptr.into(),
                     symbol_type: right.symbol_type,
                 };
             }
 
-            None => match &self.var_type {
+            None => match &var_type {
                 None => return Err(CrocoError::infer_error(&self.code_pos, &self.left)),
 
+                Some(SymbolType::Trait(_)) => {
+                    return Err(CrocoError::trait_init_error(&self.code_pos))
+                }
+
+                Some(SymbolType::Function(_)) => {
+                    return Err(CrocoError::function_init_error(&self.code_pos))
+                }
+
+                Some(SymbolType::Enum(_)) => {
+                    return Err(CrocoError::enum_init_error(&self.code_pos))
+                }
+
                 Some(var_type) => {
                     let llvm_type = get_llvm_type(&var_type, codegen);
-                    let alloca = codegen.create_block_alloca(llvm_type, &self.left);
+                    let ptr = if is_global {
+                        codegen.create_global(llvm_type, &self.left)
+                    } else {
+                        // the default value is built over a zeroed variable
+                        let alloca = codegen.create_block_alloca(llvm_type, &self.left);
+                        codegen.build_decl_store(alloca, const_zero(llvm_type));
+                        alloca
+                    };
 
                     symbol = LSymbol {
-                        value: alloca.into(),
+                        value: ptr.into(),
                         symbol_type: var_type.clone(),
                     };
 
@@ -54,6 +123,10 @@ impl CrocolNode for VarDeclNode {
             },
         }
 
+        if is_global {
+            codegen.leave_init_fn();
+        }
+
         codegen
             .symtable
             .insert_symbol(&self.left, symbol)
@@ -15,9 +15,9 @@ impl CrocolNode for WhileNode {
         let while_block = codegen.context.append_basic_block(current_fn, "while");
         let end_block = codegen.context.append_basic_block(current_fn, "endwhile");
 
-        // set in the codegen the current loop
-        codegen.current_loop_block = Some(cond_block);
-        codegen.current_loop_end_block = Some(end_block);
+        // set in the codegen the current loop, and restore the enclosing one afterwards.
+        let outer_loop_block = codegen.current_loop_block.replace(cond_block);
+        let outer_loop_end_block = codegen.current_loop_end_block.replace(end_block);
 
         // build the condition block
         codegen.builder.build_unconditional_branch(cond_block);
@@ -52,6 +52,9 @@ impl CrocolNode for WhileNode {
             }
         }
 
+        codegen.current_loop_block = outer_loop_block;
+        codegen.current_loop_end_block = outer_loop_end_block;
+
         codegen.builder.position_at_end(end_block);
         Ok(LNodeResult::Void)
     }
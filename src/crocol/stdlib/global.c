@@ -1,9 +1,12 @@
+#include <inttypes.h>
 #include <stdbool.h>
 #include <stdio.h>
 #include <stdlib.h>
 #include <stdint.h>
 #include <string.h>
 
+#include "grapheme_table.h"
+
 /**
  * Representation of a croco str
  * https://mapping-high-level-constructs-to-llvm-ir.readthedocs.io/en/latest/appendix-a-how-to-implement-a-string-type-in-llvm/
@@ -52,6 +55,18 @@ void _croco_error(char *file, uint32_t line, char *message, char *hint)
   exit(1);
 }
 
+/**
+ * Throws a CrocoError at runtime, with a message only known at runtime
+ */
+void _croco_str_error(char *file, uint32_t line, CrocoStr *message, char *hint)
+{
+  char *c_message = malloc(message->len + 1);
+  memcpy(c_message, message->ptr, message->len);
+  c_message[message->len] = '\0';
+
+  _croco_error(file, line, c_message, hint);
+}
+
 /**
  * Resizes a CrocoStr if needed
  */
@@ -70,6 +85,7 @@ void _croco_str_resize(CrocoStr *string, size_t new_len)
  * Compares two CrocoStr
  * Returns 0 if both strings are equal
  * Returns < 0 if the first string is inferior to the second
+ * Returns > 0 if the first string is superior to the second
  */
 char _croco_str_cmp(CrocoStr *string1, CrocoStr *string2)
 {
@@ -79,11 +95,13 @@ char _croco_str_cmp(CrocoStr *string1, CrocoStr *string2)
   // one of the string is finished
   while (string1->len > pos && string2->len > pos)
   {
-    char diff = string1->ptr[pos] - string2->ptr[pos];
+    // the bytes are compared unsigned, so that the order is the one of the code points
+    unsigned char c1 = string1->ptr[pos];
+    unsigned char c2 = string2->ptr[pos];
 
-    if (diff != 0)
+    if (c1 != c2)
     {
-      return diff;
+      return c1 < c2 ? -1 : 1;
     }
 
     pos++;
@@ -107,23 +125,336 @@ char _croco_str_cmp(CrocoStr *string1, CrocoStr *string2)
 }
 
 /**
- * Casts a `str` into a `num`
+ * Representation of a croco map
+ * The entries are stored in insertion order in two dense arrays,
+ * and an open-addressing table maps the hash of each key to its position (plus one, since 0 means empty).
+ * {
+ *   keys: i8*,
+ *   values: i8*,
+ *   index: isize*,
+ *   len: isize,
+ *   max_len: isize,
+ *   index_len: isize,
+ *   key_size: isize,
+ *   value_size: isize,
+ *   str_keys: i8
+ * }
  */
-float _as_str_num(CrocoStr string)
+typedef struct
 {
-  float res;
+  void *keys;
+  void *values;
+  size_t *index;
+  size_t len;
+  size_t max_len;
+  size_t index_len;
+  size_t key_size;
+  size_t value_size;
+  char str_keys;
+} CrocoMap;
+
+/**
+ * Returns a pointer to the key at a given position
+ */
+static void *_croco_map_key_at(CrocoMap *map, size_t pos)
+{
+  return (char *)map->keys + pos * map->key_size;
+}
+
+/**
+ * Returns a pointer to the value at a given position
+ */
+static void *_croco_map_value_at(CrocoMap *map, size_t pos)
+{
+  return (char *)map->values + pos * map->value_size;
+}
+
+/**
+ * Hashes a key with FNV-1a
+ */
+static size_t _croco_map_hash(CrocoMap *map, void *key)
+{
+  unsigned char *bytes;
+  size_t len;
+
+  // for strs we want to hash the contents, not the pointer
+  if (map->str_keys)
+  {
+    CrocoStr *string = (CrocoStr *)key;
+    bytes = (unsigned char *)string->ptr;
+    len = string->len;
+  }
+  else
+  {
+    bytes = (unsigned char *)key;
+    len = map->key_size;
+  }
+
+  uint64_t hash = 14695981039346656037ULL;
+
+  for (size_t i = 0; i < len; i++)
+  {
+    hash ^= bytes[i];
+    hash *= 1099511628211ULL;
+  }
+
+  return (size_t)hash;
+}
+
+/**
+ * Finds the slot of a key in the index, or the empty slot where it should be inserted
+ */
+static size_t _croco_map_probe(CrocoMap *map, void *key)
+{
+  size_t mask = map->index_len - 1;
+  size_t slot = _croco_map_hash(map, key) & mask;
+
+  while (map->index[slot] != 0)
+  {
+    void *slot_key = _croco_map_key_at(map, map->index[slot] - 1);
+
+    bool is_equal = map->str_keys
+                        ? _croco_str_cmp((CrocoStr *)slot_key, (CrocoStr *)key) == 0
+                        : memcmp(slot_key, key, map->key_size) == 0;
+
+    if (is_equal)
+    {
+      break;
+    }
+
+    slot = (slot + 1) & mask;
+  }
+
+  return slot;
+}
+
+/**
+ * Rebuilds the index of a map with a given size, which must be a power of two
+ */
+static void _croco_map_reindex(CrocoMap *map, size_t index_len)
+{
+  free(map->index);
+  map->index = (size_t *)calloc(index_len, sizeof(size_t));
+  map->index_len = index_len;
+
+  for (size_t i = 0; i < map->len; i++)
+  {
+    size_t slot = _croco_map_probe(map, _croco_map_key_at(map, i));
+    map->index[slot] = i + 1;
+  }
+}
+
+/**
+ * Returns a pointer to the value associated to a key, or NULL if the key doesn't exist
+ */
+void *_croco_map_get(CrocoMap *map, void *key)
+{
+  if (map->len == 0)
+  {
+    return NULL;
+  }
+
+  size_t slot = _croco_map_probe(map, key);
+
+  if (map->index[slot] == 0)
+  {
+    return NULL;
+  }
+
+  return _croco_map_value_at(map, map->index[slot] - 1);
+}
+
+/**
+ * Returns a pointer to the value associated to a key.
+ * If the key doesn't exist it is inserted with a zeroed value.
+ */
+void *_croco_map_slot(CrocoMap *map, void *key)
+{
+  void *value = _croco_map_get(map, key);
+
+  if (value)
+  {
+    return value;
+  }
+
+  // keep the index at most half full so the probing stays short
+  if ((map->len + 1) * 2 > map->index_len)
+  {
+    _croco_map_reindex(map, map->index_len ? map->index_len * 2 : 8);
+  }
+
+  if (map->len == map->max_len)
+  {
+    map->max_len = map->max_len ? map->max_len * 2 : 4;
+    map->keys = realloc(map->keys, map->max_len * map->key_size);
+    map->values = realloc(map->values, map->max_len * map->value_size);
+  }
+
+  void *new_key = _croco_map_key_at(map, map->len);
+
+  // copy the str keys so they can't be mutated from outside of the map
+  if (map->str_keys)
+  {
+    CrocoStr *string = (CrocoStr *)key;
+    CrocoStr *new_string = (CrocoStr *)new_key;
+
+    new_string->ptr = (char *)malloc(string->len);
+    memcpy(new_string->ptr, string->ptr, string->len);
+    new_string->len = string->len;
+    new_string->max_len = string->len;
+  }
+  else
+  {
+    memcpy(new_key, key, map->key_size);
+  }
+
+  size_t slot = _croco_map_probe(map, key);
+  map->index[slot] = map->len + 1;
+
+  value = _croco_map_value_at(map, map->len);
+  memset(value, 0, map->value_size);
+  map->len++;
+
+  return value;
+}
+
+/**
+ * Removes a key and its value from a map, if the key exists
+ */
+void _croco_map_remove(CrocoMap *map, void *key)
+{
+  if (map->len == 0)
+  {
+    return;
+  }
+
+  size_t slot = _croco_map_probe(map, key);
+
+  if (map->index[slot] == 0)
+  {
+    return;
+  }
+
+  size_t pos = map->index[slot] - 1;
+
+  if (map->str_keys)
+  {
+    free(((CrocoStr *)_croco_map_key_at(map, pos))->ptr);
+  }
+
+  // shift the following entries to keep the insertion order
+  size_t following = map->len - pos - 1;
+  memmove(_croco_map_key_at(map, pos), _croco_map_key_at(map, pos + 1), following * map->key_size);
+  memmove(_croco_map_value_at(map, pos), _croco_map_value_at(map, pos + 1), following * map->value_size);
+  map->len--;
+
+  // the positions have changed, so the index must be rebuilt
+  _croco_map_reindex(map, map->index_len);
+}
+
+/**
+ * Copies the keys of a map into a new array, in insertion order
+ */
+void _croco_map_keys(CrocoArray *array_res, CrocoMap *map)
+{
+  array_res->ptr = malloc(map->len * map->key_size);
+  array_res->len = map->len;
+  array_res->max_len = map->len;
+
+  if (!map->str_keys)
+  {
+    memcpy(array_res->ptr, map->keys, map->len * map->key_size);
+    return;
+  }
 
+  // the array gets its own copy of the strs
+  for (size_t i = 0; i < map->len; i++)
+  {
+    CrocoStr *string = (CrocoStr *)_croco_map_key_at(map, i);
+    CrocoStr *new_string = (CrocoStr *)array_res->ptr + i;
+
+    new_string->ptr = (char *)malloc(string->len);
+    memcpy(new_string->ptr, string->ptr, string->len);
+    new_string->len = string->len;
+    new_string->max_len = string->len;
+  }
+}
+
+/**
+ * Copies the values of a map into a new array, in insertion order
+ */
+void _croco_map_values(CrocoArray *array_res, CrocoMap *map)
+{
+  array_res->ptr = malloc(map->len * map->value_size);
+  array_res->len = map->len;
+  array_res->max_len = map->len;
+  memcpy(array_res->ptr, map->values, map->len * map->value_size);
+}
+
+/**
+ * Parses a `str` with a scanf format, or exits if it's not a valid number
+ */
+static void _as_str_scan(CrocoStr string, const char *format, void *res, char *message)
+{
   // the number should be less than 100 digits, right ?!?!
   char tmp_str[100];
   sprintf(tmp_str, "%.*s", (int)string.len, string.ptr);
 
-  int success = sscanf(tmp_str, "%f", &res);
+  int success = sscanf(tmp_str, format, res);
 
-  if (!success)
+  if (success != 1)
   {
-    _croco_error(NULL, 0, "cannot cast a str to num", NULL);
+    _croco_error(NULL, 0, message, NULL);
   }
+}
+
+/**
+ * Casts a `str` into a `num`
+ */
+int32_t _as_str_num(CrocoStr string)
+{
+  int32_t res;
+  _as_str_scan(string, "%" SCNd32, &res, "cannot cast a str to num");
+  return res;
+}
+
+/**
+ * Casts a `str` into a `fnum`
+ */
+float _as_str_fnum(CrocoStr string)
+{
+  float res;
+  _as_str_scan(string, "%f", &res, "cannot cast a str to fnum");
+  return res;
+}
+
+/**
+ * Casts a `str` into an `i64`
+ */
+int64_t _as_str_i64(CrocoStr string)
+{
+  int64_t res;
+  _as_str_scan(string, "%" SCNd64, &res, "cannot cast a str to i64");
+  return res;
+}
+
+/**
+ * Casts a `str` into an `u64`
+ */
+uint64_t _as_str_u64(CrocoStr string)
+{
+  uint64_t res;
+  _as_str_scan(string, "%" SCNu64, &res, "cannot cast a str to u64");
+  return res;
+}
 
+/**
+ * Casts a `str` into a `f64`
+ */
+double _as_str_f64(CrocoStr string)
+{
+  double res;
+  _as_str_scan(string, "%lf", &res, "cannot cast a str to f64");
   return res;
 }
 
@@ -150,6 +481,1188 @@ void _as_num_str(int32_t num, CrocoStr *string_res)
   string_res->len = strlen(string_res->ptr);
 }
 
+/**
+ * Casts an `i64` into a `str`
+ */
+void _as_i64_str(int64_t num, CrocoStr *string_res)
+{
+  // 19 chars is the max int size, 1 char for the sign, 1 char for the null byte
+  _croco_str_resize(string_res, 21);
+  sprintf(string_res->ptr, "%" PRId64, num);
+  string_res->len = strlen(string_res->ptr);
+}
+
+/**
+ * Casts an `u64` into a `str`
+ */
+void _as_u64_str(uint64_t num, CrocoStr *string_res)
+{
+  // 20 chars is the max int size, 1 char for the null byte
+  _croco_str_resize(string_res, 21);
+  sprintf(string_res->ptr, "%" PRIu64, num);
+  string_res->len = strlen(string_res->ptr);
+}
+
+/**
+ * Casts a `f64` into a `str`
+ */
+void _as_f64_str(double fnum, CrocoStr *string_res)
+{
+  _croco_str_resize(string_res, 100);
+  sprintf(string_res->ptr, "%.15g", fnum);
+  string_res->len = strlen(string_res->ptr);
+}
+
+/**
+ * Decodes the UTF-8 code point at a given position, and moves the position after it
+ */
+static uint32_t _croco_utf8_decode(CrocoStr *string, size_t *pos)
+{
+  unsigned char *bytes = (unsigned char *)string->ptr;
+  uint32_t code_point = bytes[*pos];
+  size_t extra_bytes = 0;
+
+  if (code_point >= 0xF0)
+  {
+    code_point &= 0x07;
+    extra_bytes = 3;
+  }
+  else if (code_point >= 0xE0)
+  {
+    code_point &= 0x0F;
+    extra_bytes = 2;
+  }
+  else if (code_point >= 0xC0)
+  {
+    code_point &= 0x1F;
+    extra_bytes = 1;
+  }
+
+  (*pos)++;
+
+  for (size_t i = 0; i < extra_bytes && *pos < string->len; i++)
+  {
+    code_point = (code_point << 6) | (bytes[*pos] & 0x3F);
+    (*pos)++;
+  }
+
+  return code_point;
+}
+
+/**
+ * Returns the grapheme cluster break category of a code point
+ */
+static CrocoGraphemeCat _croco_grapheme_cat(uint32_t c)
+{
+  // the Hangul syllables are LVT, except the first one of every 28 which is LV
+  if (c >= 0xAC00 && c <= 0xD7A3)
+  {
+    return (c - 0xAC00) % 28 == 0 ? CROCO_GC_LV : CROCO_GC_LVT;
+  }
+
+  size_t low = 0;
+  size_t high = sizeof(_croco_grapheme_ranges) / sizeof(_croco_grapheme_ranges[0]);
+
+  while (low < high)
+  {
+    size_t mid = low + (high - low) / 2;
+
+    if (c < _croco_grapheme_ranges[mid].start)
+    {
+      high = mid;
+    }
+    else if (c > _croco_grapheme_ranges[mid].end)
+    {
+      low = mid + 1;
+    }
+    else
+    {
+      return _croco_grapheme_ranges[mid].cat;
+    }
+  }
+
+  return CROCO_GC_ANY;
+}
+
+/**
+ * Checks if there is a grapheme boundary between two code points, following the rules of UAX #29.
+ * `pictographic_zwj` tells if prev is a ZWJ following an emoji and its extends,
+ * `odd_regional_indicators` if prev ends an odd run of regional indicators.
+ */
+static bool _croco_is_grapheme_break(CrocoGraphemeCat prev, CrocoGraphemeCat next,
+                                     bool pictographic_zwj, bool odd_regional_indicators)
+{
+  // GB3: \r\n is a single grapheme
+  if (prev == CROCO_GC_CR && next == CROCO_GC_LF)
+  {
+    return false;
+  }
+
+  // GB4 and GB5: break around the controls
+  if (prev == CROCO_GC_CR || prev == CROCO_GC_LF || prev == CROCO_GC_CONTROL ||
+      next == CROCO_GC_CR || next == CROCO_GC_LF || next == CROCO_GC_CONTROL)
+  {
+    return true;
+  }
+
+  // GB6 to GB8: the Hangul syllable sequences
+  switch (prev)
+  {
+  case CROCO_GC_L:
+    if (next == CROCO_GC_L || next == CROCO_GC_V || next == CROCO_GC_LV || next == CROCO_GC_LVT)
+    {
+      return false;
+    }
+    break;
+  case CROCO_GC_LV:
+  case CROCO_GC_V:
+    if (next == CROCO_GC_V || next == CROCO_GC_T)
+    {
+      return false;
+    }
+    break;
+  case CROCO_GC_LVT:
+  case CROCO_GC_T:
+    if (next == CROCO_GC_T)
+    {
+      return false;
+    }
+    break;
+  default:
+    break;
+  }
+
+  // GB9, GB9a and GB9b: the extends, spacing marks and prepends
+  if (next == CROCO_GC_EXTEND || next == CROCO_GC_ZWJ || next == CROCO_GC_SPACING_MARK ||
+      prev == CROCO_GC_PREPEND)
+  {
+    return false;
+  }
+
+  // GB11: the emoji joined with a ZWJ
+  if (pictographic_zwj && next == CROCO_GC_EXTENDED_PICTOGRAPHIC)
+  {
+    return false;
+  }
+
+  // GB12 and GB13: two regional indicators make a flag
+  if (odd_regional_indicators && next == CROCO_GC_REGIONAL_INDICATOR)
+  {
+    return false;
+  }
+
+  return true;
+}
+
+/**
+ * Returns the position of the end of the grapheme starting at a given position
+ */
+static size_t _croco_next_grapheme(CrocoStr *string, size_t pos)
+{
+  CrocoGraphemeCat prev = _croco_grapheme_cat(_croco_utf8_decode(string, &pos));
+
+  // if the code points so far end with an emoji followed by extends
+  bool pictographic = prev == CROCO_GC_EXTENDED_PICTOGRAPHIC;
+  bool pictographic_zwj = false;
+  bool odd_regional_indicators = prev == CROCO_GC_REGIONAL_INDICATOR;
+
+  while (pos < string->len)
+  {
+    size_t next_pos = pos;
+    CrocoGraphemeCat next = _croco_grapheme_cat(_croco_utf8_decode(string, &next_pos));
+
+    if (_croco_is_grapheme_break(prev, next, pictographic_zwj, odd_regional_indicators))
+    {
+      break;
+    }
+
+    pictographic_zwj = pictographic && next == CROCO_GC_ZWJ;
+    pictographic = next == CROCO_GC_EXTENDED_PICTOGRAPHIC ||
+                   (pictographic && next == CROCO_GC_EXTEND);
+    odd_regional_indicators = next == CROCO_GC_REGIONAL_INDICATOR && !odd_regional_indicators;
+    prev = next;
+    pos = next_pos;
+  }
+
+  return pos;
+}
+
+/**
+ * Counts the graphemes of a str
+ */
+static size_t _croco_grapheme_count(CrocoStr *string)
+{
+  size_t count = 0;
+
+  for (size_t pos = 0; pos < string->len; pos = _croco_next_grapheme(string, pos))
+  {
+    count++;
+  }
+
+  return count;
+}
+
+/**
+ * Checks if a code point is a whitespace, with the same definition as Rust
+ */
+static bool _croco_is_whitespace(uint32_t c)
+{
+  return (c >= 0x09 && c <= 0x0D) ||
+         c == 0x20 ||
+         c == 0x85 ||
+         c == 0xA0 ||
+         c == 0x1680 ||
+         (c >= 0x2000 && c <= 0x200A) ||
+         c == 0x2028 ||
+         c == 0x2029 ||
+         c == 0x202F ||
+         c == 0x205F ||
+         c == 0x3000;
+}
+
+/**
+ * Encodes a code point in UTF-8, and returns the number of bytes written
+ */
+static size_t _croco_utf8_encode(uint32_t c, char *bytes)
+{
+  if (c < 0x80)
+  {
+    bytes[0] = (char)c;
+    return 1;
+  }
+
+  if (c < 0x800)
+  {
+    bytes[0] = (char)(0xC0 | (c >> 6));
+    bytes[1] = (char)(0x80 | (c & 0x3F));
+    return 2;
+  }
+
+  if (c < 0x10000)
+  {
+    bytes[0] = (char)(0xE0 | (c >> 12));
+    bytes[1] = (char)(0x80 | ((c >> 6) & 0x3F));
+    bytes[2] = (char)(0x80 | (c & 0x3F));
+    return 3;
+  }
+
+  bytes[0] = (char)(0xF0 | (c >> 18));
+  bytes[1] = (char)(0x80 | ((c >> 12) & 0x3F));
+  bytes[2] = (char)(0x80 | ((c >> 6) & 0x3F));
+  bytes[3] = (char)(0x80 | (c & 0x3F));
+  return 4;
+}
+
+/**
+ * Converts a code point to uppercase.
+ * This covers the Latin, Greek and Cyrillic letters
+ */
+static uint32_t _croco_to_upper(uint32_t c)
+{
+  if ((c >= 'a' && c <= 'z') ||
+      (c >= 0xE0 && c <= 0xFE && c != 0xF7) ||
+      (c >= 0x3B1 && c <= 0x3CB && c != 0x3C2) ||
+      (c >= 0x430 && c <= 0x44F))
+  {
+    return c - 0x20;
+  }
+
+  if (c == 0xFF)
+  {
+    return 0x178;
+  }
+
+  // the final sigma
+  if (c == 0x3C2)
+  {
+    return 0x3A3;
+  }
+
+  if (c >= 0x450 && c <= 0x45F)
+  {
+    return c - 0x50;
+  }
+
+  // in Latin Extended-A, the lowercase letters follow their uppercase letter
+  if ((c >= 0x100 && c <= 0x12F && c % 2 == 1) ||
+      (c >= 0x132 && c <= 0x137 && c % 2 == 1) ||
+      (c >= 0x14A && c <= 0x177 && c % 2 == 1))
+  {
+    return c - 1;
+  }
+
+  if ((c >= 0x139 && c <= 0x148 && c % 2 == 0) ||
+      (c >= 0x179 && c <= 0x17E && c % 2 == 0))
+  {
+    return c - 1;
+  }
+
+  return c;
+}
+
+/**
+ * Converts a code point to lowercase.
+ * This covers the Latin, Greek and Cyrillic letters
+ */
+static uint32_t _croco_to_lower(uint32_t c)
+{
+  if ((c >= 'A' && c <= 'Z') ||
+      (c >= 0xC0 && c <= 0xDE && c != 0xD7) ||
+      (c >= 0x391 && c <= 0x3AB && c != 0x3A2) ||
+      (c >= 0x410 && c <= 0x42F))
+  {
+    return c + 0x20;
+  }
+
+  if (c == 0x178)
+  {
+    return 0xFF;
+  }
+
+  if (c >= 0x400 && c <= 0x40F)
+  {
+    return c + 0x50;
+  }
+
+  if ((c >= 0x100 && c <= 0x12F && c % 2 == 0) ||
+      (c >= 0x132 && c <= 0x137 && c % 2 == 0) ||
+      (c >= 0x14A && c <= 0x177 && c % 2 == 0))
+  {
+    return c + 1;
+  }
+
+  if ((c >= 0x139 && c <= 0x148 && c % 2 == 1) ||
+      (c >= 0x179 && c <= 0x17E && c % 2 == 1))
+  {
+    return c + 1;
+  }
+
+  return c;
+}
+
+/**
+ * Copies bytes into a new CrocoStr
+ */
+static void _croco_str_from_bytes(CrocoStr *string_res, char *bytes, size_t len)
+{
+  string_res->ptr = (char *)malloc(len);
+
+  if (len)
+  {
+    memcpy(string_res->ptr, bytes, len);
+  }
+
+  string_res->len = len;
+  string_res->max_len = len;
+}
+
+/**
+ * Appends bytes at the end of a CrocoStr
+ */
+static void _croco_str_append(CrocoStr *string, char *bytes, size_t len)
+{
+  if (string->len + len > string->max_len)
+  {
+    size_t new_max_len = string->max_len ? string->max_len * 2 : 8;
+
+    while (new_max_len < string->len + len)
+    {
+      new_max_len *= 2;
+    }
+
+    string->ptr = (char *)realloc(string->ptr, new_max_len);
+    string->max_len = new_max_len;
+  }
+
+  memcpy(string->ptr + string->len, bytes, len);
+  string->len += len;
+}
+
+/**
+ * Grows a CrocoArray so it can hold a given number of elements, doubling its capacity
+ */
+static void _croco_array_reserve(CrocoArray *array, size_t new_len, size_t el_size)
+{
+  if (new_len <= array->max_len)
+  {
+    return;
+  }
+
+  size_t new_max_len = array->max_len ? array->max_len * 2 : 4;
+
+  while (new_max_len < new_len)
+  {
+    new_max_len *= 2;
+  }
+
+  array->ptr = realloc(array->ptr, new_max_len * el_size);
+  array->max_len = new_max_len;
+}
+
+/**
+ * Appends a CrocoStr at the end of a str array
+ */
+static void _croco_array_push_str(CrocoArray *array, char *bytes, size_t len)
+{
+  _croco_array_reserve(array, array->len + 1, sizeof(CrocoStr));
+  _croco_str_from_bytes((CrocoStr *)array->ptr + array->len, bytes, len);
+  array->len++;
+}
+
+/**
+ * Appends an element at the end of an array
+ */
+void _croco_array_push(CrocoArray *array, void *el, size_t el_size)
+{
+  _croco_array_reserve(array, array->len + 1, el_size);
+  memcpy((char *)array->ptr + array->len * el_size, el, el_size);
+  array->len++;
+}
+
+/**
+ * Removes the last element of an array and copies it into `el_res`.
+ * Returns false if the array is empty
+ */
+bool _croco_array_pop(CrocoArray *array, void *el_res, size_t el_size)
+{
+  if (array->len == 0)
+  {
+    return false;
+  }
+
+  array->len--;
+  memcpy(el_res, (char *)array->ptr + array->len * el_size, el_size);
+  return true;
+}
+
+/**
+ * Inserts an element at the given index, shifting all the elements after it.
+ * Returns false if the index is out of bounds
+ */
+bool _croco_array_insert(CrocoArray *array, int32_t index, void *el, size_t el_size)
+{
+  if (index < 0 || (size_t)index > array->len)
+  {
+    return false;
+  }
+
+  _croco_array_reserve(array, array->len + 1, el_size);
+
+  char *el_ptr = (char *)array->ptr + index * el_size;
+  memmove(el_ptr + el_size, el_ptr, (array->len - index) * el_size);
+  memcpy(el_ptr, el, el_size);
+  array->len++;
+  return true;
+}
+
+/**
+ * Removes the element at the given index and copies it into `el_res`, shifting all the elements after it.
+ * Returns false if the index is out of bounds
+ */
+bool _croco_array_remove(CrocoArray *array, int32_t index, void *el_res, size_t el_size)
+{
+  if (index < 0 || (size_t)index >= array->len)
+  {
+    return false;
+  }
+
+  char *el_ptr = (char *)array->ptr + index * el_size;
+  memcpy(el_res, el_ptr, el_size);
+  memmove(el_ptr, el_ptr + el_size, (array->len - index - 1) * el_size);
+  array->len--;
+  return true;
+}
+
+/**
+ * Joins a str array into a str
+ */
+void _croco_array_join(CrocoStr *string_res, CrocoArray *array, CrocoStr *delimiter)
+{
+  string_res->ptr = NULL;
+  string_res->len = 0;
+  string_res->max_len = 0;
+
+  for (size_t i = 0; i < array->len; i++)
+  {
+    if (i != 0)
+    {
+      _croco_str_append(string_res, delimiter->ptr, delimiter->len);
+    }
+
+    CrocoStr *string = (CrocoStr *)array->ptr + i;
+    _croco_str_append(string_res, string->ptr, string->len);
+  }
+}
+
+/**
+ * Copies the elements of an array followed by the elements of another one into a new array
+ */
+void _croco_array_concat(CrocoArray *array_res, CrocoArray *array, CrocoArray *other, size_t el_size)
+{
+  array_res->ptr = NULL;
+  array_res->len = 0;
+  array_res->max_len = 0;
+
+  _croco_array_reserve(array_res, array->len + other->len, el_size);
+
+  if (array->len)
+  {
+    memcpy(array_res->ptr, array->ptr, array->len * el_size);
+  }
+
+  if (other->len)
+  {
+    memcpy((char *)array_res->ptr + array->len * el_size, other->ptr, other->len * el_size);
+  }
+
+  array_res->len = array->len + other->len;
+}
+
+/**
+ * Reverses the order of the elements of an array, in place
+ */
+void _croco_array_reverse(CrocoArray *array, size_t el_size)
+{
+  if (array->len < 2)
+  {
+    return;
+  }
+
+  char *tmp = malloc(el_size);
+  char *left = (char *)array->ptr;
+  char *right = (char *)array->ptr + (array->len - 1) * el_size;
+
+  while (left < right)
+  {
+    memcpy(tmp, left, el_size);
+    memcpy(left, right, el_size);
+    memcpy(right, tmp, el_size);
+
+    left += el_size;
+    right -= el_size;
+  }
+
+  free(tmp);
+}
+
+/**
+ * Copies the elements between a start index and an end index into a new array.
+ * Negative indexes start from the end of the array, like JavaScript Array.prototype.slice
+ */
+void _croco_array_slice(CrocoArray *array_res, CrocoArray *array, int32_t start, int32_t end, size_t el_size)
+{
+  int64_t len = (int64_t)array->len;
+  int64_t bounds[2] = {start, end};
+
+  // all indexes are clamped to the array
+  for (int i = 0; i < 2; i++)
+  {
+    if (bounds[i] < 0)
+    {
+      bounds[i] += len;
+    }
+
+    if (bounds[i] < 0)
+    {
+      bounds[i] = 0;
+    }
+    else if (bounds[i] > len)
+    {
+      bounds[i] = len;
+    }
+  }
+
+  size_t res_len = bounds[1] > bounds[0] ? (size_t)(bounds[1] - bounds[0]) : 0;
+
+  array_res->ptr = NULL;
+  array_res->len = 0;
+  array_res->max_len = 0;
+
+  if (res_len)
+  {
+    _croco_array_reserve(array_res, res_len, el_size);
+    memcpy(array_res->ptr, (char *)array->ptr + bounds[0] * el_size, res_len * el_size);
+  }
+
+  array_res->len = res_len;
+}
+
+/**
+ * Returns an array containing `times` times the `fnum`
+ */
+void _fnum_times(CrocoArray *array_res, float fnum, float times)
+{
+  // like in Rust, negative and NaN counts give an empty array
+  size_t len = times > 0 ? (size_t)times : 0;
+
+  array_res->ptr = malloc(len * sizeof(float));
+  array_res->len = len;
+  array_res->max_len = len;
+
+  for (size_t i = 0; i < len; i++)
+  {
+    ((float *)array_res->ptr)[i] = fnum;
+  }
+}
+
+/**
+ * Returns an array containing `times` times the `num`
+ */
+void _num_times(CrocoArray *array_res, int32_t num, float times)
+{
+  size_t len = times > 0 ? (size_t)times : 0;
+
+  array_res->ptr = malloc(len * sizeof(int32_t));
+  array_res->len = len;
+  array_res->max_len = len;
+
+  for (size_t i = 0; i < len; i++)
+  {
+    ((int32_t *)array_res->ptr)[i] = num;
+  }
+}
+
+/**
+ * Returns the graphemes of a str
+ */
+void _str_chars(CrocoArray *array_res, CrocoStr *string)
+{
+  array_res->ptr = NULL;
+  array_res->len = 0;
+  array_res->max_len = 0;
+
+  size_t pos = 0;
+
+  while (pos < string->len)
+  {
+    size_t next_pos = _croco_next_grapheme(string, pos);
+    _croco_array_push_str(array_res, string->ptr + pos, next_pos - pos);
+    pos = next_pos;
+  }
+}
+
+/**
+ * Returns the index in graphemes of the first occurence of a pattern starting on a grapheme,
+ * or -1 if there's none
+ */
+static int64_t _croco_find_grapheme(CrocoStr *string, CrocoStr *pattern)
+{
+  size_t pos = 0;
+  int64_t grapheme = 0;
+
+  while (pos + pattern->len <= string->len)
+  {
+    if (pattern->len == 0 || memcmp(string->ptr + pos, pattern->ptr, pattern->len) == 0)
+    {
+      return grapheme;
+    }
+
+    pos = _croco_next_grapheme(string, pos);
+    grapheme++;
+  }
+
+  return -1;
+}
+
+/**
+ * Returns true if a str contains a pattern, starting on a grapheme
+ */
+bool _str_contains(CrocoStr *string, CrocoStr *pattern)
+{
+  return _croco_find_grapheme(string, pattern) != -1;
+}
+
+/**
+ * Returns true if a str ends with a pattern
+ */
+bool _str_ends_with(CrocoStr *string, CrocoStr *pattern)
+{
+  return pattern->len <= string->len &&
+         (pattern->len == 0 ||
+          memcmp(string->ptr + string->len - pattern->len, pattern->ptr, pattern->len) == 0);
+}
+
+/**
+ * Returns the index in graphemes of the first occurence of a pattern, or -1 if there's none
+ */
+int32_t _str_find(CrocoStr *string, CrocoStr *pattern)
+{
+  return (int32_t)_croco_find_grapheme(string, pattern);
+}
+
+/**
+ * Returns the number of graphemes of a str, as a `fnum`
+ */
+float _str_len(CrocoStr *string)
+{
+  return (float)_croco_grapheme_count(string);
+}
+
+/**
+ * Representation of a croco result of a `num`
+ * {
+ *   is_err: i1,
+ *   message: CrocoStr,
+ *   value: i32
+ * }
+ */
+typedef struct
+{
+  bool is_err;
+  CrocoStr message;
+  int32_t value;
+} CrocoNumResult;
+
+/**
+ * Representation of a croco result of a `fnum`
+ * {
+ *   is_err: i1,
+ *   message: CrocoStr,
+ *   value: float
+ * }
+ */
+typedef struct
+{
+  bool is_err;
+  CrocoStr message;
+  float value;
+} CrocoFnumResult;
+
+/**
+ * Builds the error message of a str which can't be parsed, e.g cannot parse "a" as a num
+ */
+static void _croco_parse_error(CrocoStr *message, CrocoStr *string, char *type_name)
+{
+  message->ptr = NULL;
+  message->len = 0;
+  message->max_len = 0;
+
+  _croco_str_append(message, "cannot parse \"", 14);
+  _croco_str_append(message, string->ptr, string->len);
+  _croco_str_append(message, "\" as a ", 7);
+  _croco_str_append(message, type_name, strlen(type_name));
+}
+
+/**
+ * Returns true if the bytes starting at pos are the given word, ignoring the case
+ */
+static bool _croco_is_word_at(CrocoStr *string, size_t pos, char *word)
+{
+  size_t len = strlen(word);
+
+  if (string->len - pos != len)
+  {
+    return false;
+  }
+
+  for (size_t i = 0; i < len; i++)
+  {
+    char c = string->ptr[pos + i];
+
+    if (c >= 'A' && c <= 'Z')
+    {
+      c += 'a' - 'A';
+    }
+
+    if (c != word[i])
+    {
+      return false;
+    }
+  }
+
+  return true;
+}
+
+/**
+ * Skips the decimal digits starting at pos, and returns how many there are
+ */
+static size_t _croco_skip_digits(CrocoStr *string, size_t *pos)
+{
+  size_t start = *pos;
+
+  while (*pos < string->len && string->ptr[*pos] >= '0' && string->ptr[*pos] <= '9')
+  {
+    (*pos)++;
+  }
+
+  return *pos - start;
+}
+
+/**
+ * Returns true if a str is a decimal number, with the same syntax as in crocoi
+ * e.g -1.5, .5, 2., 1e-3, inf or nan
+ */
+static bool _croco_is_decimal(CrocoStr *string)
+{
+  size_t pos = 0;
+
+  if (pos < string->len && (string->ptr[pos] == '+' || string->ptr[pos] == '-'))
+  {
+    pos++;
+  }
+
+  if (_croco_is_word_at(string, pos, "inf") || _croco_is_word_at(string, pos, "infinity") ||
+      _croco_is_word_at(string, pos, "nan"))
+  {
+    return true;
+  }
+
+  size_t digits = _croco_skip_digits(string, &pos);
+
+  if (pos < string->len && string->ptr[pos] == '.')
+  {
+    pos++;
+    digits += _croco_skip_digits(string, &pos);
+  }
+
+  if (!digits)
+  {
+    return false;
+  }
+
+  if (pos < string->len && (string->ptr[pos] == 'e' || string->ptr[pos] == 'E'))
+  {
+    pos++;
+
+    if (pos < string->len && (string->ptr[pos] == '+' || string->ptr[pos] == '-'))
+    {
+      pos++;
+    }
+
+    if (!_croco_skip_digits(string, &pos))
+    {
+      return false;
+    }
+  }
+
+  return pos == string->len;
+}
+
+/**
+ * Parses a str into a `fnum`, or returns an error if it isn't a decimal number
+ */
+void _str_parse_fnum(CrocoFnumResult *res, CrocoStr *string)
+{
+  if (!_croco_is_decimal(string))
+  {
+    res->is_err = true;
+    _croco_parse_error(&res->message, string, "fnum");
+    return;
+  }
+
+  // strtof needs a null-terminated string
+  char *c_string = malloc(string->len + 1);
+  memcpy(c_string, string->ptr, string->len);
+  c_string[string->len] = '\0';
+
+  res->is_err = false;
+  res->value = strtof(c_string, NULL);
+  free(c_string);
+}
+
+/**
+ * Parses a str into a `num`, or returns an error if it isn't an integer which fits in a `num`
+ */
+void _str_parse_num(CrocoNumResult *res, CrocoStr *string)
+{
+  size_t pos = 0;
+  bool is_negative = false;
+
+  if (pos < string->len && (string->ptr[pos] == '+' || string->ptr[pos] == '-'))
+  {
+    is_negative = string->ptr[pos] == '-';
+    pos++;
+  }
+
+  // the value is accumulated negatively, since INT32_MIN has no positive counterpart
+  int64_t value = 0;
+  size_t start = pos;
+  bool overflows = false;
+
+  while (pos < string->len && string->ptr[pos] >= '0' && string->ptr[pos] <= '9')
+  {
+    // once it overflows, the digits are only checked
+    if (!overflows)
+    {
+      value = value * 10 - (string->ptr[pos] - '0');
+      overflows = value < INT32_MIN;
+    }
+
+    pos++;
+  }
+
+  if (!is_negative)
+  {
+    value = -value;
+    overflows = overflows || value > INT32_MAX;
+  }
+
+  if (pos == start || pos != string->len || overflows)
+  {
+    res->is_err = true;
+    _croco_parse_error(&res->message, string, "num");
+    return;
+  }
+
+  res->is_err = false;
+  res->value = (int32_t)value;
+}
+
+/**
+ * Repeats a str a number of times, a negative number giving an empty str
+ */
+void _str_repeat(CrocoStr *string_res, CrocoStr *string, int32_t times)
+{
+  string_res->ptr = NULL;
+  string_res->len = 0;
+  string_res->max_len = 0;
+
+  for (int32_t i = 0; i < times; i++)
+  {
+    _croco_str_append(string_res, string->ptr, string->len);
+  }
+}
+
+/**
+ * Replaces all the occurences of a pattern by another str.
+ * An empty pattern matches between each grapheme
+ */
+void _str_replace(CrocoStr *string_res, CrocoStr *string, CrocoStr *from, CrocoStr *to)
+{
+  string_res->ptr = NULL;
+  string_res->len = 0;
+  string_res->max_len = 0;
+
+  if (from->len == 0)
+  {
+    _croco_str_append(string_res, to->ptr, to->len);
+
+    size_t pos = 0;
+
+    while (pos < string->len)
+    {
+      size_t next_pos = _croco_next_grapheme(string, pos);
+      _croco_str_append(string_res, string->ptr + pos, next_pos - pos);
+      _croco_str_append(string_res, to->ptr, to->len);
+      pos = next_pos;
+    }
+
+    return;
+  }
+
+  size_t piece_start = 0;
+  size_t pos = 0;
+
+  while (pos + from->len <= string->len)
+  {
+    if (memcmp(string->ptr + pos, from->ptr, from->len) == 0)
+    {
+      _croco_str_append(string_res, string->ptr + piece_start, pos - piece_start);
+      _croco_str_append(string_res, to->ptr, to->len);
+      pos += from->len;
+      piece_start = pos;
+    }
+    else
+    {
+      pos++;
+    }
+  }
+
+  _croco_str_append(string_res, string->ptr + piece_start, string->len - piece_start);
+}
+
+/**
+ * Slices a str given a start offset and an end offset, in graphemes.
+ * Negative offsets start from the end of the str, like JavaScript String.prototype.slice
+ */
+void _str_slice(CrocoStr *string_res, CrocoStr *string, int32_t start, int32_t end)
+{
+  int64_t start_offset = start;
+  int64_t end_offset = end;
+
+  if (start_offset < 0 || end_offset < 0)
+  {
+    int64_t len = (int64_t)_croco_grapheme_count(string);
+
+    if (start_offset < 0)
+    {
+      start_offset += len;
+    }
+
+    if (end_offset < 0)
+    {
+      end_offset += len;
+    }
+  }
+
+  // a start still negative skips everything, like in crocoi
+  if (start_offset < 0 || end_offset <= start_offset)
+  {
+    _croco_str_from_bytes(string_res, NULL, 0);
+    return;
+  }
+
+  size_t start_pos = 0;
+  int64_t grapheme = 0;
+
+  while (grapheme < start_offset && start_pos < string->len)
+  {
+    start_pos = _croco_next_grapheme(string, start_pos);
+    grapheme++;
+  }
+
+  size_t end_pos = start_pos;
+
+  while (grapheme < end_offset && end_pos < string->len)
+  {
+    end_pos = _croco_next_grapheme(string, end_pos);
+    grapheme++;
+  }
+
+  _croco_str_from_bytes(string_res, string->ptr + start_pos, end_pos - start_pos);
+}
+
+/**
+ * Splits a str with a delimiter into a str array
+ */
+void _str_split(CrocoArray *array_res, CrocoStr *string, CrocoStr *delimiter)
+{
+  array_res->ptr = NULL;
+  array_res->len = 0;
+  array_res->max_len = 0;
+
+  // an empty delimiter splits every character, with an empty str at both ends like in Rust
+  if (delimiter->len == 0)
+  {
+    _croco_array_push_str(array_res, NULL, 0);
+
+    size_t pos = 0;
+    while (pos < string->len)
+    {
+      size_t char_start = pos;
+      _croco_utf8_decode(string, &pos);
+      _croco_array_push_str(array_res, string->ptr + char_start, pos - char_start);
+    }
+
+    _croco_array_push_str(array_res, NULL, 0);
+    return;
+  }
+
+  size_t piece_start = 0;
+  size_t pos = 0;
+
+  while (pos + delimiter->len <= string->len)
+  {
+    if (memcmp(string->ptr + pos, delimiter->ptr, delimiter->len) == 0)
+    {
+      _croco_array_push_str(array_res, string->ptr + piece_start, pos - piece_start);
+      pos += delimiter->len;
+      piece_start = pos;
+    }
+    else
+    {
+      pos++;
+    }
+  }
+
+  _croco_array_push_str(array_res, string->ptr + piece_start, string->len - piece_start);
+}
+
+/**
+ * Returns true if a str starts with a pattern
+ */
+bool _str_starts_with(CrocoStr *string, CrocoStr *pattern)
+{
+  return pattern->len <= string->len &&
+         (pattern->len == 0 || memcmp(string->ptr, pattern->ptr, pattern->len) == 0);
+}
+
+/**
+ * Converts each code point of a str with a case mapping
+ */
+static void _croco_str_map_case(CrocoStr *string_res, CrocoStr *string, uint32_t (*map)(uint32_t))
+{
+  string_res->ptr = NULL;
+  string_res->len = 0;
+  string_res->max_len = 0;
+
+  size_t pos = 0;
+  char bytes[4];
+
+  while (pos < string->len)
+  {
+    uint32_t c = map(_croco_utf8_decode(string, &pos));
+    _croco_str_append(string_res, bytes, _croco_utf8_encode(c, bytes));
+  }
+}
+
+/**
+ * Returns a str in lowercase
+ */
+void _str_to_lower(CrocoStr *string_res, CrocoStr *string)
+{
+  _croco_str_map_case(string_res, string, _croco_to_lower);
+}
+
+/**
+ * Returns a str in uppercase
+ */
+void _str_to_upper(CrocoStr *string_res, CrocoStr *string)
+{
+  string_res->ptr = NULL;
+  string_res->len = 0;
+  string_res->max_len = 0;
+
+  size_t pos = 0;
+  char bytes[4];
+
+  while (pos < string->len)
+  {
+    uint32_t c = _croco_utf8_decode(string, &pos);
+
+    // the sharp s is the only letter converted whose uppercase is two letters, like in crocoi
+    if (c == 0xDF)
+    {
+      _croco_str_append(string_res, "SS", 2);
+    }
+    else
+    {
+      c = _croco_to_upper(c);
+      _croco_str_append(string_res, bytes, _croco_utf8_encode(c, bytes));
+    }
+  }
+}
+
+/**
+ * Removes the whitespaces at the start and at the end of a str
+ */
+void _str_trim(CrocoStr *string_res, CrocoStr *string)
+{
+  size_t start = 0;
+  size_t end = 0;
+  size_t pos = 0;
+  bool found_start = false;
+
+  // the end is after the last character which isn't a whitespace
+  while (pos < string->len)
+  {
+    size_t char_start = pos;
+
+    if (!_croco_is_whitespace(_croco_utf8_decode(string, &pos)))
+    {
+      if (!found_start)
+      {
+        start = char_start;
+        found_start = true;
+      }
+
+      end = pos;
+    }
+  }
+
+  _croco_str_from_bytes(string_res, string->ptr + start, end - start);
+}
+
 /**
  * Exits if `assertion` is false
  */
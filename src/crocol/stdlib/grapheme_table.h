@@ -0,0 +1,659 @@
+/**
+ * Grapheme cluster break categories of the code points, from Unicode 13.0.0,
+ * the version used by the unicode-segmentation crate of crocoi.
+ * The Hangul LV and LVT syllables are computed instead of listed.
+ * Generated from the grapheme tables of unicode-segmentation 1.7.1.
+ */
+
+typedef enum
+{
+  CROCO_GC_ANY,
+  CROCO_GC_CR,
+  CROCO_GC_LF,
+  CROCO_GC_CONTROL,
+  CROCO_GC_EXTEND,
+  CROCO_GC_ZWJ,
+  CROCO_GC_REGIONAL_INDICATOR,
+  CROCO_GC_PREPEND,
+  CROCO_GC_SPACING_MARK,
+  CROCO_GC_L,
+  CROCO_GC_V,
+  CROCO_GC_T,
+  CROCO_GC_LV,
+  CROCO_GC_LVT,
+  CROCO_GC_EXTENDED_PICTOGRAPHIC,
+} CrocoGraphemeCat;
+
+typedef struct
+{
+  uint32_t start;
+  uint32_t end;
+  CrocoGraphemeCat cat;
+} CrocoGraphemeRange;
+
+static const CrocoGraphemeRange _croco_grapheme_ranges[] = {
+    {0x0000, 0x0009, CROCO_GC_CONTROL},
+    {0x000A, 0x000A, CROCO_GC_LF},
+    {0x000B, 0x000C, CROCO_GC_CONTROL},
+    {0x000D, 0x000D, CROCO_GC_CR},
+    {0x000E, 0x001F, CROCO_GC_CONTROL},
+    {0x007F, 0x009F, CROCO_GC_CONTROL},
+    {0x00A9, 0x00A9, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x00AD, 0x00AD, CROCO_GC_CONTROL},
+    {0x00AE, 0x00AE, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x0300, 0x036F, CROCO_GC_EXTEND},
+    {0x0483, 0x0489, CROCO_GC_EXTEND},
+    {0x0591, 0x05BD, CROCO_GC_EXTEND},
+    {0x05BF, 0x05BF, CROCO_GC_EXTEND},
+    {0x05C1, 0x05C2, CROCO_GC_EXTEND},
+    {0x05C4, 0x05C5, CROCO_GC_EXTEND},
+    {0x05C7, 0x05C7, CROCO_GC_EXTEND},
+    {0x0600, 0x0605, CROCO_GC_PREPEND},
+    {0x0610, 0x061A, CROCO_GC_EXTEND},
+    {0x061C, 0x061C, CROCO_GC_CONTROL},
+    {0x064B, 0x065F, CROCO_GC_EXTEND},
+    {0x0670, 0x0670, CROCO_GC_EXTEND},
+    {0x06D6, 0x06DC, CROCO_GC_EXTEND},
+    {0x06DD, 0x06DD, CROCO_GC_PREPEND},
+    {0x06DF, 0x06E4, CROCO_GC_EXTEND},
+    {0x06E7, 0x06E8, CROCO_GC_EXTEND},
+    {0x06EA, 0x06ED, CROCO_GC_EXTEND},
+    {0x070F, 0x070F, CROCO_GC_PREPEND},
+    {0x0711, 0x0711, CROCO_GC_EXTEND},
+    {0x0730, 0x074A, CROCO_GC_EXTEND},
+    {0x07A6, 0x07B0, CROCO_GC_EXTEND},
+    {0x07EB, 0x07F3, CROCO_GC_EXTEND},
+    {0x07FD, 0x07FD, CROCO_GC_EXTEND},
+    {0x0816, 0x0819, CROCO_GC_EXTEND},
+    {0x081B, 0x0823, CROCO_GC_EXTEND},
+    {0x0825, 0x0827, CROCO_GC_EXTEND},
+    {0x0829, 0x082D, CROCO_GC_EXTEND},
+    {0x0859, 0x085B, CROCO_GC_EXTEND},
+    {0x08D3, 0x08E1, CROCO_GC_EXTEND},
+    {0x08E2, 0x08E2, CROCO_GC_PREPEND},
+    {0x08E3, 0x0902, CROCO_GC_EXTEND},
+    {0x0903, 0x0903, CROCO_GC_SPACING_MARK},
+    {0x093A, 0x093A, CROCO_GC_EXTEND},
+    {0x093B, 0x093B, CROCO_GC_SPACING_MARK},
+    {0x093C, 0x093C, CROCO_GC_EXTEND},
+    {0x093E, 0x0940, CROCO_GC_SPACING_MARK},
+    {0x0941, 0x0948, CROCO_GC_EXTEND},
+    {0x0949, 0x094C, CROCO_GC_SPACING_MARK},
+    {0x094D, 0x094D, CROCO_GC_EXTEND},
+    {0x094E, 0x094F, CROCO_GC_SPACING_MARK},
+    {0x0951, 0x0957, CROCO_GC_EXTEND},
+    {0x0962, 0x0963, CROCO_GC_EXTEND},
+    {0x0981, 0x0981, CROCO_GC_EXTEND},
+    {0x0982, 0x0983, CROCO_GC_SPACING_MARK},
+    {0x09BC, 0x09BC, CROCO_GC_EXTEND},
+    {0x09BE, 0x09BE, CROCO_GC_EXTEND},
+    {0x09BF, 0x09C0, CROCO_GC_SPACING_MARK},
+    {0x09C1, 0x09C4, CROCO_GC_EXTEND},
+    {0x09C7, 0x09C8, CROCO_GC_SPACING_MARK},
+    {0x09CB, 0x09CC, CROCO_GC_SPACING_MARK},
+    {0x09CD, 0x09CD, CROCO_GC_EXTEND},
+    {0x09D7, 0x09D7, CROCO_GC_EXTEND},
+    {0x09E2, 0x09E3, CROCO_GC_EXTEND},
+    {0x09FE, 0x09FE, CROCO_GC_EXTEND},
+    {0x0A01, 0x0A02, CROCO_GC_EXTEND},
+    {0x0A03, 0x0A03, CROCO_GC_SPACING_MARK},
+    {0x0A3C, 0x0A3C, CROCO_GC_EXTEND},
+    {0x0A3E, 0x0A40, CROCO_GC_SPACING_MARK},
+    {0x0A41, 0x0A42, CROCO_GC_EXTEND},
+    {0x0A47, 0x0A48, CROCO_GC_EXTEND},
+    {0x0A4B, 0x0A4D, CROCO_GC_EXTEND},
+    {0x0A51, 0x0A51, CROCO_GC_EXTEND},
+    {0x0A70, 0x0A71, CROCO_GC_EXTEND},
+    {0x0A75, 0x0A75, CROCO_GC_EXTEND},
+    {0x0A81, 0x0A82, CROCO_GC_EXTEND},
+    {0x0A83, 0x0A83, CROCO_GC_SPACING_MARK},
+    {0x0ABC, 0x0ABC, CROCO_GC_EXTEND},
+    {0x0ABE, 0x0AC0, CROCO_GC_SPACING_MARK},
+    {0x0AC1, 0x0AC5, CROCO_GC_EXTEND},
+    {0x0AC7, 0x0AC8, CROCO_GC_EXTEND},
+    {0x0AC9, 0x0AC9, CROCO_GC_SPACING_MARK},
+    {0x0ACB, 0x0ACC, CROCO_GC_SPACING_MARK},
+    {0x0ACD, 0x0ACD, CROCO_GC_EXTEND},
+    {0x0AE2, 0x0AE3, CROCO_GC_EXTEND},
+    {0x0AFA, 0x0AFF, CROCO_GC_EXTEND},
+    {0x0B01, 0x0B01, CROCO_GC_EXTEND},
+    {0x0B02, 0x0B03, CROCO_GC_SPACING_MARK},
+    {0x0B3C, 0x0B3C, CROCO_GC_EXTEND},
+    {0x0B3E, 0x0B3F, CROCO_GC_EXTEND},
+    {0x0B40, 0x0B40, CROCO_GC_SPACING_MARK},
+    {0x0B41, 0x0B44, CROCO_GC_EXTEND},
+    {0x0B47, 0x0B48, CROCO_GC_SPACING_MARK},
+    {0x0B4B, 0x0B4C, CROCO_GC_SPACING_MARK},
+    {0x0B4D, 0x0B4D, CROCO_GC_EXTEND},
+    {0x0B55, 0x0B57, CROCO_GC_EXTEND},
+    {0x0B62, 0x0B63, CROCO_GC_EXTEND},
+    {0x0B82, 0x0B82, CROCO_GC_EXTEND},
+    {0x0BBE, 0x0BBE, CROCO_GC_EXTEND},
+    {0x0BBF, 0x0BBF, CROCO_GC_SPACING_MARK},
+    {0x0BC0, 0x0BC0, CROCO_GC_EXTEND},
+    {0x0BC1, 0x0BC2, CROCO_GC_SPACING_MARK},
+    {0x0BC6, 0x0BC8, CROCO_GC_SPACING_MARK},
+    {0x0BCA, 0x0BCC, CROCO_GC_SPACING_MARK},
+    {0x0BCD, 0x0BCD, CROCO_GC_EXTEND},
+    {0x0BD7, 0x0BD7, CROCO_GC_EXTEND},
+    {0x0C00, 0x0C00, CROCO_GC_EXTEND},
+    {0x0C01, 0x0C03, CROCO_GC_SPACING_MARK},
+    {0x0C04, 0x0C04, CROCO_GC_EXTEND},
+    {0x0C3E, 0x0C40, CROCO_GC_EXTEND},
+    {0x0C41, 0x0C44, CROCO_GC_SPACING_MARK},
+    {0x0C46, 0x0C48, CROCO_GC_EXTEND},
+    {0x0C4A, 0x0C4D, CROCO_GC_EXTEND},
+    {0x0C55, 0x0C56, CROCO_GC_EXTEND},
+    {0x0C62, 0x0C63, CROCO_GC_EXTEND},
+    {0x0C81, 0x0C81, CROCO_GC_EXTEND},
+    {0x0C82, 0x0C83, CROCO_GC_SPACING_MARK},
+    {0x0CBC, 0x0CBC, CROCO_GC_EXTEND},
+    {0x0CBE, 0x0CBE, CROCO_GC_SPACING_MARK},
+    {0x0CBF, 0x0CBF, CROCO_GC_EXTEND},
+    {0x0CC0, 0x0CC1, CROCO_GC_SPACING_MARK},
+    {0x0CC2, 0x0CC2, CROCO_GC_EXTEND},
+    {0x0CC3, 0x0CC4, CROCO_GC_SPACING_MARK},
+    {0x0CC6, 0x0CC6, CROCO_GC_EXTEND},
+    {0x0CC7, 0x0CC8, CROCO_GC_SPACING_MARK},
+    {0x0CCA, 0x0CCB, CROCO_GC_SPACING_MARK},
+    {0x0CCC, 0x0CCD, CROCO_GC_EXTEND},
+    {0x0CD5, 0x0CD6, CROCO_GC_EXTEND},
+    {0x0CE2, 0x0CE3, CROCO_GC_EXTEND},
+    {0x0D00, 0x0D01, CROCO_GC_EXTEND},
+    {0x0D02, 0x0D03, CROCO_GC_SPACING_MARK},
+    {0x0D3B, 0x0D3C, CROCO_GC_EXTEND},
+    {0x0D3E, 0x0D3E, CROCO_GC_EXTEND},
+    {0x0D3F, 0x0D40, CROCO_GC_SPACING_MARK},
+    {0x0D41, 0x0D44, CROCO_GC_EXTEND},
+    {0x0D46, 0x0D48, CROCO_GC_SPACING_MARK},
+    {0x0D4A, 0x0D4C, CROCO_GC_SPACING_MARK},
+    {0x0D4D, 0x0D4D, CROCO_GC_EXTEND},
+    {0x0D4E, 0x0D4E, CROCO_GC_PREPEND},
+    {0x0D57, 0x0D57, CROCO_GC_EXTEND},
+    {0x0D62, 0x0D63, CROCO_GC_EXTEND},
+    {0x0D81, 0x0D81, CROCO_GC_EXTEND},
+    {0x0D82, 0x0D83, CROCO_GC_SPACING_MARK},
+    {0x0DCA, 0x0DCA, CROCO_GC_EXTEND},
+    {0x0DCF, 0x0DCF, CROCO_GC_EXTEND},
+    {0x0DD0, 0x0DD1, CROCO_GC_SPACING_MARK},
+    {0x0DD2, 0x0DD4, CROCO_GC_EXTEND},
+    {0x0DD6, 0x0DD6, CROCO_GC_EXTEND},
+    {0x0DD8, 0x0DDE, CROCO_GC_SPACING_MARK},
+    {0x0DDF, 0x0DDF, CROCO_GC_EXTEND},
+    {0x0DF2, 0x0DF3, CROCO_GC_SPACING_MARK},
+    {0x0E31, 0x0E31, CROCO_GC_EXTEND},
+    {0x0E33, 0x0E33, CROCO_GC_SPACING_MARK},
+    {0x0E34, 0x0E3A, CROCO_GC_EXTEND},
+    {0x0E47, 0x0E4E, CROCO_GC_EXTEND},
+    {0x0EB1, 0x0EB1, CROCO_GC_EXTEND},
+    {0x0EB3, 0x0EB3, CROCO_GC_SPACING_MARK},
+    {0x0EB4, 0x0EBC, CROCO_GC_EXTEND},
+    {0x0EC8, 0x0ECD, CROCO_GC_EXTEND},
+    {0x0F18, 0x0F19, CROCO_GC_EXTEND},
+    {0x0F35, 0x0F35, CROCO_GC_EXTEND},
+    {0x0F37, 0x0F37, CROCO_GC_EXTEND},
+    {0x0F39, 0x0F39, CROCO_GC_EXTEND},
+    {0x0F3E, 0x0F3F, CROCO_GC_SPACING_MARK},
+    {0x0F71, 0x0F7E, CROCO_GC_EXTEND},
+    {0x0F7F, 0x0F7F, CROCO_GC_SPACING_MARK},
+    {0x0F80, 0x0F84, CROCO_GC_EXTEND},
+    {0x0F86, 0x0F87, CROCO_GC_EXTEND},
+    {0x0F8D, 0x0F97, CROCO_GC_EXTEND},
+    {0x0F99, 0x0FBC, CROCO_GC_EXTEND},
+    {0x0FC6, 0x0FC6, CROCO_GC_EXTEND},
+    {0x102D, 0x1030, CROCO_GC_EXTEND},
+    {0x1031, 0x1031, CROCO_GC_SPACING_MARK},
+    {0x1032, 0x1037, CROCO_GC_EXTEND},
+    {0x1039, 0x103A, CROCO_GC_EXTEND},
+    {0x103B, 0x103C, CROCO_GC_SPACING_MARK},
+    {0x103D, 0x103E, CROCO_GC_EXTEND},
+    {0x1056, 0x1057, CROCO_GC_SPACING_MARK},
+    {0x1058, 0x1059, CROCO_GC_EXTEND},
+    {0x105E, 0x1060, CROCO_GC_EXTEND},
+    {0x1071, 0x1074, CROCO_GC_EXTEND},
+    {0x1082, 0x1082, CROCO_GC_EXTEND},
+    {0x1084, 0x1084, CROCO_GC_SPACING_MARK},
+    {0x1085, 0x1086, CROCO_GC_EXTEND},
+    {0x108D, 0x108D, CROCO_GC_EXTEND},
+    {0x109D, 0x109D, CROCO_GC_EXTEND},
+    {0x1100, 0x115F, CROCO_GC_L},
+    {0x1160, 0x11A7, CROCO_GC_V},
+    {0x11A8, 0x11FF, CROCO_GC_T},
+    {0x135D, 0x135F, CROCO_GC_EXTEND},
+    {0x1712, 0x1714, CROCO_GC_EXTEND},
+    {0x1732, 0x1734, CROCO_GC_EXTEND},
+    {0x1752, 0x1753, CROCO_GC_EXTEND},
+    {0x1772, 0x1773, CROCO_GC_EXTEND},
+    {0x17B4, 0x17B5, CROCO_GC_EXTEND},
+    {0x17B6, 0x17B6, CROCO_GC_SPACING_MARK},
+    {0x17B7, 0x17BD, CROCO_GC_EXTEND},
+    {0x17BE, 0x17C5, CROCO_GC_SPACING_MARK},
+    {0x17C6, 0x17C6, CROCO_GC_EXTEND},
+    {0x17C7, 0x17C8, CROCO_GC_SPACING_MARK},
+    {0x17C9, 0x17D3, CROCO_GC_EXTEND},
+    {0x17DD, 0x17DD, CROCO_GC_EXTEND},
+    {0x180B, 0x180D, CROCO_GC_EXTEND},
+    {0x180E, 0x180E, CROCO_GC_CONTROL},
+    {0x1885, 0x1886, CROCO_GC_EXTEND},
+    {0x18A9, 0x18A9, CROCO_GC_EXTEND},
+    {0x1920, 0x1922, CROCO_GC_EXTEND},
+    {0x1923, 0x1926, CROCO_GC_SPACING_MARK},
+    {0x1927, 0x1928, CROCO_GC_EXTEND},
+    {0x1929, 0x192B, CROCO_GC_SPACING_MARK},
+    {0x1930, 0x1931, CROCO_GC_SPACING_MARK},
+    {0x1932, 0x1932, CROCO_GC_EXTEND},
+    {0x1933, 0x1938, CROCO_GC_SPACING_MARK},
+    {0x1939, 0x193B, CROCO_GC_EXTEND},
+    {0x1A17, 0x1A18, CROCO_GC_EXTEND},
+    {0x1A19, 0x1A1A, CROCO_GC_SPACING_MARK},
+    {0x1A1B, 0x1A1B, CROCO_GC_EXTEND},
+    {0x1A55, 0x1A55, CROCO_GC_SPACING_MARK},
+    {0x1A56, 0x1A56, CROCO_GC_EXTEND},
+    {0x1A57, 0x1A57, CROCO_GC_SPACING_MARK},
+    {0x1A58, 0x1A5E, CROCO_GC_EXTEND},
+    {0x1A60, 0x1A60, CROCO_GC_EXTEND},
+    {0x1A62, 0x1A62, CROCO_GC_EXTEND},
+    {0x1A65, 0x1A6C, CROCO_GC_EXTEND},
+    {0x1A6D, 0x1A72, CROCO_GC_SPACING_MARK},
+    {0x1A73, 0x1A7C, CROCO_GC_EXTEND},
+    {0x1A7F, 0x1A7F, CROCO_GC_EXTEND},
+    {0x1AB0, 0x1AC0, CROCO_GC_EXTEND},
+    {0x1B00, 0x1B03, CROCO_GC_EXTEND},
+    {0x1B04, 0x1B04, CROCO_GC_SPACING_MARK},
+    {0x1B34, 0x1B3A, CROCO_GC_EXTEND},
+    {0x1B3B, 0x1B3B, CROCO_GC_SPACING_MARK},
+    {0x1B3C, 0x1B3C, CROCO_GC_EXTEND},
+    {0x1B3D, 0x1B41, CROCO_GC_SPACING_MARK},
+    {0x1B42, 0x1B42, CROCO_GC_EXTEND},
+    {0x1B43, 0x1B44, CROCO_GC_SPACING_MARK},
+    {0x1B6B, 0x1B73, CROCO_GC_EXTEND},
+    {0x1B80, 0x1B81, CROCO_GC_EXTEND},
+    {0x1B82, 0x1B82, CROCO_GC_SPACING_MARK},
+    {0x1BA1, 0x1BA1, CROCO_GC_SPACING_MARK},
+    {0x1BA2, 0x1BA5, CROCO_GC_EXTEND},
+    {0x1BA6, 0x1BA7, CROCO_GC_SPACING_MARK},
+    {0x1BA8, 0x1BA9, CROCO_GC_EXTEND},
+    {0x1BAA, 0x1BAA, CROCO_GC_SPACING_MARK},
+    {0x1BAB, 0x1BAD, CROCO_GC_EXTEND},
+    {0x1BE6, 0x1BE6, CROCO_GC_EXTEND},
+    {0x1BE7, 0x1BE7, CROCO_GC_SPACING_MARK},
+    {0x1BE8, 0x1BE9, CROCO_GC_EXTEND},
+    {0x1BEA, 0x1BEC, CROCO_GC_SPACING_MARK},
+    {0x1BED, 0x1BED, CROCO_GC_EXTEND},
+    {0x1BEE, 0x1BEE, CROCO_GC_SPACING_MARK},
+    {0x1BEF, 0x1BF1, CROCO_GC_EXTEND},
+    {0x1BF2, 0x1BF3, CROCO_GC_SPACING_MARK},
+    {0x1C24, 0x1C2B, CROCO_GC_SPACING_MARK},
+    {0x1C2C, 0x1C33, CROCO_GC_EXTEND},
+    {0x1C34, 0x1C35, CROCO_GC_SPACING_MARK},
+    {0x1C36, 0x1C37, CROCO_GC_EXTEND},
+    {0x1CD0, 0x1CD2, CROCO_GC_EXTEND},
+    {0x1CD4, 0x1CE0, CROCO_GC_EXTEND},
+    {0x1CE1, 0x1CE1, CROCO_GC_SPACING_MARK},
+    {0x1CE2, 0x1CE8, CROCO_GC_EXTEND},
+    {0x1CED, 0x1CED, CROCO_GC_EXTEND},
+    {0x1CF4, 0x1CF4, CROCO_GC_EXTEND},
+    {0x1CF7, 0x1CF7, CROCO_GC_SPACING_MARK},
+    {0x1CF8, 0x1CF9, CROCO_GC_EXTEND},
+    {0x1DC0, 0x1DF9, CROCO_GC_EXTEND},
+    {0x1DFB, 0x1DFF, CROCO_GC_EXTEND},
+    {0x200B, 0x200B, CROCO_GC_CONTROL},
+    {0x200C, 0x200C, CROCO_GC_EXTEND},
+    {0x200D, 0x200D, CROCO_GC_ZWJ},
+    {0x200E, 0x200F, CROCO_GC_CONTROL},
+    {0x2028, 0x202E, CROCO_GC_CONTROL},
+    {0x203C, 0x203C, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2049, 0x2049, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2060, 0x206F, CROCO_GC_CONTROL},
+    {0x20D0, 0x20F0, CROCO_GC_EXTEND},
+    {0x2122, 0x2122, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2139, 0x2139, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2194, 0x2199, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x21A9, 0x21AA, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x231A, 0x231B, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2328, 0x2328, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2388, 0x2388, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x23CF, 0x23CF, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x23E9, 0x23F3, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x23F8, 0x23FA, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x24C2, 0x24C2, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x25AA, 0x25AB, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x25B6, 0x25B6, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x25C0, 0x25C0, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x25FB, 0x25FE, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2600, 0x2605, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2607, 0x2612, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2614, 0x2685, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2690, 0x2705, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2708, 0x2712, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2714, 0x2714, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2716, 0x2716, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x271D, 0x271D, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2721, 0x2721, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2728, 0x2728, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2733, 0x2734, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2744, 0x2744, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2747, 0x2747, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x274C, 0x274C, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x274E, 0x274E, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2753, 0x2755, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2757, 0x2757, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2763, 0x2767, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2795, 0x2797, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x27A1, 0x27A1, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x27B0, 0x27B0, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x27BF, 0x27BF, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2934, 0x2935, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2B05, 0x2B07, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2B1B, 0x2B1C, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2B50, 0x2B50, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2B55, 0x2B55, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x2CEF, 0x2CF1, CROCO_GC_EXTEND},
+    {0x2D7F, 0x2D7F, CROCO_GC_EXTEND},
+    {0x2DE0, 0x2DFF, CROCO_GC_EXTEND},
+    {0x302A, 0x302F, CROCO_GC_EXTEND},
+    {0x3030, 0x3030, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x303D, 0x303D, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x3099, 0x309A, CROCO_GC_EXTEND},
+    {0x3297, 0x3297, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x3299, 0x3299, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0xA66F, 0xA672, CROCO_GC_EXTEND},
+    {0xA674, 0xA67D, CROCO_GC_EXTEND},
+    {0xA69E, 0xA69F, CROCO_GC_EXTEND},
+    {0xA6F0, 0xA6F1, CROCO_GC_EXTEND},
+    {0xA802, 0xA802, CROCO_GC_EXTEND},
+    {0xA806, 0xA806, CROCO_GC_EXTEND},
+    {0xA80B, 0xA80B, CROCO_GC_EXTEND},
+    {0xA823, 0xA824, CROCO_GC_SPACING_MARK},
+    {0xA825, 0xA826, CROCO_GC_EXTEND},
+    {0xA827, 0xA827, CROCO_GC_SPACING_MARK},
+    {0xA82C, 0xA82C, CROCO_GC_EXTEND},
+    {0xA880, 0xA881, CROCO_GC_SPACING_MARK},
+    {0xA8B4, 0xA8C3, CROCO_GC_SPACING_MARK},
+    {0xA8C4, 0xA8C5, CROCO_GC_EXTEND},
+    {0xA8E0, 0xA8F1, CROCO_GC_EXTEND},
+    {0xA8FF, 0xA8FF, CROCO_GC_EXTEND},
+    {0xA926, 0xA92D, CROCO_GC_EXTEND},
+    {0xA947, 0xA951, CROCO_GC_EXTEND},
+    {0xA952, 0xA953, CROCO_GC_SPACING_MARK},
+    {0xA960, 0xA97C, CROCO_GC_L},
+    {0xA980, 0xA982, CROCO_GC_EXTEND},
+    {0xA983, 0xA983, CROCO_GC_SPACING_MARK},
+    {0xA9B3, 0xA9B3, CROCO_GC_EXTEND},
+    {0xA9B4, 0xA9B5, CROCO_GC_SPACING_MARK},
+    {0xA9B6, 0xA9B9, CROCO_GC_EXTEND},
+    {0xA9BA, 0xA9BB, CROCO_GC_SPACING_MARK},
+    {0xA9BC, 0xA9BD, CROCO_GC_EXTEND},
+    {0xA9BE, 0xA9C0, CROCO_GC_SPACING_MARK},
+    {0xA9E5, 0xA9E5, CROCO_GC_EXTEND},
+    {0xAA29, 0xAA2E, CROCO_GC_EXTEND},
+    {0xAA2F, 0xAA30, CROCO_GC_SPACING_MARK},
+    {0xAA31, 0xAA32, CROCO_GC_EXTEND},
+    {0xAA33, 0xAA34, CROCO_GC_SPACING_MARK},
+    {0xAA35, 0xAA36, CROCO_GC_EXTEND},
+    {0xAA43, 0xAA43, CROCO_GC_EXTEND},
+    {0xAA4C, 0xAA4C, CROCO_GC_EXTEND},
+    {0xAA4D, 0xAA4D, CROCO_GC_SPACING_MARK},
+    {0xAA7C, 0xAA7C, CROCO_GC_EXTEND},
+    {0xAAB0, 0xAAB0, CROCO_GC_EXTEND},
+    {0xAAB2, 0xAAB4, CROCO_GC_EXTEND},
+    {0xAAB7, 0xAAB8, CROCO_GC_EXTEND},
+    {0xAABE, 0xAABF, CROCO_GC_EXTEND},
+    {0xAAC1, 0xAAC1, CROCO_GC_EXTEND},
+    {0xAAEB, 0xAAEB, CROCO_GC_SPACING_MARK},
+    {0xAAEC, 0xAAED, CROCO_GC_EXTEND},
+    {0xAAEE, 0xAAEF, CROCO_GC_SPACING_MARK},
+    {0xAAF5, 0xAAF5, CROCO_GC_SPACING_MARK},
+    {0xAAF6, 0xAAF6, CROCO_GC_EXTEND},
+    {0xABE3, 0xABE4, CROCO_GC_SPACING_MARK},
+    {0xABE5, 0xABE5, CROCO_GC_EXTEND},
+    {0xABE6, 0xABE7, CROCO_GC_SPACING_MARK},
+    {0xABE8, 0xABE8, CROCO_GC_EXTEND},
+    {0xABE9, 0xABEA, CROCO_GC_SPACING_MARK},
+    {0xABEC, 0xABEC, CROCO_GC_SPACING_MARK},
+    {0xABED, 0xABED, CROCO_GC_EXTEND},
+    {0xD7B0, 0xD7C6, CROCO_GC_V},
+    {0xD7CB, 0xD7FB, CROCO_GC_T},
+    {0xFB1E, 0xFB1E, CROCO_GC_EXTEND},
+    {0xFE00, 0xFE0F, CROCO_GC_EXTEND},
+    {0xFE20, 0xFE2F, CROCO_GC_EXTEND},
+    {0xFEFF, 0xFEFF, CROCO_GC_CONTROL},
+    {0xFF9E, 0xFF9F, CROCO_GC_EXTEND},
+    {0xFFF0, 0xFFFB, CROCO_GC_CONTROL},
+    {0x101FD, 0x101FD, CROCO_GC_EXTEND},
+    {0x102E0, 0x102E0, CROCO_GC_EXTEND},
+    {0x10376, 0x1037A, CROCO_GC_EXTEND},
+    {0x10A01, 0x10A03, CROCO_GC_EXTEND},
+    {0x10A05, 0x10A06, CROCO_GC_EXTEND},
+    {0x10A0C, 0x10A0F, CROCO_GC_EXTEND},
+    {0x10A38, 0x10A3A, CROCO_GC_EXTEND},
+    {0x10A3F, 0x10A3F, CROCO_GC_EXTEND},
+    {0x10AE5, 0x10AE6, CROCO_GC_EXTEND},
+    {0x10D24, 0x10D27, CROCO_GC_EXTEND},
+    {0x10EAB, 0x10EAC, CROCO_GC_EXTEND},
+    {0x10F46, 0x10F50, CROCO_GC_EXTEND},
+    {0x11000, 0x11000, CROCO_GC_SPACING_MARK},
+    {0x11001, 0x11001, CROCO_GC_EXTEND},
+    {0x11002, 0x11002, CROCO_GC_SPACING_MARK},
+    {0x11038, 0x11046, CROCO_GC_EXTEND},
+    {0x1107F, 0x11081, CROCO_GC_EXTEND},
+    {0x11082, 0x11082, CROCO_GC_SPACING_MARK},
+    {0x110B0, 0x110B2, CROCO_GC_SPACING_MARK},
+    {0x110B3, 0x110B6, CROCO_GC_EXTEND},
+    {0x110B7, 0x110B8, CROCO_GC_SPACING_MARK},
+    {0x110B9, 0x110BA, CROCO_GC_EXTEND},
+    {0x110BD, 0x110BD, CROCO_GC_PREPEND},
+    {0x110CD, 0x110CD, CROCO_GC_PREPEND},
+    {0x11100, 0x11102, CROCO_GC_EXTEND},
+    {0x11127, 0x1112B, CROCO_GC_EXTEND},
+    {0x1112C, 0x1112C, CROCO_GC_SPACING_MARK},
+    {0x1112D, 0x11134, CROCO_GC_EXTEND},
+    {0x11145, 0x11146, CROCO_GC_SPACING_MARK},
+    {0x11173, 0x11173, CROCO_GC_EXTEND},
+    {0x11180, 0x11181, CROCO_GC_EXTEND},
+    {0x11182, 0x11182, CROCO_GC_SPACING_MARK},
+    {0x111B3, 0x111B5, CROCO_GC_SPACING_MARK},
+    {0x111B6, 0x111BE, CROCO_GC_EXTEND},
+    {0x111BF, 0x111C0, CROCO_GC_SPACING_MARK},
+    {0x111C2, 0x111C3, CROCO_GC_PREPEND},
+    {0x111C9, 0x111CC, CROCO_GC_EXTEND},
+    {0x111CE, 0x111CE, CROCO_GC_SPACING_MARK},
+    {0x111CF, 0x111CF, CROCO_GC_EXTEND},
+    {0x1122C, 0x1122E, CROCO_GC_SPACING_MARK},
+    {0x1122F, 0x11231, CROCO_GC_EXTEND},
+    {0x11232, 0x11233, CROCO_GC_SPACING_MARK},
+    {0x11234, 0x11234, CROCO_GC_EXTEND},
+    {0x11235, 0x11235, CROCO_GC_SPACING_MARK},
+    {0x11236, 0x11237, CROCO_GC_EXTEND},
+    {0x1123E, 0x1123E, CROCO_GC_EXTEND},
+    {0x112DF, 0x112DF, CROCO_GC_EXTEND},
+    {0x112E0, 0x112E2, CROCO_GC_SPACING_MARK},
+    {0x112E3, 0x112EA, CROCO_GC_EXTEND},
+    {0x11300, 0x11301, CROCO_GC_EXTEND},
+    {0x11302, 0x11303, CROCO_GC_SPACING_MARK},
+    {0x1133B, 0x1133C, CROCO_GC_EXTEND},
+    {0x1133E, 0x1133E, CROCO_GC_EXTEND},
+    {0x1133F, 0x1133F, CROCO_GC_SPACING_MARK},
+    {0x11340, 0x11340, CROCO_GC_EXTEND},
+    {0x11341, 0x11344, CROCO_GC_SPACING_MARK},
+    {0x11347, 0x11348, CROCO_GC_SPACING_MARK},
+    {0x1134B, 0x1134D, CROCO_GC_SPACING_MARK},
+    {0x11357, 0x11357, CROCO_GC_EXTEND},
+    {0x11362, 0x11363, CROCO_GC_SPACING_MARK},
+    {0x11366, 0x1136C, CROCO_GC_EXTEND},
+    {0x11370, 0x11374, CROCO_GC_EXTEND},
+    {0x11435, 0x11437, CROCO_GC_SPACING_MARK},
+    {0x11438, 0x1143F, CROCO_GC_EXTEND},
+    {0x11440, 0x11441, CROCO_GC_SPACING_MARK},
+    {0x11442, 0x11444, CROCO_GC_EXTEND},
+    {0x11445, 0x11445, CROCO_GC_SPACING_MARK},
+    {0x11446, 0x11446, CROCO_GC_EXTEND},
+    {0x1145E, 0x1145E, CROCO_GC_EXTEND},
+    {0x114B0, 0x114B0, CROCO_GC_EXTEND},
+    {0x114B1, 0x114B2, CROCO_GC_SPACING_MARK},
+    {0x114B3, 0x114B8, CROCO_GC_EXTEND},
+    {0x114B9, 0x114B9, CROCO_GC_SPACING_MARK},
+    {0x114BA, 0x114BA, CROCO_GC_EXTEND},
+    {0x114BB, 0x114BC, CROCO_GC_SPACING_MARK},
+    {0x114BD, 0x114BD, CROCO_GC_EXTEND},
+    {0x114BE, 0x114BE, CROCO_GC_SPACING_MARK},
+    {0x114BF, 0x114C0, CROCO_GC_EXTEND},
+    {0x114C1, 0x114C1, CROCO_GC_SPACING_MARK},
+    {0x114C2, 0x114C3, CROCO_GC_EXTEND},
+    {0x115AF, 0x115AF, CROCO_GC_EXTEND},
+    {0x115B0, 0x115B1, CROCO_GC_SPACING_MARK},
+    {0x115B2, 0x115B5, CROCO_GC_EXTEND},
+    {0x115B8, 0x115BB, CROCO_GC_SPACING_MARK},
+    {0x115BC, 0x115BD, CROCO_GC_EXTEND},
+    {0x115BE, 0x115BE, CROCO_GC_SPACING_MARK},
+    {0x115BF, 0x115C0, CROCO_GC_EXTEND},
+    {0x115DC, 0x115DD, CROCO_GC_EXTEND},
+    {0x11630, 0x11632, CROCO_GC_SPACING_MARK},
+    {0x11633, 0x1163A, CROCO_GC_EXTEND},
+    {0x1163B, 0x1163C, CROCO_GC_SPACING_MARK},
+    {0x1163D, 0x1163D, CROCO_GC_EXTEND},
+    {0x1163E, 0x1163E, CROCO_GC_SPACING_MARK},
+    {0x1163F, 0x11640, CROCO_GC_EXTEND},
+    {0x116AB, 0x116AB, CROCO_GC_EXTEND},
+    {0x116AC, 0x116AC, CROCO_GC_SPACING_MARK},
+    {0x116AD, 0x116AD, CROCO_GC_EXTEND},
+    {0x116AE, 0x116AF, CROCO_GC_SPACING_MARK},
+    {0x116B0, 0x116B5, CROCO_GC_EXTEND},
+    {0x116B6, 0x116B6, CROCO_GC_SPACING_MARK},
+    {0x116B7, 0x116B7, CROCO_GC_EXTEND},
+    {0x1171D, 0x1171F, CROCO_GC_EXTEND},
+    {0x11720, 0x11721, CROCO_GC_SPACING_MARK},
+    {0x11722, 0x11725, CROCO_GC_EXTEND},
+    {0x11726, 0x11726, CROCO_GC_SPACING_MARK},
+    {0x11727, 0x1172B, CROCO_GC_EXTEND},
+    {0x1182C, 0x1182E, CROCO_GC_SPACING_MARK},
+    {0x1182F, 0x11837, CROCO_GC_EXTEND},
+    {0x11838, 0x11838, CROCO_GC_SPACING_MARK},
+    {0x11839, 0x1183A, CROCO_GC_EXTEND},
+    {0x11930, 0x11930, CROCO_GC_EXTEND},
+    {0x11931, 0x11935, CROCO_GC_SPACING_MARK},
+    {0x11937, 0x11938, CROCO_GC_SPACING_MARK},
+    {0x1193B, 0x1193C, CROCO_GC_EXTEND},
+    {0x1193D, 0x1193D, CROCO_GC_SPACING_MARK},
+    {0x1193E, 0x1193E, CROCO_GC_EXTEND},
+    {0x1193F, 0x1193F, CROCO_GC_PREPEND},
+    {0x11940, 0x11940, CROCO_GC_SPACING_MARK},
+    {0x11941, 0x11941, CROCO_GC_PREPEND},
+    {0x11942, 0x11942, CROCO_GC_SPACING_MARK},
+    {0x11943, 0x11943, CROCO_GC_EXTEND},
+    {0x119D1, 0x119D3, CROCO_GC_SPACING_MARK},
+    {0x119D4, 0x119D7, CROCO_GC_EXTEND},
+    {0x119DA, 0x119DB, CROCO_GC_EXTEND},
+    {0x119DC, 0x119DF, CROCO_GC_SPACING_MARK},
+    {0x119E0, 0x119E0, CROCO_GC_EXTEND},
+    {0x119E4, 0x119E4, CROCO_GC_SPACING_MARK},
+    {0x11A01, 0x11A0A, CROCO_GC_EXTEND},
+    {0x11A33, 0x11A38, CROCO_GC_EXTEND},
+    {0x11A39, 0x11A39, CROCO_GC_SPACING_MARK},
+    {0x11A3A, 0x11A3A, CROCO_GC_PREPEND},
+    {0x11A3B, 0x11A3E, CROCO_GC_EXTEND},
+    {0x11A47, 0x11A47, CROCO_GC_EXTEND},
+    {0x11A51, 0x11A56, CROCO_GC_EXTEND},
+    {0x11A57, 0x11A58, CROCO_GC_SPACING_MARK},
+    {0x11A59, 0x11A5B, CROCO_GC_EXTEND},
+    {0x11A84, 0x11A89, CROCO_GC_PREPEND},
+    {0x11A8A, 0x11A96, CROCO_GC_EXTEND},
+    {0x11A97, 0x11A97, CROCO_GC_SPACING_MARK},
+    {0x11A98, 0x11A99, CROCO_GC_EXTEND},
+    {0x11C2F, 0x11C2F, CROCO_GC_SPACING_MARK},
+    {0x11C30, 0x11C36, CROCO_GC_EXTEND},
+    {0x11C38, 0x11C3D, CROCO_GC_EXTEND},
+    {0x11C3E, 0x11C3E, CROCO_GC_SPACING_MARK},
+    {0x11C3F, 0x11C3F, CROCO_GC_EXTEND},
+    {0x11C92, 0x11CA7, CROCO_GC_EXTEND},
+    {0x11CA9, 0x11CA9, CROCO_GC_SPACING_MARK},
+    {0x11CAA, 0x11CB0, CROCO_GC_EXTEND},
+    {0x11CB1, 0x11CB1, CROCO_GC_SPACING_MARK},
+    {0x11CB2, 0x11CB3, CROCO_GC_EXTEND},
+    {0x11CB4, 0x11CB4, CROCO_GC_SPACING_MARK},
+    {0x11CB5, 0x11CB6, CROCO_GC_EXTEND},
+    {0x11D31, 0x11D36, CROCO_GC_EXTEND},
+    {0x11D3A, 0x11D3A, CROCO_GC_EXTEND},
+    {0x11D3C, 0x11D3D, CROCO_GC_EXTEND},
+    {0x11D3F, 0x11D45, CROCO_GC_EXTEND},
+    {0x11D46, 0x11D46, CROCO_GC_PREPEND},
+    {0x11D47, 0x11D47, CROCO_GC_EXTEND},
+    {0x11D8A, 0x11D8E, CROCO_GC_SPACING_MARK},
+    {0x11D90, 0x11D91, CROCO_GC_EXTEND},
+    {0x11D93, 0x11D94, CROCO_GC_SPACING_MARK},
+    {0x11D95, 0x11D95, CROCO_GC_EXTEND},
+    {0x11D96, 0x11D96, CROCO_GC_SPACING_MARK},
+    {0x11D97, 0x11D97, CROCO_GC_EXTEND},
+    {0x11EF3, 0x11EF4, CROCO_GC_EXTEND},
+    {0x11EF5, 0x11EF6, CROCO_GC_SPACING_MARK},
+    {0x13430, 0x13438, CROCO_GC_CONTROL},
+    {0x16AF0, 0x16AF4, CROCO_GC_EXTEND},
+    {0x16B30, 0x16B36, CROCO_GC_EXTEND},
+    {0x16F4F, 0x16F4F, CROCO_GC_EXTEND},
+    {0x16F51, 0x16F87, CROCO_GC_SPACING_MARK},
+    {0x16F8F, 0x16F92, CROCO_GC_EXTEND},
+    {0x16FE4, 0x16FE4, CROCO_GC_EXTEND},
+    {0x16FF0, 0x16FF1, CROCO_GC_SPACING_MARK},
+    {0x1BC9D, 0x1BC9E, CROCO_GC_EXTEND},
+    {0x1BCA0, 0x1BCA3, CROCO_GC_CONTROL},
+    {0x1D165, 0x1D165, CROCO_GC_EXTEND},
+    {0x1D166, 0x1D166, CROCO_GC_SPACING_MARK},
+    {0x1D167, 0x1D169, CROCO_GC_EXTEND},
+    {0x1D16D, 0x1D16D, CROCO_GC_SPACING_MARK},
+    {0x1D16E, 0x1D172, CROCO_GC_EXTEND},
+    {0x1D173, 0x1D17A, CROCO_GC_CONTROL},
+    {0x1D17B, 0x1D182, CROCO_GC_EXTEND},
+    {0x1D185, 0x1D18B, CROCO_GC_EXTEND},
+    {0x1D1AA, 0x1D1AD, CROCO_GC_EXTEND},
+    {0x1D242, 0x1D244, CROCO_GC_EXTEND},
+    {0x1DA00, 0x1DA36, CROCO_GC_EXTEND},
+    {0x1DA3B, 0x1DA6C, CROCO_GC_EXTEND},
+    {0x1DA75, 0x1DA75, CROCO_GC_EXTEND},
+    {0x1DA84, 0x1DA84, CROCO_GC_EXTEND},
+    {0x1DA9B, 0x1DA9F, CROCO_GC_EXTEND},
+    {0x1DAA1, 0x1DAAF, CROCO_GC_EXTEND},
+    {0x1E000, 0x1E006, CROCO_GC_EXTEND},
+    {0x1E008, 0x1E018, CROCO_GC_EXTEND},
+    {0x1E01B, 0x1E021, CROCO_GC_EXTEND},
+    {0x1E023, 0x1E024, CROCO_GC_EXTEND},
+    {0x1E026, 0x1E02A, CROCO_GC_EXTEND},
+    {0x1E130, 0x1E136, CROCO_GC_EXTEND},
+    {0x1E2EC, 0x1E2EF, CROCO_GC_EXTEND},
+    {0x1E8D0, 0x1E8D6, CROCO_GC_EXTEND},
+    {0x1E944, 0x1E94A, CROCO_GC_EXTEND},
+    {0x1F000, 0x1F0FF, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F10D, 0x1F10F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F12F, 0x1F12F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F16C, 0x1F171, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F17E, 0x1F17F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F18E, 0x1F18E, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F191, 0x1F19A, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F1AD, 0x1F1E5, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F1E6, 0x1F1FF, CROCO_GC_REGIONAL_INDICATOR},
+    {0x1F201, 0x1F20F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F21A, 0x1F21A, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F22F, 0x1F22F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F232, 0x1F23A, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F23C, 0x1F23F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F249, 0x1F3FA, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F3FB, 0x1F3FF, CROCO_GC_EXTEND},
+    {0x1F400, 0x1F53D, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F546, 0x1F64F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F680, 0x1F6FF, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F774, 0x1F77F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F7D5, 0x1F7FF, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F80C, 0x1F80F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F848, 0x1F84F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F85A, 0x1F85F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F888, 0x1F88F, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F8AE, 0x1F8FF, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F90C, 0x1F93A, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F93C, 0x1F945, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1F947, 0x1FAFF, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0x1FC00, 0x1FFFD, CROCO_GC_EXTENDED_PICTOGRAPHIC},
+    {0xE0000, 0xE001F, CROCO_GC_CONTROL},
+    {0xE0020, 0xE007F, CROCO_GC_EXTEND},
+    {0xE0080, 0xE00FF, CROCO_GC_CONTROL},
+    {0xE0100, 0xE01EF, CROCO_GC_EXTEND},
+    {0xE01F0, 0xE0FFF, CROCO_GC_CONTROL},
+};
@@ -0,0 +1,345 @@
+use std::collections::HashMap;
+
+use inkwell::{
+    module::Linkage,
+    types::{BasicType, BasicTypeEnum},
+    values::BasicValueEnum,
+    IntPredicate,
+};
+
+use crate::crocol::{symbol::get_instance_name, utils::get_llvm_fn_type, LCodegen, LSymbol};
+use crate::parser::TypedArg;
+use crate::symbol::{Decl, FunctionDecl};
+use crate::symbol_type::SymbolType;
+
+/// How a function of the math module is lowered
+enum Lowering {
+    /// A call to an LLVM intrinsic, which may itself become a libm call
+    Intrinsic(&'static str),
+    /// A call to a libm function without intrinsic
+    Libm(&'static str),
+    /// The natural logarithm of x divided by the one of the base.
+    /// The base is optional, and defaults to 10
+    Log,
+}
+
+/// How a function of the math module taking numbers of any type is lowered
+enum NumberLowering {
+    Abs,
+    Max,
+    Min,
+    /// max then min, so that the max wins when min is greater than max
+    Clamp,
+}
+
+/// The functions of the math module taking fnums, with their number of arguments.
+/// They all return a fnum.
+const FUNCTIONS: [(&str, usize, Lowering); 16] = [
+    ("acos", 1, Lowering::Libm("acosf")),
+    ("asin", 1, Lowering::Libm("asinf")),
+    ("atan", 1, Lowering::Libm("atanf")),
+    ("atan2", 2, Lowering::Libm("atan2f")),
+    ("ceil", 1, Lowering::Intrinsic("llvm.ceil.f32")),
+    ("cos", 1, Lowering::Intrinsic("llvm.cos.f32")),
+    ("exp", 1, Lowering::Intrinsic("llvm.exp.f32")),
+    ("floor", 1, Lowering::Intrinsic("llvm.floor.f32")),
+    ("hypot", 2, Lowering::Libm("hypotf")),
+    ("ln", 1, Lowering::Intrinsic("llvm.log.f32")),
+    ("log", 2, Lowering::Log),
+    ("pow", 2, Lowering::Intrinsic("llvm.pow.f32")),
+    ("round", 1, Lowering::Intrinsic("llvm.round.f32")),
+    ("sin", 1, Lowering::Intrinsic("llvm.sin.f32")),
+    ("sqrt", 1, Lowering::Intrinsic("llvm.sqrt.f32")),
+    ("tan", 1, Lowering::Libm("tanf")),
+];
+
+/// The functions of the math module taking numbers of any type, with their number of arguments.
+/// They return a number of the same type as their arguments.
+const NUMBER_FUNCTIONS: [(&str, usize, NumberLowering); 4] = [
+    ("abs", 1, NumberLowering::Abs),
+    ("clamp", 3, NumberLowering::Clamp),
+    ("max", 2, NumberLowering::Max),
+    ("min", 2, NumberLowering::Min),
+];
+
+/// The number types the functions taking numbers are instantiated for
+const NUMBER_TYPES: [SymbolType; 6] = [
+    SymbolType::Num,
+    SymbolType::I64,
+    SymbolType::U64,
+    SymbolType::U8,
+    SymbolType::Fnum,
+    SymbolType::F64,
+];
+
+/// Builds the functions and the constants of the math module, under the math namespace
+pub fn import_module(codegen: &mut LCodegen) {
+    // the module may be imported by several files of the program
+    if codegen.module.get_function("math.sqrt").is_some() {
+        return;
+    }
+
+    // the module is built outside of the current function
+    let insert_block = codegen.builder.get_insert_block();
+
+    for (name, arg_count, lowering) in FUNCTIONS.iter() {
+        build_function(name, *arg_count, lowering, codegen);
+    }
+
+    for (name, arg_count, lowering) in NUMBER_FUNCTIONS.iter() {
+        build_number_function(name, *arg_count, lowering, codegen);
+    }
+
+    match insert_block {
+        Some(block) => codegen.builder.position_at_end(block),
+        None => codegen.builder.clear_insertion_position(),
+    }
+
+    let constants = [
+        ("pi", std::f64::consts::PI),
+        ("e", std::f64::consts::E),
+        ("inf", f64::INFINITY),
+        ("nan", f64::NAN),
+    ];
+
+    let f32_type = codegen.context.f32_type();
+
+    for (name, value) in constants.iter() {
+        let namespaced_name = format!("math.{}", name);
+        let global = codegen.module.add_global(f32_type, None, &namespaced_name);
+        global.set_linkage(Linkage::Internal);
+        global.set_initializer(&f32_type.const_float(*value));
+        global.set_constant(true);
+
+        let symbol = LSymbol {
+            value: global.as_pointer_value().into(),
+            symbol_type: SymbolType::Fnum,
+        };
+
+        codegen
+            .symtable
+            .register_decl(namespaced_name, Decl::GlobalVariable(symbol))
+            .unwrap();
+    }
+}
+
+/// Declares a math function and builds its body
+fn build_function(name: &str, arg_count: usize, lowering: &Lowering, codegen: &mut LCodegen) {
+    let namespaced_name = format!("math.{}", name);
+
+    let mut fn_decl = FunctionDecl {
+        args: vec![
+            TypedArg {
+                arg_name: String::new(),
+                arg_type: SymbolType::Fnum,
+            };
+            arg_count
+        ],
+        return_type: Some(SymbolType::Fnum),
+    };
+
+    if let Lowering::Log = lowering {
+        fn_decl.args[1].arg_type = SymbolType::Optional(Box::new(SymbolType::Fnum));
+    }
+
+    let fn_type = get_llvm_fn_type(&fn_decl, codegen);
+
+    codegen
+        .symtable
+        .register_decl(namespaced_name.clone(), Decl::FunctionDecl(fn_decl))
+        .unwrap();
+
+    let function = codegen
+        .module
+        .add_function(&namespaced_name, fn_type, Some(Linkage::Internal));
+
+    let entry = codegen.context.append_basic_block(function, "entry");
+    codegen.builder.position_at_end(entry);
+
+    let params: Vec<BasicValueEnum> = function.get_param_iter().collect();
+
+    let value = match lowering {
+        Lowering::Intrinsic(callee) | Lowering::Libm(callee) => {
+            build_float_call(callee, &params, codegen)
+        }
+        Lowering::Log => {
+            // the base is a null pointer when it's omitted
+            let f32_type = codegen.context.f32_type();
+            let default_base_ptr = codegen.builder.build_alloca(f32_type, "defaultbase");
+            codegen
+                .builder
+                .build_store(default_base_ptr, f32_type.const_float(10.));
+
+            let base_ptr = params[1].into_pointer_value();
+            let is_none = codegen.builder.build_is_null(base_ptr, "isnone");
+            let base_ptr = codegen
+                .builder
+                .build_select(is_none, default_base_ptr, base_ptr, "baseptr")
+                .into_pointer_value();
+            let base = codegen.builder.build_load(base_ptr, "base");
+
+            let ln_x = build_float_call("llvm.log.f32", &params[..1], codegen);
+            let ln_base = build_float_call("llvm.log.f32", &[base], codegen);
+            codegen
+                .builder
+                .build_float_div(ln_x.into_float_value(), ln_base.into_float_value(), "log")
+                .into()
+        }
+    };
+
+    codegen.builder.build_return(Some(&value));
+}
+
+/// Declares a generic math function taking numbers,
+/// and builds its instances for all the number types.
+/// The instances are named like the ones of the generic functions, e.g math.abs<num>
+fn build_number_function(
+    name: &str,
+    arg_count: usize,
+    lowering: &NumberLowering,
+    codegen: &mut LCodegen,
+) {
+    let namespaced_name = format!("math.{}", name);
+    let generic_type = SymbolType::Generic("T".to_owned());
+
+    let fn_decl = FunctionDecl {
+        args: vec![
+            TypedArg {
+                arg_name: String::new(),
+                arg_type: generic_type.clone(),
+            };
+            arg_count
+        ],
+        return_type: Some(generic_type),
+    };
+
+    for number_type in NUMBER_TYPES.iter() {
+        let mut generics = HashMap::new();
+        generics.insert("T".to_owned(), number_type.clone());
+        let instance_decl = fn_decl.resolve_generics(&generics);
+        let instance_name = get_instance_name(&namespaced_name, &instance_decl);
+
+        let fn_type = get_llvm_fn_type(&instance_decl, codegen);
+        let function =
+            codegen
+                .module
+                .add_function(&instance_name, fn_type, Some(Linkage::Internal));
+
+        let entry = codegen.context.append_basic_block(function, "entry");
+        codegen.builder.position_at_end(entry);
+
+        let params: Vec<BasicValueEnum> = function.get_param_iter().collect();
+
+        let value = match lowering {
+            NumberLowering::Abs => build_abs(params[0], number_type, codegen),
+            NumberLowering::Max => build_min_max(params[0], params[1], true, number_type, codegen),
+            NumberLowering::Min => build_min_max(params[0], params[1], false, number_type, codegen),
+            NumberLowering::Clamp => {
+                let at_least_min = build_min_max(params[0], params[1], true, number_type, codegen);
+                build_min_max(at_least_min, params[2], false, number_type, codegen)
+            }
+        };
+
+        codegen.builder.build_return(Some(&value));
+
+        codegen
+            .symtable
+            .register_decl(instance_name, Decl::FunctionDecl(instance_decl))
+            .unwrap();
+    }
+
+    codegen
+        .symtable
+        .register_decl(namespaced_name, Decl::FunctionDecl(fn_decl))
+        .unwrap();
+}
+
+/// Returns the absolute value of a number, wrapping around for the smallest signed integers
+fn build_abs<'ctx>(
+    value: BasicValueEnum<'ctx>,
+    number_type: &SymbolType,
+    codegen: &LCodegen<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    if number_type.is_float() {
+        let callee = format!("llvm.fabs.{}", get_float_suffix(number_type));
+        return build_float_call(&callee, &[value], codegen);
+    }
+
+    if number_type.is_unsigned() {
+        return value;
+    }
+
+    let value = value.into_int_value();
+    let zero = value.get_type().const_zero();
+    let is_negative =
+        codegen
+            .builder
+            .build_int_compare(IntPredicate::SLT, value, zero, "isnegative");
+    let negated = codegen.builder.build_int_neg(value, "negated");
+
+    codegen
+        .builder
+        .build_select(is_negative, negated, value, "abs")
+}
+
+/// Returns the greatest or the smallest of two numbers.
+/// For floats, nan is ignored
+fn build_min_max<'ctx>(
+    a: BasicValueEnum<'ctx>,
+    b: BasicValueEnum<'ctx>,
+    is_max: bool,
+    number_type: &SymbolType,
+    codegen: &LCodegen<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    if number_type.is_float() {
+        let intrinsic = if is_max { "maxnum" } else { "minnum" };
+        let callee = format!("llvm.{}.{}", intrinsic, get_float_suffix(number_type));
+        return build_float_call(&callee, &[a, b], codegen);
+    }
+
+    let predicate = match (is_max, number_type.is_unsigned()) {
+        (true, true) => IntPredicate::UGT,
+        (true, false) => IntPredicate::SGT,
+        (false, true) => IntPredicate::ULT,
+        (false, false) => IntPredicate::SLT,
+    };
+
+    let a_wins = codegen.builder.build_int_compare(
+        predicate,
+        a.into_int_value(),
+        b.into_int_value(),
+        "cmpminmax",
+    );
+
+    codegen.builder.build_select(a_wins, a, b, "minmax")
+}
+
+/// Returns the suffix of the LLVM intrinsics for a float type
+fn get_float_suffix(number_type: &SymbolType) -> &'static str {
+    if *number_type == SymbolType::Fnum {
+        "f32"
+    } else {
+        "f64"
+    }
+}
+
+/// Calls a function taking and returning floats of the same type, declaring it if needed
+fn build_float_call<'ctx>(
+    callee: &str,
+    args: &[BasicValueEnum<'ctx>],
+    codegen: &LCodegen<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    let function = codegen.module.get_function(callee).unwrap_or_else(|| {
+        let float_type = args[0].get_type();
+        let param_types: Vec<BasicTypeEnum> = vec![float_type; args.len()];
+        let fn_type = float_type.fn_type(&param_types, false);
+        codegen.module.add_function(callee, fn_type, None)
+    });
+
+    codegen
+        .builder
+        .build_call(function, args, callee)
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+}
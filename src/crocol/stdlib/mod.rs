@@ -0,0 +1,17 @@
+// the builtin modules which can be imported in crocol.
+// the global functions are defined in global.c
+
+// the math module
+pub mod math;
+
+use crate::crocol::LCodegen;
+
+/// imports a builtin module by name, returns false if there is no such module
+pub fn import_builtin_module(codegen: &mut LCodegen, name: &str) -> bool {
+    match name {
+        "math" => math::import_module(codegen),
+        _ => return false,
+    }
+
+    true
+}
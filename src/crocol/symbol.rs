@@ -3,20 +3,23 @@ use inkwell::{
     basic_block::BasicBlock,
     builder::Builder,
     context::Context,
-    module::Module,
+    module::{Linkage, Module},
+    targets::TargetData,
     types::BasicTypeEnum,
     types::IntType,
     types::StructType,
-    values::{BasicValueEnum, FloatValue, FunctionValue, IntValue, PointerValue},
+    values::{BasicValueEnum, FloatValue, FunctionValue, InstructionValue, IntValue, PointerValue},
     AddressSpace,
 };
 
-use crate::crocol::utils::get_or_define_function;
+use crate::crocol::utils::{get_env_type, get_or_define_closure_fn, get_or_define_function};
+use crate::symbol::{Decl, FunctionDecl};
 use crate::{ast::BackendNode, token::CodePos};
 use crate::{ast::NodeResult, symbol_type::SymbolType};
 use crate::{error::CrocoError, symbol::SymTable};
+use std::collections::HashMap;
 
-use super::utils::{get_llvm_type, get_or_define_struct};
+use super::utils::{const_zero, get_llvm_type, get_or_define_struct};
 
 // I'll be using a simple struct as in the README example for now
 // https://github.com/TheDan64/inkwell/blob/master/README.md
@@ -34,8 +37,16 @@ pub struct LCodegen<'ctx> {
     pub array_type: StructType<'ctx>,
     /// The str type as defined in crocol
     pub str_type: StructType<'ctx>,
+    /// The map type as defined in crocol
+    pub map_type: StructType<'ctx>,
+    /// The trait object type, a pointer to the data and a pointer to the vtable
+    pub trait_type: StructType<'ctx>,
+    /// The function value type, a pointer to the function and a pointer to its environment
+    pub closure_type: StructType<'ctx>,
     /// The pointer size of this architecture
     pub ptr_size: IntType<'ctx>,
+    /// The data layout of the target, used to size the enums
+    pub target_data: TargetData,
     /// The current function being built
     pub current_fn: Option<FunctionValue<'ctx>>,
     /// The current block when a loop is being built. This is used for the continue instruction.
@@ -44,6 +55,32 @@ pub struct LCodegen<'ctx> {
     pub current_loop_end_block: Option<BasicBlock<'ctx>>,
     /// The pointer used as a return value in case of a sret function
     pub sret_ptr: Option<PointerValue<'ctx>>,
+    /// The return type of the current function being built
+    pub return_type: Option<SymbolType>,
+    /// The bodies of the generic functions, which are built once per set of type arguments
+    pub generic_fns: HashMap<String, (Box<dyn BackendNode>, CodePos)>,
+    /// The instances of generic functions which still have to be built, with the generic function name
+    pub pending_instances: Vec<(String, String)>,
+    /// The types bound to the type parameters of the generic instance being built
+    pub generics: HashMap<String, SymbolType>,
+    /// The closures which still have to be built
+    pub pending_closures: Vec<PendingClosure>,
+    /// The functions and methods declared, which are built once all the global variables are known
+    pub pending_fns: Vec<(String, Box<dyn BackendNode>, CodePos)>,
+    /// The function initializing the global variables, called at the start of main
+    pub init_fn: FunctionValue<'ctx>,
+    /// The stores giving their first value to the local variables.
+    /// A variable captured by a closure is moved to the heap there, so each declaration gets its own box
+    pub decl_stores: HashMap<PointerValue<'ctx>, InstructionValue<'ctx>>,
+}
+
+/// A closure whose body is built once the whole tree is built
+pub struct PendingClosure {
+    pub fn_name: String,
+    pub fn_body: Box<dyn BackendNode>,
+    /// The variables captured by reference, in the order of the environment struct
+    pub captures: Vec<(String, SymbolType)>,
+    pub code_pos: CodePos,
 }
 
 impl<'ctx> LCodegen<'ctx> {
@@ -61,17 +98,58 @@ impl<'ctx> LCodegen<'ctx> {
 
             alloca
 
-        // global variable
+        // there is no function at the top level
         } else {
-            todo!("handle global variables !");
+            self.create_global(ty, name)
         }
     }
 
+    /// Stores the first value of a local variable, where it is declared
+    pub fn build_decl_store(&mut self, var_ptr: PointerValue<'ctx>, value: BasicValueEnum<'ctx>) {
+        let store = self.builder.build_store(var_ptr, value);
+        self.decl_stores.insert(var_ptr, store);
+    }
+
+    /// Creates a zero-initialized global variable, internal so it never clashes with a C symbol
+    pub fn create_global(&self, ty: BasicTypeEnum<'ctx>, name: &str) -> PointerValue<'ctx> {
+        let global = self.module.add_global(ty, None, name);
+        global.set_linkage(Linkage::Internal);
+        global.set_initializer(&const_zero(ty));
+        global.as_pointer_value()
+    }
+
+    /// Moves the builder to the end of the init function, to compute the value of a global variable
+    pub fn enter_init_fn(&mut self) {
+        let init_block = self.init_fn.get_last_basic_block().unwrap();
+        self.builder.position_at_end(init_block);
+        self.current_fn = Some(self.init_fn);
+        self.sret_ptr = None;
+        self.return_type = None;
+    }
+
+    /// Moves the builder back to the top level, where there is no function
+    pub fn leave_init_fn(&mut self) {
+        self.builder.clear_insertion_position();
+        self.current_fn = None;
+    }
+
     /// Builds a function from an AST node
     pub fn build_function(
+        &mut self,
+        fn_name: &str,
+        fn_body: Box<dyn BackendNode>,
+        code_pos: &CodePos,
+    ) -> Result<(), CrocoError> {
+        self.build_function_with_env(fn_name, fn_body, None, code_pos)
+    }
+
+    /// Builds a function from an AST node.
+    /// If captures are given, the function is a closure taking its environment as the last parameter.
+    pub fn build_function_with_env(
         &mut self,
         fn_name: &str,
         mut fn_body: Box<dyn BackendNode>,
+        captures: Option<&[(String, SymbolType)]>,
         code_pos: &CodePos,
     ) -> Result<(), CrocoError> {
         let fn_decl = self.symtable.get_function_decl(fn_name).unwrap().clone();
@@ -79,11 +157,22 @@ impl<'ctx> LCodegen<'ctx> {
         // we're done with the current variables
         self.symtable.pop_symbols();
 
-        let function = get_or_define_function(fn_name, &fn_decl, self);
+        let function = match captures {
+            Some(_) => get_or_define_closure_fn(fn_name, &fn_decl, self),
+            None => get_or_define_function(fn_name, &fn_decl, self),
+        };
         let sret_fn = matches!(
             fn_decl.return_type,
-            Some(SymbolType::Struct(_)) | Some(SymbolType::Str)
+            Some(SymbolType::Struct(_))
+                | Some(SymbolType::Str)
+                | Some(SymbolType::Array(_))
+                | Some(SymbolType::Map(_, _))
+                | Some(SymbolType::Trait(_))
+                | Some(SymbolType::Enum(_))
+                | Some(SymbolType::Result(_))
+                | Some(SymbolType::Tuple(_))
         );
+        self.return_type = fn_decl.return_type.clone();
 
         // add the sret tag to the first param if needed
         if sret_fn {
@@ -109,21 +198,39 @@ impl<'ctx> LCodegen<'ctx> {
 
         self.current_fn = Some(function);
 
+        // the global variables are initialized before anything else
+        if fn_name == "main" && captures.is_none() {
+            self.builder.build_call(self.init_fn, &[], "callinit");
+        }
+
         // inject the function arguments in the body
         for (arg, param_value) in args_iter {
             // to comply with the "C ABI", fn(Struct a) is changed to fn(&Struct a)
             // this means we need to add a memcpy in the function body.
             let abi_ptr = match &arg.arg_type {
                 // TODO: copy correctly heap allocated memory
-                SymbolType::Str | SymbolType::Array(_) | SymbolType::Struct(_) => {
-                    let ty = if let SymbolType::Struct(struct_name) = &arg.arg_type {
-                        let struct_ty = self
-                            .symtable
-                            .get_struct_decl(struct_name)
-                            .map_err(|e| CrocoError::new(code_pos, e))?;
-                        get_or_define_struct(struct_name, &struct_ty, self)
-                    } else {
-                        self.str_type
+                SymbolType::Str
+                | SymbolType::Array(_)
+                | SymbolType::Map(_, _)
+                | SymbolType::Struct(_)
+                | SymbolType::Trait(_)
+                | SymbolType::Enum(_)
+                | SymbolType::Result(_)
+                | SymbolType::Tuple(_) => {
+                    let ty = match &arg.arg_type {
+                        SymbolType::Struct(struct_name) => {
+                            let struct_ty = self
+                                .symtable
+                                .get_struct_decl(struct_name)
+                                .map_err(|e| CrocoError::new(code_pos, e))?;
+                            get_or_define_struct(struct_name, &struct_ty, self)
+                        }
+                        SymbolType::Trait(_) => self.trait_type,
+                        SymbolType::Map(_, _) => self.map_type,
+                        SymbolType::Result(_) | SymbolType::Enum(_) | SymbolType::Tuple(_) => {
+                            get_llvm_type(&arg.arg_type, self).into_struct_type()
+                        }
+                        _ => self.str_type,
                     };
 
                     let copy_alloca = self.create_block_alloca(ty.into(), "copy");
@@ -139,7 +246,16 @@ impl<'ctx> LCodegen<'ctx> {
                         .unwrap();
                     copy_alloca
                 }
-                SymbolType::Bool | SymbolType::Num | SymbolType::Fnum | SymbolType::Ref(_) => {
+                SymbolType::Bool
+                | SymbolType::Num
+                | SymbolType::Fnum
+                | SymbolType::I64
+                | SymbolType::U64
+                | SymbolType::U8
+                | SymbolType::F64
+                | SymbolType::Ref(_)
+                | SymbolType::Optional(_)
+                | SymbolType::Function(_) => {
                     let param_ptr = self.create_block_alloca(param_value.get_type(), "param");
                     self.builder.build_store(param_ptr, param_value);
                     param_ptr
@@ -156,6 +272,35 @@ impl<'ctx> LCodegen<'ctx> {
                 .map_err(|e| CrocoError::new(&code_pos, e))?;
         }
 
+        // the captured variables are pointers stored in the environment
+        if let Some(captures) = captures {
+            let env_param = function.get_last_param().unwrap();
+            let env_ptr = self
+                .builder
+                .build_bitcast(
+                    env_param,
+                    get_env_type(captures, self).ptr_type(AddressSpace::Generic),
+                    "envptr",
+                )
+                .into_pointer_value();
+
+            for (i, (name, symbol_type)) in captures.iter().enumerate() {
+                let capture_ptr_ptr = self
+                    .builder
+                    .build_struct_gep(env_ptr, i as u32, "gepcapture")
+                    .unwrap();
+                let capture_ptr = self.builder.build_load(capture_ptr_ptr, name);
+
+                let symbol = LSymbol {
+                    symbol_type: symbol_type.clone(),
+                    value: capture_ptr,
+                };
+                self.symtable
+                    .insert_symbol(name, symbol)
+                    .map_err(|e| CrocoError::new(&code_pos, e))?;
+            }
+        }
+
         // populate the function body
         let ret_val = match fn_body.crocol(self)? {
             LNodeResult::Return(ret) => ret,
@@ -169,7 +314,7 @@ impl<'ctx> LCodegen<'ctx> {
         // make sure the return value matches the function declaration
         let ret_ty_opt = ret_val.map(|x| x.symbol_type);
         match (&fn_decl.return_type, &ret_ty_opt) {
-            (None, None) => (),
+            (None, None) | (Some(SymbolType::Result(None)), None) => (),
             (Some(fn_ty), Some(ret_ty)) if fn_ty != ret_ty => {
                 return Err(CrocoError::wrong_return(
                     fn_decl.return_type.as_ref(),
@@ -190,6 +335,88 @@ impl<'ctx> LCodegen<'ctx> {
         Ok(())
     }
 
+    /// Returns the name of the instance of a generic function for the given type arguments,
+    /// and queues the instance to be built if it doesn't exist yet.
+    /// e.g first<num>
+    pub fn instantiate_generic_fn(
+        &mut self,
+        fn_name: &str,
+        fn_decl: FunctionDecl,
+        code_pos: &CodePos,
+    ) -> Result<String, CrocoError> {
+        let instance_name = get_instance_name(fn_name, &fn_decl);
+
+        if self.symtable.get_function_decl(&instance_name).is_err() {
+            // the builtin generic functions only have the instances they're built with
+            if !self.generic_fns.contains_key(fn_name) {
+                return Err(CrocoError::new(
+                    code_pos,
+                    format!(
+                        "function {} can't be called with arguments of type {}",
+                        fn_name,
+                        get_type_args(&fn_decl)
+                    ),
+                ));
+            }
+
+            self.symtable
+                .register_decl(instance_name.clone(), Decl::FunctionDecl(fn_decl))
+                .map_err(|e| CrocoError::new(code_pos, e))?;
+
+            self.pending_instances
+                .push((instance_name.clone(), fn_name.to_owned()));
+        }
+
+        Ok(instance_name)
+    }
+
+    /// Builds all the declared functions, the instances of the generic functions and all the closures.
+    /// This is done once the whole tree is built, so the global variables and the generic bodies are all known.
+    pub fn build_pending_functions(&mut self) -> Result<(), CrocoError> {
+        for (fn_name, fn_body, code_pos) in std::mem::take(&mut self.pending_fns) {
+            self.build_function(&fn_name, fn_body, &code_pos)?;
+        }
+
+        // building a function can queue other instances or closures
+        loop {
+            if let Some((instance_name, fn_name)) = self.pending_instances.pop() {
+                let (fn_body, code_pos) = self.generic_fns.get(&fn_name).unwrap().clone();
+
+                // bind the type parameters again, for the types used in the body
+                let generic_decl = self.symtable.get_function_decl(&fn_name).unwrap().clone();
+                let instance_decl = self.symtable.get_function_decl(&instance_name).unwrap();
+                let mut generics = HashMap::new();
+
+                for (generic_arg, instance_arg) in generic_decl.args.iter().zip(&instance_decl.args)
+                {
+                    generic_arg
+                        .arg_type
+                        .bind_generics(&instance_arg.arg_type, &mut generics);
+                }
+
+                self.generics = generics;
+                self.build_function(&instance_name, fn_body, &code_pos)?;
+                self.generics.clear();
+            } else if let Some(closure) = self.pending_closures.pop() {
+                self.build_function_with_env(
+                    &closure.fn_name,
+                    closure.fn_body,
+                    Some(&closure.captures),
+                    &closure.code_pos,
+                )?;
+            } else {
+                break;
+            }
+        }
+
+        // all the global variables are initialized
+        self.enter_init_fn();
+        self.builder.build_return(None);
+        self.leave_init_fn();
+
+        Ok(())
+    }
+
     /// Allocates a CrocoArray given specific elements.
     pub fn alloc_array(&self, elements: Vec<LSymbol>) -> PointerValue<'ctx> {
         let alloca = self.create_block_alloca(self.array_type.into(), "array");
@@ -291,6 +518,22 @@ impl<'ctx> LCodegen<'ctx> {
     }
 }
 
+/// Returns the name of the instance of a generic function for the types of its arguments
+pub fn get_instance_name(fn_name: &str, fn_decl: &FunctionDecl) -> String {
+    format!("{}<{}>", fn_name, get_type_args(fn_decl))
+}
+
+/// Returns the types of the arguments of a function, separated by commas
+fn get_type_args(fn_decl: &FunctionDecl) -> String {
+    let type_args: Vec<String> = fn_decl
+        .args
+        .iter()
+        .map(|arg| arg.arg_type.to_string())
+        .collect();
+
+    type_args.join(", ")
+}
+
 // we have to keep track of the symbol type.
 /// A symbol in the crocol backend
 #[derive(Clone, Debug)]
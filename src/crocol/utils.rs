@@ -2,15 +2,17 @@ use crate::{
     crocol::{symbol::LSymTable, LCodegen, LSymbol},
     parser::TypedArg,
     symbol::Decl,
-    symbol::{FunctionDecl, StructDecl},
+    symbol::{EnumDecl, FunctionDecl, StructDecl},
     symbol_type::SymbolType,
+    token::{CodePos, OperatorEnum},
     CrocoError,
 };
 
 use inkwell::{
-    types::{BasicType, BasicTypeEnum, StructType},
-    values::{FunctionValue, PointerValue},
-    AddressSpace,
+    module::Linkage,
+    types::{BasicType, BasicTypeEnum, FunctionType, StructType},
+    values::{BasicValueEnum, FunctionValue, GlobalValue, IntValue, PointerValue},
+    AddressSpace, FloatPredicate, IntPredicate,
 };
 use std::{path::Path, vec};
 
@@ -28,6 +30,18 @@ pub fn auto_deref<'ctx>(mut symbol: LSymbol<'ctx>, codegen: &LCodegen<'ctx>) ->
     symbol
 }
 
+/// Gets the zero value of an llvm type
+pub fn const_zero(ty: BasicTypeEnum<'_>) -> BasicValueEnum<'_> {
+    match ty {
+        BasicTypeEnum::ArrayType(ty) => ty.const_zero().into(),
+        BasicTypeEnum::FloatType(ty) => ty.const_zero().into(),
+        BasicTypeEnum::IntType(ty) => ty.const_zero().into(),
+        BasicTypeEnum::PointerType(ty) => ty.const_null().into(),
+        BasicTypeEnum::StructType(ty) => ty.const_zero().into(),
+        BasicTypeEnum::VectorType(ty) => ty.const_zero().into(),
+    }
+}
+
 /// Gets the llvm type corresponding to a SymbolType
 pub fn get_llvm_type<'ctx>(
     symbol_type: &SymbolType,
@@ -36,31 +50,101 @@ pub fn get_llvm_type<'ctx>(
     match symbol_type {
         SymbolType::Num => codegen.context.i32_type().into(),
         SymbolType::Fnum => codegen.context.f32_type().into(),
+        SymbolType::I64 | SymbolType::U64 => codegen.context.i64_type().into(),
+        SymbolType::U8 => codegen.context.i8_type().into(),
+        SymbolType::F64 => codegen.context.f64_type().into(),
         SymbolType::Str => codegen.str_type.into(),
         SymbolType::Bool => codegen.context.bool_type().into(),
-        SymbolType::Function(_fn_type) => {
-            todo!("transform this to a FunctionPointer");
-            // https://github.com/TheDan64/inkwell/commit/5a793eba3e0c3a903a0c35da7c61b12790d2c009
-            // let return_type: BasicTypeEnum = get_llvm_type(&*fn_type.return_type, codegen);
-
-            // let mut arg_types = Vec::with_capacity(fn_type.args);
-
-            // for arg in fn_type.args.clone() {
-            //     arg_types.push(get_llvm_type(&arg.arg_type, codegen).try_into().unwrap());
-            // }
-
-            // return_type.fn_type(&arg_types, false).into()
-        }
+        SymbolType::Function(_) => codegen.closure_type.into(),
         SymbolType::Array(_) => codegen.str_type.into(),
         SymbolType::Ref(ref_type) => get_llvm_type(ref_type, codegen)
             .ptr_type(AddressSpace::Generic)
             .into(),
-        SymbolType::Map(_, _) => todo!(),
+        SymbolType::Map(_, _) => codegen.map_type.into(),
         SymbolType::Struct(s) => {
             let struct_decl = codegen.symtable.get_struct_decl(s).unwrap();
             get_or_define_struct(s, struct_decl, codegen).into()
         }
-        SymbolType::CrocoType => unreachable!(),
+        SymbolType::Trait(_) => codegen.trait_type.into(),
+        SymbolType::Enum(e) => {
+            let enum_decl = codegen.symtable.get_enum_decl(e).unwrap();
+            get_or_define_enum(e, enum_decl, codegen).into()
+        }
+        SymbolType::Result(ok_type) => get_result_type(ok_type.as_deref(), codegen).into(),
+        SymbolType::Error => codegen.str_type.into(),
+        // an optional points to its heap-allocated value, and is null when there's none
+        SymbolType::Optional(some_type) => get_llvm_type(some_type, codegen)
+            .ptr_type(AddressSpace::Generic)
+            .into(),
+        SymbolType::None => codegen
+            .context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .into(),
+        SymbolType::Tuple(types) => {
+            let fields: Vec<BasicTypeEnum> =
+                types.iter().map(|t| get_llvm_type(t, codegen)).collect();
+            codegen.context.struct_type(&fields, false).into()
+        }
+        // type parameters are always resolved when the function is monomorphized
+        SymbolType::Generic(_) | SymbolType::CrocoType => unreachable!(),
+    }
+}
+
+/// Gets the llvm type of a result.
+/// It holds a flag set if there's an error, the error message and the value if there's one.
+pub fn get_result_type<'ctx>(
+    ok_type: Option<&SymbolType>,
+    codegen: &LCodegen<'ctx>,
+) -> StructType<'ctx> {
+    let mut fields = vec![codegen.context.bool_type().into(), codegen.str_type.into()];
+
+    if let Some(ok_type) = ok_type {
+        fields.push(get_llvm_type(ok_type, codegen));
+    }
+
+    codegen.context.struct_type(&fields, false)
+}
+
+/// Builds a result from either a value or an error message
+pub fn build_result_value<'ctx>(
+    ok_type: &Option<Box<SymbolType>>,
+    value: Option<BasicValueEnum<'ctx>>,
+    message: Option<BasicValueEnum<'ctx>>,
+    codegen: &LCodegen<'ctx>,
+) -> LSymbol<'ctx> {
+    let is_err = codegen
+        .context
+        .bool_type()
+        .const_int(message.is_some() as u64, false);
+
+    let mut result = codegen
+        .builder
+        .build_insert_value(
+            get_result_type(ok_type.as_deref(), codegen).get_undef(),
+            is_err,
+            0,
+            "insertiserr",
+        )
+        .unwrap();
+
+    if let Some(message) = message {
+        result = codegen
+            .builder
+            .build_insert_value(result, message, 1, "insertmessage")
+            .unwrap();
+    }
+
+    if let Some(value) = value {
+        result = codegen
+            .builder
+            .build_insert_value(result, value, 2, "insertvalue")
+            .unwrap();
+    }
+
+    LSymbol {
+        value: result.into_struct_value().into(),
+        symbol_type: SymbolType::Result(ok_type.clone()),
     }
 }
 
@@ -84,6 +168,27 @@ pub fn init_default<'ctx>(init_symbol: &LSymbol<'ctx>, codegen: &LCodegen<'ctx>)
                 .build_store(ptr, codegen.context.f32_type().const_zero());
         }
 
+        // stack allocation of an i64
+        SymbolType::I64 | SymbolType::U64 => {
+            codegen
+                .builder
+                .build_store(ptr, codegen.context.i64_type().const_zero());
+        }
+
+        // stack allocation of an i8
+        SymbolType::U8 => {
+            codegen
+                .builder
+                .build_store(ptr, codegen.context.i8_type().const_zero());
+        }
+
+        // stack allocation of a f64
+        SymbolType::F64 => {
+            codegen
+                .builder
+                .build_store(ptr, codegen.context.f64_type().const_zero());
+        }
+
         // stack allocation of a bool
         SymbolType::Bool => {
             codegen
@@ -121,6 +226,66 @@ pub fn init_default<'ctx>(init_symbol: &LSymbol<'ctx>, codegen: &LCodegen<'ctx>)
                 .build_store(max_len, codegen.ptr_size.const_int(0, false));
         }
 
+        // maps are heap-allocated as well, but the runtime also needs to know the entries sizes
+        SymbolType::Map(key_type, value_type) => {
+            // the keys, values and index are null pointers
+            for i in 0..3 {
+                let field_ptr = codegen
+                    .builder
+                    .build_struct_gep(ptr, i, "gepmapptr")
+                    .unwrap();
+                let null_ptr = field_ptr
+                    .get_type()
+                    .get_element_type()
+                    .into_pointer_type()
+                    .const_null();
+                codegen.builder.build_store(field_ptr, null_ptr);
+            }
+
+            // the len, max_len and index_len fields defaults to 0
+            for i in 3..6 {
+                let field_ptr = codegen
+                    .builder
+                    .build_struct_gep(ptr, i, "gepmaplen")
+                    .unwrap();
+                codegen
+                    .builder
+                    .build_store(field_ptr, codegen.ptr_size.const_int(0, false));
+            }
+
+            let key_size = get_llvm_type(key_type, codegen).size_of().unwrap();
+            let key_size = codegen
+                .builder
+                .build_int_cast(key_size, codegen.ptr_size, "keysize");
+            let key_size_ptr = codegen
+                .builder
+                .build_struct_gep(ptr, 6, "gepkeysize")
+                .unwrap();
+            codegen.builder.build_store(key_size_ptr, key_size);
+
+            let value_size = get_llvm_type(value_type, codegen).size_of().unwrap();
+            let value_size =
+                codegen
+                    .builder
+                    .build_int_cast(value_size, codegen.ptr_size, "valuesize");
+            let value_size_ptr = codegen
+                .builder
+                .build_struct_gep(ptr, 7, "gepvaluesize")
+                .unwrap();
+            codegen.builder.build_store(value_size_ptr, value_size);
+
+            // str keys are compared by contents
+            let str_keys = codegen
+                .context
+                .i8_type()
+                .const_int((**key_type == SymbolType::Str) as u64, false);
+            let str_keys_ptr = codegen
+                .builder
+                .build_struct_gep(ptr, 8, "gepstrkeys")
+                .unwrap();
+            codegen.builder.build_store(str_keys_ptr, str_keys);
+        }
+
         SymbolType::Struct(s) => {
             let struct_decl = codegen.symtable.get_struct_decl(&s).unwrap();
 
@@ -139,6 +304,56 @@ pub fn init_default<'ctx>(init_symbol: &LSymbol<'ctx>, codegen: &LCodegen<'ctx>)
             }
         }
 
+        // a default result holds the default value
+        SymbolType::Result(ok_type) => {
+            let is_err_ptr = codegen
+                .builder
+                .build_struct_gep(ptr, 0, "gepiserr")
+                .unwrap();
+            codegen
+                .builder
+                .build_store(is_err_ptr, codegen.context.bool_type().const_zero());
+
+            if let Some(ok_type) = ok_type {
+                let value_ptr = codegen
+                    .builder
+                    .build_struct_gep(ptr, 2, "gepvalue")
+                    .unwrap();
+
+                let value_symbol = LSymbol {
+                    value: value_ptr.into(),
+                    symbol_type: *ok_type.clone(),
+                };
+
+                init_default(&value_symbol, codegen);
+            }
+        }
+
+        // all the elements of a tuple are default initialized
+        SymbolType::Tuple(types) => {
+            for (i, el_type) in types.iter().enumerate() {
+                let el_ptr = codegen
+                    .builder
+                    .build_struct_gep(ptr, i as u32, "geptuple")
+                    .unwrap();
+
+                let el_symbol = LSymbol {
+                    value: el_ptr.into(),
+                    symbol_type: el_type.clone(),
+                };
+
+                init_default(&el_symbol, codegen);
+            }
+        }
+
+        // a default optional holds no value
+        SymbolType::Optional(some_type) => {
+            let null_ptr = get_llvm_type(some_type, codegen)
+                .ptr_type(AddressSpace::Generic)
+                .const_null();
+            codegen.builder.build_store(ptr, null_ptr);
+        }
+
         _ => unimplemented!(),
     };
 }
@@ -160,51 +375,204 @@ pub fn get_or_define_function<'ctx>(
 ) -> FunctionValue<'ctx> {
     codegen.module.get_function(fn_name).unwrap_or_else(|| {
         // create a new llvm function
+        let fn_ty = get_llvm_fn_type(fn_decl, codegen);
+        codegen.module.add_function(fn_name, fn_ty, None)
+    })
+}
+
+/// Gets the llvm function type corresponding to a croco function definition
+pub fn get_llvm_fn_type<'ctx>(
+    fn_decl: &FunctionDecl,
+    codegen: &LCodegen<'ctx>,
+) -> FunctionType<'ctx> {
+    // convert the arguments to llvm
+    // to comply with the "C ABI", fn(Struct a) is changed to fn(&Struct a)
+    let mut llvm_args = Vec::with_capacity(fn_decl.args.len());
+    for arg in fn_decl.args.iter() {
+        let llvm_arg = match arg.arg_type {
+            SymbolType::Str
+            | SymbolType::Array(_)
+            | SymbolType::Map(_, _)
+            | SymbolType::Struct(_)
+            | SymbolType::Trait(_)
+            | SymbolType::Enum(_)
+            | SymbolType::Result(_)
+            | SymbolType::Tuple(_) => get_llvm_type(&arg.arg_type, codegen)
+                .ptr_type(AddressSpace::Generic)
+                .into(),
+            SymbolType::Num
+            | SymbolType::Fnum
+            | SymbolType::I64
+            | SymbolType::U64
+            | SymbolType::U8
+            | SymbolType::F64
+            | SymbolType::Bool
+            | SymbolType::Ref(_)
+            | SymbolType::Optional(_)
+            | SymbolType::Function(_) => get_llvm_type(&arg.arg_type, codegen),
+            _ => unimplemented!(),
+        };
 
-        // convert the arguments to llvm
-        // to comply with the "C ABI", fn(Struct a) is changed to fn(&Struct a)
-        let mut llvm_args = Vec::with_capacity(fn_decl.args.len());
-        for arg in fn_decl.args.iter() {
-            let llvm_arg = match arg.arg_type {
-                SymbolType::Str | SymbolType::Struct(_) => get_llvm_type(&arg.arg_type, codegen)
+        llvm_args.push(llvm_arg);
+    }
+
+    // if the return type is a struct, pass as the first argument a pointer to this struct which
+    // will contain the result of the function.
+    match &fn_decl.return_type {
+        Some(SymbolType::Str)
+        | Some(SymbolType::Array(_))
+        | Some(SymbolType::Map(_, _))
+        | Some(SymbolType::Struct(_))
+        | Some(SymbolType::Trait(_))
+        | Some(SymbolType::Enum(_))
+        | Some(SymbolType::Result(_))
+        | Some(SymbolType::Tuple(_)) => {
+            llvm_args.insert(
+                0,
+                get_llvm_type(&fn_decl.return_type.as_ref().unwrap(), codegen)
                     .ptr_type(AddressSpace::Generic)
                     .into(),
-                SymbolType::Num | SymbolType::Fnum | SymbolType::Bool | SymbolType::Ref(_) => {
-                    get_llvm_type(&arg.arg_type, codegen)
-                }
-                _ => unimplemented!(),
-            };
+            );
+            codegen.context.void_type().fn_type(&llvm_args, false)
+        }
 
-            llvm_args.push(llvm_arg);
+        Some(SymbolType::Bool)
+        | Some(SymbolType::Fnum)
+        | Some(SymbolType::Num)
+        | Some(SymbolType::I64)
+        | Some(SymbolType::U64)
+        | Some(SymbolType::U8)
+        | Some(SymbolType::F64)
+        | Some(SymbolType::Optional(_))
+        | Some(SymbolType::Function(_)) => {
+            let ret_ty = get_llvm_type(&fn_decl.return_type.as_ref().unwrap(), codegen);
+            ret_ty.fn_type(&llvm_args, false)
         }
 
-        // if the return type is a struct, pass as the first argument a pointer to this struct which
-        // will contain the result of the function.
-        let fn_ty = match &fn_decl.return_type {
-            Some(SymbolType::Str) | Some(SymbolType::Struct(_)) => {
-                llvm_args.insert(
-                    0,
-                    get_llvm_type(&fn_decl.return_type.as_ref().unwrap(), codegen)
-                        .ptr_type(AddressSpace::Generic)
-                        .into(),
-                );
-                codegen.context.void_type().fn_type(&llvm_args, false)
-            }
+        None => codegen.context.void_type().fn_type(&llvm_args, false),
 
-            Some(SymbolType::Bool) | Some(SymbolType::Fnum) | Some(SymbolType::Num) => {
-                let ret_ty = get_llvm_type(&fn_decl.return_type.as_ref().unwrap(), codegen);
-                ret_ty.fn_type(&llvm_args, false)
-            }
+        _ => unimplemented!(),
+    }
+}
 
-            None => codegen.context.void_type().fn_type(&llvm_args, false),
+/// Gets the llvm function type of a function called through a function value.
+/// This is the type of the croco function, with a pointer to the environment as the last parameter.
+pub fn get_llvm_closure_fn_type<'ctx>(
+    fn_decl: &FunctionDecl,
+    codegen: &LCodegen<'ctx>,
+) -> FunctionType<'ctx> {
+    let fn_ty = get_llvm_fn_type(fn_decl, codegen);
 
-            _ => unimplemented!(),
-        };
+    let mut llvm_args = fn_ty.get_param_types();
+    llvm_args.push(
+        codegen
+            .context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .into(),
+    );
 
+    match fn_ty.get_return_type() {
+        Some(ret_ty) => ret_ty.fn_type(&llvm_args, false),
+        None => codegen.context.void_type().fn_type(&llvm_args, false),
+    }
+}
+
+/// Returns the inkwell closure function if it exists, or create one according to the croco function definition
+pub fn get_or_define_closure_fn<'ctx>(
+    fn_name: &str,
+    fn_decl: &FunctionDecl,
+    codegen: &LCodegen<'ctx>,
+) -> FunctionValue<'ctx> {
+    codegen.module.get_function(fn_name).unwrap_or_else(|| {
+        let fn_ty = get_llvm_closure_fn_type(fn_decl, codegen);
         codegen.module.add_function(fn_name, fn_ty, None)
     })
 }
 
+/// Gets the type of the environment of a closure, a struct of pointers to the captured variables
+pub fn get_env_type<'ctx>(
+    captures: &[(String, SymbolType)],
+    codegen: &LCodegen<'ctx>,
+) -> StructType<'ctx> {
+    let fields: Vec<BasicTypeEnum> = captures
+        .iter()
+        .map(|(_, symbol_type)| {
+            get_llvm_type(symbol_type, codegen)
+                .ptr_type(AddressSpace::Generic)
+                .into()
+        })
+        .collect();
+
+    codegen.context.struct_type(&fields, false)
+}
+
+/// Returns the function used to call a named function through a function value, or create it.
+/// It takes an environment like a closure, and forwards all the other parameters.
+pub fn get_or_define_fn_thunk<'ctx>(
+    fn_name: &str,
+    fn_decl: &FunctionDecl,
+    codegen: &LCodegen<'ctx>,
+) -> FunctionValue<'ctx> {
+    let thunk_name = format!("_fnptr_{}", fn_name);
+
+    if let Some(thunk) = codegen.module.get_function(&thunk_name) {
+        return thunk;
+    }
+
+    let function = get_or_define_function(fn_name, fn_decl, codegen);
+    let thunk = get_or_define_closure_fn(&thunk_name, fn_decl, codegen);
+
+    // the thunk is built aside, so we have to get back where we were afterwards
+    let current_block = codegen.builder.get_insert_block();
+    let entry = codegen.context.append_basic_block(thunk, "entry");
+    codegen.builder.position_at_end(entry);
+
+    // the environment is always the last parameter
+    let params: Vec<BasicValueEnum> = thunk
+        .get_param_iter()
+        .take(thunk.count_params() as usize - 1)
+        .collect();
+
+    let res = codegen.builder.build_call(function, &params, "callfn");
+    match res.try_as_basic_value().left() {
+        Some(value) => codegen.builder.build_return(Some(&value)),
+        None => codegen.builder.build_return(None),
+    };
+
+    if let Some(block) = current_block {
+        codegen.builder.position_at_end(block);
+    }
+
+    thunk
+}
+
+/// Builds a function value from a function pointer and a pointer to its environment
+pub fn build_closure_value<'ctx>(
+    fn_ptr: PointerValue<'ctx>,
+    env_ptr: PointerValue<'ctx>,
+    codegen: &LCodegen<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    let void_ptr_type = codegen.context.i8_type().ptr_type(AddressSpace::Generic);
+    let void_fn_ptr = codegen
+        .builder
+        .build_bitcast(fn_ptr, void_ptr_type, "closurefn");
+    let void_env_ptr = codegen
+        .builder
+        .build_bitcast(env_ptr, void_ptr_type, "closureenv");
+
+    let closure_value = codegen
+        .builder
+        .build_insert_value(codegen.closure_type.get_undef(), void_fn_ptr, 0, "insertfn")
+        .unwrap();
+    let closure_value = codegen
+        .builder
+        .build_insert_value(closure_value, void_env_ptr, 1, "insertenv")
+        .unwrap();
+
+    closure_value.into_struct_value().into()
+}
+
 /// Returns the inkwell struct if it exists, or create one according to the struct declaration
 pub fn get_or_define_struct<'ctx>(
     struct_name: &str,
@@ -228,6 +596,719 @@ pub fn get_or_define_struct<'ctx>(
             opaque
         })
 }
+
+/// Returns the inkwell struct of an enum if it exists, or create one according to the enum declaration.
+/// An enum is a tag, followed by enough room to hold the values of its largest variant.
+pub fn get_or_define_enum<'ctx>(
+    enum_name: &str,
+    enum_decl: &EnumDecl,
+    codegen: &LCodegen<'ctx>,
+) -> StructType<'ctx> {
+    codegen
+        .module
+        .get_struct_type(enum_name)
+        .unwrap_or_else(|| {
+            let payload_size = enum_decl
+                .variants
+                .iter()
+                .map(|(variant_name, payload)| {
+                    let variant_struct =
+                        get_or_define_variant_struct(enum_name, variant_name, payload, codegen);
+                    codegen.target_data.get_abi_size(&variant_struct)
+                })
+                .max()
+                .unwrap_or(0);
+
+            // the values are stored in 64 bits words, so they are aligned for every type
+            let words = (payload_size + 7) / 8;
+
+            let opaque = codegen.context.opaque_struct_type(enum_name);
+            opaque.set_body(
+                &[
+                    codegen.context.i32_type().into(),
+                    codegen.context.i64_type().array_type(words as u32).into(),
+                ],
+                false,
+            );
+
+            opaque
+        })
+}
+
+/// Returns the inkwell struct holding the values of an enum variant
+pub fn get_or_define_variant_struct<'ctx>(
+    enum_name: &str,
+    variant_name: &str,
+    payload: &[SymbolType],
+    codegen: &LCodegen<'ctx>,
+) -> StructType<'ctx> {
+    // the fields are named after their padded position, so the BTreeMap keeps them in order
+    let variant_decl = StructDecl {
+        fields: payload
+            .iter()
+            .enumerate()
+            .map(|(i, value_type)| (format!("{:03}", i), value_type.clone()))
+            .collect(),
+    };
+
+    get_or_define_struct(
+        &format!("{}.{}", enum_name, variant_name),
+        &variant_decl,
+        codegen,
+    )
+}
+
+/// Returns the vtable of a struct implementing a trait if it exists, or create one.
+/// The methods are ordered as in the trait declaration.
+pub fn get_or_define_vtable<'ctx>(
+    struct_name: &str,
+    trait_name: &str,
+    codegen: &mut LCodegen<'ctx>,
+) -> GlobalValue<'ctx> {
+    let vtable_name = format!("_vtable_{}_{}", struct_name, trait_name);
+
+    if let Some(vtable) = codegen.module.get_global(&vtable_name) {
+        return vtable;
+    }
+
+    let void_ptr_type = codegen.context.i8_type().ptr_type(AddressSpace::Generic);
+    let trait_decl = codegen.symtable.get_trait_decl(trait_name).unwrap().clone();
+
+    let mut methods = Vec::with_capacity(trait_decl.methods.len());
+    for method_name in trait_decl.methods.keys() {
+        let fn_name = format!("_{}_{}", struct_name, method_name);
+        let fn_decl = codegen
+            .symtable
+            .get_function_decl(&fn_name)
+            .unwrap()
+            .clone();
+        let function = get_or_define_function(&fn_name, &fn_decl, codegen);
+
+        methods.push(
+            function
+                .as_global_value()
+                .as_pointer_value()
+                .const_cast(void_ptr_type),
+        );
+    }
+
+    let vtable_type = void_ptr_type.array_type(methods.len() as u32);
+    let vtable = codegen.module.add_global(vtable_type, None, &vtable_name);
+    vtable.set_linkage(Linkage::Internal);
+    vtable.set_constant(true);
+    vtable.set_initializer(&void_ptr_type.const_array(&methods));
+
+    vtable
+}
+
+/// Converts a symbol into the expected type when it can be done implicitly  
+/// e.g let a: Speak = Dog {}, or returning a str from a !str function.
+/// The elements of a tuple are converted one by one.
+pub fn coerce_to_type<'ctx>(
+    symbol: LSymbol<'ctx>,
+    expected_type: &SymbolType,
+    codegen: &mut LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<LSymbol<'ctx>, CrocoError> {
+    match (&symbol.symbol_type, expected_type) {
+        (SymbolType::Struct(struct_name), SymbolType::Trait(trait_name)) => {
+            let (struct_name, trait_name) = (struct_name.clone(), trait_name.clone());
+            build_trait_object(symbol, &struct_name, &trait_name, codegen, code_pos)
+        }
+
+        (SymbolType::Error, SymbolType::Result(ok_type)) => Ok(build_result_value(
+            ok_type,
+            None,
+            Some(symbol.value),
+            codegen,
+        )),
+
+        (SymbolType::None, SymbolType::Optional(some_type)) => Ok(LSymbol {
+            value: get_llvm_type(some_type, codegen)
+                .ptr_type(AddressSpace::Generic)
+                .const_null()
+                .into(),
+            symbol_type: expected_type.clone(),
+        }),
+
+        (symbol_type, SymbolType::Optional(some_type))
+            if !matches!(symbol_type, SymbolType::Optional(_)) =>
+        {
+            let some_type = some_type.clone();
+            let value = coerce_to_type(symbol, &some_type, codegen, code_pos)?;
+
+            // let the caller report the type mismatch
+            if value.symbol_type != *some_type {
+                return Ok(value);
+            }
+
+            Ok(build_optional_value(value, codegen))
+        }
+
+        (SymbolType::Tuple(types), SymbolType::Tuple(expected_types))
+            if types.len() == expected_types.len() && symbol.symbol_type != *expected_type =>
+        {
+            let types = types.clone();
+            let tuple_value = symbol.value.into_struct_value();
+            let mut coerced = get_llvm_type(expected_type, codegen)
+                .into_struct_type()
+                .get_undef();
+
+            for (i, (el_type, expected_el_type)) in
+                types.iter().zip(expected_types.iter()).enumerate()
+            {
+                let el = LSymbol {
+                    value: codegen
+                        .builder
+                        .build_extract_value(tuple_value, i as u32, "extracttuple")
+                        .unwrap(),
+                    symbol_type: el_type.clone(),
+                };
+                let el = coerce_to_type(el, expected_el_type, codegen, code_pos)?;
+
+                // let the caller report the type mismatch
+                if el.symbol_type != *expected_el_type {
+                    return Ok(symbol);
+                }
+
+                coerced = codegen
+                    .builder
+                    .build_insert_value(coerced, el.value, i as u32, "inserttuple")
+                    .unwrap()
+                    .into_struct_value();
+            }
+
+            Ok(LSymbol {
+                value: coerced.into(),
+                symbol_type: expected_type.clone(),
+            })
+        }
+
+        (_, SymbolType::Result(Some(ok_type))) => {
+            let value = coerce_to_type(symbol, ok_type, codegen, code_pos)?;
+
+            // let the caller report the type mismatch
+            if value.symbol_type != **ok_type {
+                return Ok(value);
+            }
+
+            Ok(build_result_value(
+                &Some(ok_type.clone()),
+                Some(value.value),
+                None,
+                codegen,
+            ))
+        }
+
+        // a number is widened to a bigger number type, e.g let a i64 = 3
+        (symbol_type, _) if symbol_type.widen(expected_type).as_ref() == Some(expected_type) => {
+            Ok(build_num_cast(symbol, expected_type, codegen))
+        }
+
+        _ => Ok(symbol),
+    }
+}
+
+/// Converts a number into another number type.
+/// Like an `as` in Rust, integers wrap around and floats are truncated.
+pub fn build_num_cast<'ctx>(
+    symbol: LSymbol<'ctx>,
+    num_type: &SymbolType,
+    codegen: &LCodegen<'ctx>,
+) -> LSymbol<'ctx> {
+    let llvm_type = get_llvm_type(num_type, codegen);
+
+    let value = match (symbol.symbol_type.is_float(), num_type.is_float()) {
+        // int to int
+        (false, false) => {
+            let int_value = symbol.value.into_int_value();
+            let int_type = llvm_type.into_int_type();
+            let from_width = int_value.get_type().get_bit_width();
+            let to_width = int_type.get_bit_width();
+
+            if from_width > to_width {
+                codegen
+                    .builder
+                    .build_int_truncate(int_value, int_type, "truncint")
+                    .into()
+            } else if from_width == to_width {
+                int_value.into()
+            } else if symbol.symbol_type.is_unsigned() {
+                codegen
+                    .builder
+                    .build_int_z_extend(int_value, int_type, "zextint")
+                    .into()
+            } else {
+                codegen
+                    .builder
+                    .build_int_s_extend(int_value, int_type, "sextint")
+                    .into()
+            }
+        }
+
+        // int to float
+        (false, true) => {
+            let int_value = symbol.value.into_int_value();
+            let float_type = llvm_type.into_float_type();
+
+            if symbol.symbol_type.is_unsigned() {
+                codegen
+                    .builder
+                    .build_unsigned_int_to_float(int_value, float_type, "castintfloat")
+                    .into()
+            } else {
+                codegen
+                    .builder
+                    .build_signed_int_to_float(int_value, float_type, "castintfloat")
+                    .into()
+            }
+        }
+
+        // float to int
+        (true, false) => {
+            let float_value = symbol.value.into_float_value();
+            let int_type = llvm_type.into_int_type();
+
+            if num_type.is_unsigned() {
+                codegen
+                    .builder
+                    .build_float_to_unsigned_int(float_value, int_type, "castfloatint")
+                    .into()
+            } else {
+                codegen
+                    .builder
+                    .build_float_to_signed_int(float_value, int_type, "castfloatint")
+                    .into()
+            }
+        }
+
+        // float to float
+        (true, true) => {
+            let float_value = symbol.value.into_float_value();
+            let float_type = llvm_type.into_float_type();
+
+            match (&symbol.symbol_type, num_type) {
+                (SymbolType::Fnum, SymbolType::F64) => codegen
+                    .builder
+                    .build_float_ext(float_value, float_type, "fpext")
+                    .into(),
+                (SymbolType::F64, SymbolType::Fnum) => codegen
+                    .builder
+                    .build_float_trunc(float_value, float_type, "fptrunc")
+                    .into(),
+                _ => float_value.into(),
+            }
+        }
+    };
+
+    LSymbol {
+        value,
+        symbol_type: num_type.clone(),
+    }
+}
+
+/// Widens two numbers to the same type so that they can be used in the same operation,
+/// or returns None if they can't be used together
+pub fn build_widened_values<'ctx>(
+    left: LSymbol<'ctx>,
+    right: LSymbol<'ctx>,
+    codegen: &LCodegen<'ctx>,
+) -> Option<(LSymbol<'ctx>, LSymbol<'ctx>)> {
+    let num_type = left.symbol_type.widen(&right.symbol_type)?;
+
+    Some((
+        build_num_cast(left, &num_type, codegen),
+        build_num_cast(right, &num_type, codegen),
+    ))
+}
+
+/// Compares two values of the same type.
+/// Arrays and structs are equal if all their elements are.
+pub fn build_compare<'ctx>(
+    left_value: LSymbol<'ctx>,
+    right_value: LSymbol<'ctx>,
+    compare_kind: &OperatorEnum,
+    codegen: &LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<IntValue<'ctx>, CrocoError> {
+    let bool_res = match left_value.symbol_type {
+        SymbolType::Fnum | SymbolType::F64 => {
+            let op = match compare_kind {
+                OperatorEnum::Equals => FloatPredicate::OEQ,
+                // nan is different from everything, itself included
+                OperatorEnum::NotEquals => FloatPredicate::UNE,
+                OperatorEnum::GreaterThan => FloatPredicate::OGT,
+                OperatorEnum::GreaterOrEqual => FloatPredicate::OGE,
+                OperatorEnum::LowerThan => FloatPredicate::OLT,
+                OperatorEnum::LowerOrEqual => FloatPredicate::OLE,
+                _ => unreachable!(),
+            };
+
+            codegen.builder.build_float_compare(
+                op,
+                left_value.value.into_float_value(),
+                right_value.value.into_float_value(),
+                "cmpfnum",
+            )
+        }
+
+        SymbolType::Num | SymbolType::I64 => {
+            let op = match compare_kind {
+                OperatorEnum::Equals => IntPredicate::EQ,
+                OperatorEnum::NotEquals => IntPredicate::NE,
+                OperatorEnum::GreaterThan => IntPredicate::SGT,
+                OperatorEnum::GreaterOrEqual => IntPredicate::SGE,
+                OperatorEnum::LowerThan => IntPredicate::SLT,
+                OperatorEnum::LowerOrEqual => IntPredicate::SLE,
+                _ => unreachable!(),
+            };
+
+            codegen.builder.build_int_compare(
+                op,
+                left_value.value.into_int_value(),
+                right_value.value.into_int_value(),
+                "cmpnum",
+            )
+        }
+
+        SymbolType::U64 | SymbolType::U8 => {
+            let op = match compare_kind {
+                OperatorEnum::Equals => IntPredicate::EQ,
+                OperatorEnum::NotEquals => IntPredicate::NE,
+                OperatorEnum::GreaterThan => IntPredicate::UGT,
+                OperatorEnum::GreaterOrEqual => IntPredicate::UGE,
+                OperatorEnum::LowerThan => IntPredicate::ULT,
+                OperatorEnum::LowerOrEqual => IntPredicate::ULE,
+                _ => unreachable!(),
+            };
+
+            codegen.builder.build_int_compare(
+                op,
+                left_value.value.into_int_value(),
+                right_value.value.into_int_value(),
+                "cmpunsigned",
+            )
+        }
+
+        SymbolType::Bool => {
+            let op = match compare_kind {
+                OperatorEnum::Equals => IntPredicate::EQ,
+                OperatorEnum::NotEquals => IntPredicate::NE,
+                _ => return Err(CrocoError::compare_numbers_only_error(code_pos)),
+            };
+
+            codegen.builder.build_int_compare(
+                op,
+                left_value.value.into_int_value(),
+                right_value.value.into_int_value(),
+                "cmpbool",
+            )
+        }
+
+        SymbolType::Str => {
+            let cmp_fn = codegen.module.get_function("_croco_str_cmp").unwrap();
+
+            let left_ptr = codegen.create_block_alloca(codegen.str_type.into(), "tmpstr");
+            let right_ptr = codegen.create_block_alloca(codegen.str_type.into(), "tmpstr");
+
+            codegen.builder.build_store(left_ptr, left_value.value);
+            codegen.builder.build_store(right_ptr, right_value.value);
+
+            let cmp_res = codegen
+                .builder
+                .build_call(cmp_fn, &[left_ptr.into(), right_ptr.into()], "cmpstr")
+                .try_as_basic_value()
+                .left()
+                .unwrap();
+
+            let op = match compare_kind {
+                OperatorEnum::Equals => IntPredicate::EQ,
+                OperatorEnum::NotEquals => IntPredicate::NE,
+                OperatorEnum::GreaterThan => IntPredicate::SGT,
+                OperatorEnum::GreaterOrEqual => IntPredicate::SGE,
+                OperatorEnum::LowerThan => IntPredicate::SLT,
+                OperatorEnum::LowerOrEqual => IntPredicate::SLE,
+                _ => unreachable!(),
+            };
+
+            codegen.builder.build_int_compare(
+                op,
+                cmp_res.into_int_value(),
+                codegen.context.i8_type().const_zero(),
+                "cmpstr",
+            )
+        }
+
+        SymbolType::Array(_) | SymbolType::Struct(_) => {
+            let is_equal = match left_value.symbol_type {
+                SymbolType::Array(el_type) => build_array_eq(
+                    left_value.value,
+                    right_value.value,
+                    &el_type,
+                    codegen,
+                    code_pos,
+                )?,
+
+                SymbolType::Struct(struct_name) => build_struct_eq(
+                    left_value.value,
+                    right_value.value,
+                    &struct_name,
+                    codegen,
+                    code_pos,
+                )?,
+
+                _ => unreachable!(),
+            };
+
+            match compare_kind {
+                OperatorEnum::Equals => is_equal,
+                OperatorEnum::NotEquals => codegen.builder.build_not(is_equal, "cmpnot"),
+                _ => return Err(CrocoError::compare_numbers_only_error(code_pos)),
+            }
+        }
+
+        symbol_type => {
+            return Err(CrocoError::compare_unsupported_type_error(
+                code_pos,
+                &symbol_type,
+            ))
+        }
+    };
+
+    Ok(bool_res)
+}
+
+/// Checks if two arrays have the same length and equal elements
+fn build_array_eq<'ctx>(
+    left_value: BasicValueEnum<'ctx>,
+    right_value: BasicValueEnum<'ctx>,
+    el_type: &SymbolType,
+    codegen: &LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<IntValue<'ctx>, CrocoError> {
+    let current_fn = codegen.current_fn.unwrap();
+    let el_ptr_type = get_llvm_type(el_type, codegen).ptr_type(AddressSpace::Generic);
+
+    let mut heap_ptrs = Vec::with_capacity(2);
+    let mut lens = Vec::with_capacity(2);
+
+    for array_value in [left_value, right_value].iter() {
+        let array_value = array_value.into_struct_value();
+
+        let heap_ptr = codegen
+            .builder
+            .build_extract_value(array_value, 0, "heapvoidptr")
+            .unwrap();
+
+        heap_ptrs.push(
+            codegen
+                .builder
+                .build_bitcast(heap_ptr, el_ptr_type, "heapelptr")
+                .into_pointer_value(),
+        );
+
+        lens.push(
+            codegen
+                .builder
+                .build_extract_value(array_value, 1, "arraylen")
+                .unwrap()
+                .into_int_value(),
+        );
+    }
+
+    // arrays of different lengths are never equal, so the loop doesn't even start
+    let res_ptr = codegen.create_block_alloca(codegen.context.bool_type().into(), "arreqres");
+    let index_ptr = codegen.create_block_alloca(codegen.ptr_size.into(), "arreqindex");
+
+    let same_len = codegen
+        .builder
+        .build_int_compare(IntPredicate::EQ, lens[0], lens[1], "cmplen");
+    codegen.builder.build_store(res_ptr, same_len);
+    codegen
+        .builder
+        .build_store(index_ptr, codegen.ptr_size.const_zero());
+
+    let loop_block = codegen.context.append_basic_block(current_fn, "arreqloop");
+    let body_block = codegen.context.append_basic_block(current_fn, "arreqbody");
+    let end_block = codegen.context.append_basic_block(current_fn, "arreqend");
+
+    codegen.builder.build_unconditional_branch(loop_block);
+
+    // stop at the end of the arrays or at the first different element
+    codegen.builder.position_at_end(loop_block);
+    let index = codegen
+        .builder
+        .build_load(index_ptr, "index")
+        .into_int_value();
+    let res = codegen.builder.build_load(res_ptr, "res").into_int_value();
+    let in_bounds =
+        codegen
+            .builder
+            .build_int_compare(IntPredicate::ULT, index, lens[0], "inbounds");
+    let keep_looping = codegen.builder.build_and(res, in_bounds, "keeplooping");
+    codegen
+        .builder
+        .build_conditional_branch(keep_looping, body_block, end_block);
+
+    codegen.builder.position_at_end(body_block);
+
+    // SAFETY: the index is inferior to the length of both arrays
+    let (left_el_ptr, right_el_ptr) = unsafe {
+        (
+            codegen
+                .builder
+                .build_gep(heap_ptrs[0], &[index], "leftelptr"),
+            codegen
+                .builder
+                .build_gep(heap_ptrs[1], &[index], "rightelptr"),
+        )
+    };
+
+    let left_el = LSymbol {
+        value: codegen.builder.build_load(left_el_ptr, "leftel"),
+        symbol_type: el_type.clone(),
+    };
+    let right_el = LSymbol {
+        value: codegen.builder.build_load(right_el_ptr, "rightel"),
+        symbol_type: el_type.clone(),
+    };
+
+    let el_eq = build_compare(left_el, right_el, &OperatorEnum::Equals, codegen, code_pos)?;
+    codegen.builder.build_store(res_ptr, el_eq);
+
+    let next_index =
+        codegen
+            .builder
+            .build_int_add(index, codegen.ptr_size.const_int(1, false), "nextindex");
+    codegen.builder.build_store(index_ptr, next_index);
+    codegen.builder.build_unconditional_branch(loop_block);
+
+    codegen.builder.position_at_end(end_block);
+    Ok(codegen
+        .builder
+        .build_load(res_ptr, "arreq")
+        .into_int_value())
+}
+
+/// Checks if all the fields of two structs are equal
+fn build_struct_eq<'ctx>(
+    left_value: BasicValueEnum<'ctx>,
+    right_value: BasicValueEnum<'ctx>,
+    struct_name: &str,
+    codegen: &LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<IntValue<'ctx>, CrocoError> {
+    // the fields are laid out in the order of the declaration
+    let field_types: Vec<SymbolType> = codegen
+        .symtable
+        .get_struct_decl(struct_name)
+        .map_err(|e| CrocoError::new(code_pos, e))?
+        .fields
+        .values()
+        .cloned()
+        .collect();
+
+    let mut res = codegen.context.bool_type().const_int(1, false);
+
+    for (i, field_type) in field_types.into_iter().enumerate() {
+        let left_field = codegen
+            .builder
+            .build_extract_value(left_value.into_struct_value(), i as u32, "leftfield")
+            .unwrap();
+        let right_field = codegen
+            .builder
+            .build_extract_value(right_value.into_struct_value(), i as u32, "rightfield")
+            .unwrap();
+
+        let field_eq = build_compare(
+            LSymbol {
+                value: left_field,
+                symbol_type: field_type.clone(),
+            },
+            LSymbol {
+                value: right_field,
+                symbol_type: field_type,
+            },
+            &OperatorEnum::Equals,
+            codegen,
+            code_pos,
+        )?;
+
+        res = codegen.builder.build_and(res, field_eq, "fieldseq");
+    }
+
+    Ok(res)
+}
+
+/// Wraps a value into an optional, by moving it to the heap
+pub fn build_optional_value<'ctx>(
+    symbol: LSymbol<'ctx>,
+    codegen: &LCodegen<'ctx>,
+) -> LSymbol<'ctx> {
+    let value_ptr = codegen
+        .builder
+        .build_malloc(symbol.value.get_type(), "mallocoptional")
+        .unwrap();
+    codegen.builder.build_store(value_ptr, symbol.value);
+
+    LSymbol {
+        value: value_ptr.into(),
+        symbol_type: SymbolType::Optional(Box::new(symbol.symbol_type)),
+    }
+}
+
+/// Wraps a struct into a trait object
+fn build_trait_object<'ctx>(
+    symbol: LSymbol<'ctx>,
+    struct_name: &str,
+    trait_name: &str,
+    codegen: &mut LCodegen<'ctx>,
+    code_pos: &CodePos,
+) -> Result<LSymbol<'ctx>, CrocoError> {
+    codegen
+        .symtable
+        .check_trait_impl(struct_name, trait_name)
+        .map_err(|e| CrocoError::new(code_pos, e))?;
+
+    // the trait object can outlive the struct, so copy it on the heap
+    let data_ptr = codegen
+        .builder
+        .build_malloc(symbol.value.get_type(), "malloctrait")
+        .unwrap();
+    codegen.builder.build_store(data_ptr, symbol.value);
+
+    let void_ptr_type = codegen.context.i8_type().ptr_type(AddressSpace::Generic);
+    let void_data_ptr = codegen
+        .builder
+        .build_bitcast(data_ptr, void_ptr_type, "traitdata");
+
+    let vtable = get_or_define_vtable(struct_name, trait_name, codegen);
+    let vtable_ptr = codegen.builder.build_bitcast(
+        vtable.as_pointer_value(),
+        void_ptr_type.ptr_type(AddressSpace::Generic),
+        "traitvtable",
+    );
+
+    let trait_value = codegen
+        .builder
+        .build_insert_value(
+            codegen.trait_type.get_undef(),
+            void_data_ptr,
+            0,
+            "insertdata",
+        )
+        .unwrap();
+    let trait_value = codegen
+        .builder
+        .build_insert_value(trait_value, vtable_ptr, 1, "insertvtable")
+        .unwrap();
+
+    Ok(LSymbol {
+        value: trait_value.into_struct_value().into(),
+        symbol_type: SymbolType::Trait(trait_name.to_owned()),
+    })
+}
+
 /// Inserts all the function definitions from the crocol std
 pub fn insert_builtin_functions(symtable: &mut LSymTable<'_>) {
     let assert_decl = FunctionDecl {
@@ -267,7 +1348,98 @@ pub fn insert_builtin_functions(symtable: &mut LSymTable<'_>) {
 
     symtable
         .register_decl("println".to_owned(), Decl::FunctionDecl(print_decl))
-        .unwrap()
+        .unwrap();
+
+    // the methods of the primitives, which take self as their first argument.
+    // the array methods depend on the element type, so they're built in function_call_node.rs
+    let methods = vec![
+        (
+            "_fnum_times",
+            vec![SymbolType::Fnum, SymbolType::Fnum],
+            SymbolType::Array(Box::new(SymbolType::Fnum)),
+        ),
+        (
+            "_num_times",
+            vec![SymbolType::Num, SymbolType::Fnum],
+            SymbolType::Array(Box::new(SymbolType::Num)),
+        ),
+        (
+            "_str_chars",
+            vec![SymbolType::Str],
+            SymbolType::Array(Box::new(SymbolType::Str)),
+        ),
+        (
+            "_str_contains",
+            vec![SymbolType::Str, SymbolType::Str],
+            SymbolType::Bool,
+        ),
+        (
+            "_str_ends_with",
+            vec![SymbolType::Str, SymbolType::Str],
+            SymbolType::Bool,
+        ),
+        (
+            "_str_find",
+            vec![SymbolType::Str, SymbolType::Str],
+            SymbolType::Num,
+        ),
+        ("_str_len", vec![SymbolType::Str], SymbolType::Fnum),
+        (
+            "_str_parse_fnum",
+            vec![SymbolType::Str],
+            SymbolType::Result(Some(Box::new(SymbolType::Fnum))),
+        ),
+        (
+            "_str_parse_num",
+            vec![SymbolType::Str],
+            SymbolType::Result(Some(Box::new(SymbolType::Num))),
+        ),
+        (
+            "_str_repeat",
+            vec![SymbolType::Str, SymbolType::Num],
+            SymbolType::Str,
+        ),
+        (
+            "_str_replace",
+            vec![SymbolType::Str, SymbolType::Str, SymbolType::Str],
+            SymbolType::Str,
+        ),
+        (
+            "_str_slice",
+            vec![SymbolType::Str, SymbolType::Num, SymbolType::Num],
+            SymbolType::Str,
+        ),
+        (
+            "_str_split",
+            vec![SymbolType::Str, SymbolType::Str],
+            SymbolType::Array(Box::new(SymbolType::Str)),
+        ),
+        (
+            "_str_starts_with",
+            vec![SymbolType::Str, SymbolType::Str],
+            SymbolType::Bool,
+        ),
+        ("_str_to_lower", vec![SymbolType::Str], SymbolType::Str),
+        ("_str_to_upper", vec![SymbolType::Str], SymbolType::Str),
+        ("_str_trim", vec![SymbolType::Str], SymbolType::Str),
+    ];
+
+    for (method_name, args, return_type) in methods {
+        let method_decl = FunctionDecl {
+            args: args
+                .into_iter()
+                .map(|arg_type| TypedArg {
+                    arg_name: String::new(),
+                    arg_type,
+                })
+                .collect(),
+            return_type: Some(return_type),
+        };
+
+        symtable
+            .register_decl(method_name.to_owned(), Decl::FunctionDecl(method_decl))
+            .unwrap();
+    }
 }
 
 /// Builds a pointer to a const, null-terminater char array
@@ -289,6 +1461,23 @@ pub fn build_cstr_ptr<'ctx>(
         .into_pointer_value()
 }
 
+/// Stores a map key on the stack, and returns a void pointer to it as expected by the map runtime
+pub fn build_map_key_ptr<'ctx>(
+    key: &LSymbol<'ctx>,
+    codegen: &LCodegen<'ctx>,
+) -> PointerValue<'ctx> {
+    let key_ptr = codegen.create_block_alloca(key.value.get_type(), "mapkey");
+    codegen.builder.build_store(key_ptr, key.value);
+    codegen
+        .builder
+        .build_bitcast(
+            key_ptr,
+            codegen.context.i8_type().ptr_type(AddressSpace::Generic),
+            "voidkeyptr",
+        )
+        .into_pointer_value()
+}
+
 /// Throws at runtime a CrocoError
 pub fn throw_runtime_error(error: CrocoError, codegen: &LCodegen) {
     let msg = build_cstr_ptr(&error.message, "error", codegen);
@@ -314,3 +1503,35 @@ pub fn throw_runtime_error(error: CrocoError, codegen: &LCodegen) {
     );
     codegen.builder.build_unreachable();
 }
+
+/// Throws at runtime a CrocoError whose message is a croco str only known at runtime
+pub fn throw_runtime_str_error<'ctx>(
+    message: BasicValueEnum<'ctx>,
+    error: CrocoError,
+    codegen: &LCodegen<'ctx>,
+) {
+    let msg = codegen.create_block_alloca(codegen.str_type.into(), "error");
+    codegen.builder.build_store(msg, message);
+
+    let hint_ptr = if let Some(hint) = error.hint {
+        build_cstr_ptr(&hint, "hint", codegen)
+    } else {
+        codegen
+            .context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .const_null()
+    };
+
+    let pos = error.pos.unwrap();
+    let file = build_cstr_ptr(&pos.file, "file", codegen);
+    let line = codegen.context.i32_type().const_int(pos.line as u64, false);
+
+    let error_fn = codegen.module.get_function("_croco_str_error").unwrap();
+    codegen.builder.build_call(
+        error_fn,
+        &[file.into(), line.into(), msg.into(), hint_ptr.into()],
+        "crocoerror",
+    );
+    codegen.builder.build_unreachable();
+}
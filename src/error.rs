@@ -17,6 +17,10 @@ pub enum CrocoErrorKind {
     /// Thrown when an error occurs at runtime
     Runtime,
 
+    // crocoi-specific
+    /// Thrown when an error result is unwrapped, until a function returning a result catches it
+    Propagated,
+
     // crocol-specific
     /// Thrown when the compilation failed
     Compilation,
@@ -62,6 +66,11 @@ impl CrocoError {
         }
     }
 
+    /// Checks if the error is an error result being propagated to the caller
+    pub fn is_propagated(&self) -> bool {
+        self.kind == CrocoErrorKind::Propagated
+    }
+
     /// Sets the kind of error
     pub fn set_kind(&mut self, kind: CrocoErrorKind) {
         self.kind = kind;
@@ -77,6 +86,17 @@ impl CrocoError {
         CrocoError::new(code_pos, "cannot add these two types together")
     }
 
+    pub fn bitwise_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            "can only use bitwise operators on integers of compatible types",
+        )
+    }
+
+    pub fn bitwise_not_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "can only invert the bits of an integer")
+    }
+
     pub fn break_in_function_error(code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, "cannot exit a function with a break")
     }
@@ -94,21 +114,92 @@ impl CrocoError {
     }
 
     pub fn compare_numbers_only_error(code_pos: &CodePos) -> CrocoError {
-        CrocoError::new(code_pos, "can compare only numbers")
+        CrocoError::new(code_pos, "can compare only numbers and strs")
+    }
+
+    pub fn compare_unsupported_type_error(
+        code_pos: &CodePos,
+        symbol_type: &SymbolType,
+    ) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!("cannot compare values of type {}", symbol_type),
+        )
     }
 
     pub fn condition_not_bool_error(code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, "expected a bool for the condition")
     }
 
+    pub fn const_assignment_error(code_pos: &CodePos, var_name: &str) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!("cannot assign to {} because it is a const", var_name),
+        )
+        .hint("use let instead of const to declare a mutable variable")
+    }
+
+    pub fn const_init_error(code_pos: &CodePos, var_name: &str) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!("the const {} must be given a value", var_name),
+        )
+    }
+
+    pub fn const_method_call_error(
+        code_pos: &CodePos,
+        var_name: &str,
+        method_name: &str,
+    ) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!(
+                "cannot call {} on {} because it modifies it and {} is a const",
+                method_name, var_name, var_name
+            ),
+        )
+        .hint("use let instead of const to declare a mutable variable")
+    }
+
+    pub fn const_reference_error(code_pos: &CodePos, var_name: &str) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!(
+                "cannot take a reference to {} because it is a const",
+                var_name
+            ),
+        )
+    }
+
     pub fn continue_in_function_error(code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, "cannot use continue in a function")
     }
 
+    pub fn default_type_error(code_pos: &CodePos, expected: &SymbolType) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!("the default value must be of type {}", expected),
+        )
+    }
+
     pub fn dereference_error(code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, "cannot dereference this variable")
     }
 
+    pub fn destructure_error(
+        code_pos: &CodePos,
+        value_type: &SymbolType,
+        var_count: usize,
+    ) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!(
+                "cannot destructure {} into {} variables",
+                value_type, var_count
+            ),
+        )
+    }
+
     pub fn divide_error(code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, "cannot divide these two types together")
     }
@@ -122,6 +213,29 @@ impl CrocoError {
             .hint("use type annotations to declare empty arrays")
     }
 
+    pub fn enum_init_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "a variable of an enum type must be initialized")
+            .hint("assign a variant of the enum")
+    }
+
+    pub fn error_message_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "an error message must be a str")
+    }
+
+    pub fn fallback_type_error(code_pos: &CodePos, expected: Option<&SymbolType>) -> CrocoError {
+        match expected {
+            Some(expected) => CrocoError::new(
+                code_pos,
+                format!("the fallback value must be of type {}", expected),
+            ),
+            None => CrocoError::new(
+                code_pos,
+                "this result doesn't hold any value to fall back to",
+            )
+            .hint("use catch to handle the error"),
+        }
+    }
+
     pub fn field_type_error(field_name: &str, code_pos: &CodePos) -> CrocoError {
         CrocoError::new(
             code_pos,
@@ -129,6 +243,30 @@ impl CrocoError {
         )
     }
 
+    pub fn function_init_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            "a variable of a function type must be initialized",
+        )
+        .hint("assign a function or a closure")
+    }
+
+    pub fn generic_fn_value_error(code_pos: &CodePos, fn_name: &str) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!("cannot use the generic function {} as a value", fn_name),
+        )
+        .hint("wrap the call in a closure with concrete types")
+    }
+
+    pub fn import_cycle_error(code_pos: &CodePos, cycle: &[String]) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!("import cycle between the files {}", cycle.join(" -> ")),
+        )
+        .hint("move the items used by several of these files to another file")
+    }
+
     pub fn index_out_of_bounds_error(code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, "index out of bounds")
     }
@@ -148,6 +286,37 @@ impl CrocoError {
         CrocoError::new(code_pos, "cannot invert something that isn't a boolean")
     }
 
+    pub fn iterable_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "can only iterate over ranges and arrays")
+    }
+
+    pub fn map_key_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "map keys can only be of type num, str or bool")
+    }
+
+    pub fn map_missing_key_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "key not found in the map")
+            .hint("use the contains method to check if a key exists")
+    }
+
+    pub fn match_arms_type_error(
+        code_pos: &CodePos,
+        expected: &SymbolType,
+        got: &SymbolType,
+    ) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!(
+                "match arms must be of the same type\nExpected {} but got {}",
+                expected, got
+            ),
+        )
+    }
+
+    pub fn match_pattern_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "pattern type doesn't match the matched value")
+    }
+
     pub fn minus_error(code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, "cannot substract these two types together")
     }
@@ -172,18 +341,87 @@ impl CrocoError {
         CrocoError::new(code_pos, "array elements must be of the same type")
     }
 
+    pub fn mixed_type_map(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "map keys and values must be of the same type")
+    }
+
+    pub fn module_assignment_error(code_pos: &CodePos, module_name: &str) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!("cannot assign to the items of the module {}", module_name),
+        )
+        .hint("use a function of the module instead")
+    }
+
+    pub fn modulo_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "cannot get the remainder of these two types")
+    }
+
+    pub fn modulo_by_zero_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            "cannot get the remainder of an integer divided by zero",
+        )
+    }
+
     pub fn multiplicate_error(code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, "cannot multiplicate these two types together")
     }
 
+    pub fn negative_exponent_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "cannot power an integer to a negative exponent")
+    }
+
     pub fn negative_indexing_error(code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, "cannot use a negative index")
     }
 
+    pub fn nested_call_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            "only a function or a variable holding a function value can be called",
+        )
+        .hint("store the function value in a variable before calling it")
+    }
+
     pub fn no_field_error(field_name: &str, code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, format!("no field with the name {}", field_name))
     }
 
+    pub fn no_variant_error(code_pos: &CodePos, enum_type: &str, variant: &str) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!("enum {} has no variant named {}", enum_type, variant),
+        )
+    }
+
+    pub fn non_exhaustive_match_error(code_pos: &CodePos, missing: &[String]) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!(
+                "the match doesn't handle the variants {}",
+                missing.join(", ")
+            ),
+        )
+        .hint("add the missing variants or a _ arm")
+    }
+
+    pub fn not_callable_error(code_pos: &CodePos, var_name: &str) -> CrocoError {
+        CrocoError::new(code_pos, format!("variable {} is not a function", var_name))
+    }
+
+    pub fn optional_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "expected an optional value")
+    }
+
+    pub fn optional_field_error(code_pos: &CodePos, field_name: &str) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!("can't access the field {} of an optional", field_name),
+        )
+        .hint("use ?. or unwrap the optional with if let")
+    }
+
     pub fn parameter_error(code_pos: &CodePos, index: usize, is_method: bool) -> CrocoError {
         // if we have a method, we don't want to show the self parameter as a true parameter
         let errored_param = if is_method { index } else { index + 1 };
@@ -201,10 +439,37 @@ impl CrocoError {
         CrocoError::new(code_pos, "cannot power these two types together")
     }
 
+    pub fn private_item_error(code_pos: &CodePos, module_name: &str, item: &str) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!("the module {} has no pub item named {}", module_name, item),
+        )
+    }
+
+    pub fn propagated_error(code_pos: &CodePos, message: &str) -> CrocoError {
+        let mut err = CrocoError::new(code_pos, message)
+            .hint("handle the error with else or catch, or return a result from this function");
+        err.set_kind(CrocoErrorKind::Propagated);
+        err
+    }
+
+    pub fn range_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "range bounds must be nums")
+    }
+
+    pub fn result_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "only results can be unwrapped or handled")
+    }
+
     pub fn tmp_value_borrow(code_pos: &CodePos) -> CrocoError {
         CrocoError::new(code_pos, "trying to borrow a temporary value")
     }
 
+    pub fn trait_init_error(code_pos: &CodePos) -> CrocoError {
+        CrocoError::new(code_pos, "a variable of a trait type must be initialized")
+            .hint("assign a struct implementing the trait")
+    }
+
     pub fn type_annotation_error(code_pos: &CodePos, var_name: &str) -> CrocoError {
         CrocoError::new(
             code_pos,
@@ -223,6 +488,16 @@ impl CrocoError {
         CrocoError::new(code_pos, "cannot negate this type of variable")
     }
 
+    pub fn variant_payload_error(code_pos: &CodePos, variant: &str) -> CrocoError {
+        CrocoError::new(
+            code_pos,
+            format!(
+                "the values don't match the declaration of the variant {}",
+                variant
+            ),
+        )
+    }
+
     pub fn wrong_return(
         fn_ty: Option<&SymbolType>,
         ret_ty: Option<&SymbolType>,
@@ -251,7 +526,7 @@ impl CrocoError {
     }
 
     pub fn wrong_type_indexing(code_pos: &CodePos) -> CrocoError {
-        CrocoError::new(code_pos, "only arrays are indexable")
+        CrocoError::new(code_pos, "only arrays and maps are indexable")
     }
 }
 
@@ -260,7 +535,7 @@ impl fmt::Display for CrocoError {
         let error_kind = match &self.kind {
             CrocoErrorKind::Syntax => "Syntax error",
             CrocoErrorKind::Parse => "Parse error",
-            CrocoErrorKind::Runtime => "Runtime error",
+            CrocoErrorKind::Runtime | CrocoErrorKind::Propagated => "Runtime error",
             CrocoErrorKind::Io => "File error",
             CrocoErrorKind::CompileTarget => "Compile error",
             CrocoErrorKind::Malloc => "Allocation error",
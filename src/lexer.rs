@@ -18,6 +18,30 @@ fn is_fnum(el: &str) -> Option<f32> {
     el.parse().ok().filter(|_| el.contains('.'))
 }
 
+/// Parses a number literal with a type suffix, e.g 10i64 or 2.5f64.
+/// Returns an error if the number doesn't fit in its type.
+fn is_suffixed_num(el: &str) -> Option<Result<LiteralEnum, &'static str>> {
+    let suffixes = ["i64", "u64", "u8", "f64"];
+    let (number, suffix) = suffixes
+        .iter()
+        .find_map(|suffix| el.strip_suffix(suffix).map(|number| (number, *suffix)))?;
+
+    // only floating-point numbers can have a decimal part
+    let is_number = number.chars().all(|c| c.is_ascii_digit() || c == '.');
+    if number.is_empty() || !is_number || (suffix != "f64" && number.contains('.')) {
+        return None;
+    }
+
+    let literal = match suffix {
+        "i64" => number.parse().ok().map(LiteralEnum::I64),
+        "u64" => number.parse().ok().map(LiteralEnum::U64),
+        "u8" => number.parse().ok().map(LiteralEnum::U8),
+        _ => number.parse().ok().map(LiteralEnum::F64),
+    };
+
+    Some(literal.ok_or("number literal out of range for its type"))
+}
+
 pub struct Lexer {
     namespace: String,
 
@@ -73,17 +97,39 @@ impl Lexer {
         // check if it's a number
         let mut fnum = is_fnum(&el);
         let num = is_num(&el);
+        let suffixed_num = is_suffixed_num(el);
 
         if let Some(num) = num {
             // make sure we handle correctly floating-point numbers like "40."
+            // but not ranges like "0..5"
             if let Some(&".") = iter.peek() {
-                fnum = Some(num as f32);
-                iter.next();
+                let mut lookahead = iter.clone();
+                lookahead.next();
+
+                if lookahead.peek() != Some(&".") {
+                    fnum = Some(num as f32);
+                    iter.next();
+                }
             }
         }
 
         // tokenize
         match el {
+            // number literal with a type suffix
+            _ if suffixed_num.is_some() => {
+                let literal = suffixed_num.unwrap().map_err(|e| {
+                    CrocoError::new(
+                        &CodePos {
+                            file: self.file.clone(),
+                            line: self.line_index,
+                            word: self.word_index,
+                        },
+                        e,
+                    )
+                })?;
+                self.queue.push(Literal(literal))
+            }
+
             // number literal
             // try to get a floating number if possible
             _ if fnum != None => self.queue.push(Literal(LiteralEnum::Fnum(fnum.unwrap()))),
@@ -94,6 +140,9 @@ impl Lexer {
             "\"" => {
                 let mut words_in_quotes: Vec<&str> = Vec::new();
 
+                // the tokens of an interpolated str, e.g "a is {a}" becomes ("a is " + {a})
+                let mut interpolated: Vec<Token> = Vec::new();
+
                 loop {
                     if let Some(el) = iter.next() {
                         self.word_index += 1;
@@ -117,6 +166,26 @@ impl Lexer {
                                 break;
                             }
 
+                            // escaped braces, e.g "{{" or "}}"
+                            "{" | "}" if iter.peek() == Some(&el) => {
+                                iter.next();
+                                words_in_quotes.push(el);
+                            }
+
+                            // interpolated expression, e.g "{a}"
+                            "{" => {
+                                let expr = self.read_interpolation(iter)?;
+
+                                interpolated
+                                    .push(Literal(LiteralEnum::Str(words_in_quotes.join(""))));
+                                interpolated.push(Operator(Plus));
+                                interpolated.push(Separator(InterpolationStart));
+                                interpolated.append(&mut self.lex_interpolation(&expr)?);
+                                interpolated.push(Separator(InterpolationEnd));
+                                interpolated.push(Operator(Plus));
+                                words_in_quotes.clear();
+                            }
+
                             _ => {
                                 words_in_quotes.push(el);
                             }
@@ -132,8 +201,23 @@ impl Lexer {
                         ));
                     }
                 }
-                self.queue
-                    .push(Literal(LiteralEnum::Str(words_in_quotes.join(""))));
+                let last_part = Literal(LiteralEnum::Str(words_in_quotes.join("")));
+
+                if interpolated.is_empty() {
+                    self.queue.push(last_part);
+                } else {
+                    // the interpolated parts are concatenated in parenthesis
+                    if words_in_quotes.is_empty() {
+                        interpolated.pop();
+                    } else {
+                        interpolated.push(last_part);
+                    }
+
+                    // the queue is consumed from the end
+                    self.queue.push(Separator(RightParenthesis));
+                    self.queue.extend(interpolated.into_iter().rev());
+                    self.queue.push(Separator(LeftParenthesis));
+                }
             }
 
             // boolean literal
@@ -148,8 +232,58 @@ impl Lexer {
             "[" => self.queue.push(Separator(LeftSquareBracket)),
             "]" => self.queue.push(Separator(RightSquareBracket)),
             "," => self.queue.push(Separator(Comma)),
-            "." => self.queue.push(Separator(Dot)),
+            "." => {
+                let mut ret = Separator(Dot);
+
+                // ranges, e.g 0..5 or 0..=5
+                if let Some(&".") = iter.peek() {
+                    iter.next();
+                    ret = Separator(DoubleDot);
+
+                    if let Some(&"=") = iter.peek() {
+                        iter.next();
+                        ret = Separator(DoubleDotEquals);
+                    }
+                } else if let Some(indexes) = iter
+                    .peek()
+                    .and_then(|next| next.split('.').map(is_num).collect::<Option<Vec<_>>>())
+                {
+                    // tuple elements, e.g pair.0
+                    // nested.0.1 is read as the fnum 0.1 by unicode_segmentation, so we split it
+                    iter.next();
+
+                    for (i, index) in indexes.iter().rev().enumerate() {
+                        self.queue.push(Literal(LiteralEnum::Num(*index)));
+
+                        if i != indexes.len() - 1 {
+                            self.queue.push(Separator(Dot));
+                        }
+                    }
+                }
+
+                self.queue.push(ret)
+            }
             ":" => self.queue.push(Separator(Colon)),
+            "?" => {
+                let mut ret = Separator(QuestionMark);
+
+                match iter.peek() {
+                    // field access on an optional, e.g node?.next
+                    Some(&".") => {
+                        iter.next();
+                        ret = Separator(QuestionDot);
+                    }
+                    // default value of an optional, e.g name ?? "anonymous"
+                    Some(&"?") => {
+                        iter.next();
+                        ret = Operator(Coalesce);
+                    }
+                    _ => (),
+                }
+
+                self.queue.push(ret)
+            }
+            "_" => self.queue.push(Separator(Underscore)),
             "\r\n" | "\n" => {
                 self.new_line = true;
                 self.queue.push(Separator(NewLine))
@@ -162,11 +296,17 @@ impl Lexer {
             "=" => {
                 let mut ret = Operator(Assign);
 
-                if let Some(x) = iter.peek() {
-                    if x == &"=" {
+                match iter.peek() {
+                    Some(&"=") => {
                         iter.next();
                         ret = Operator(Equals);
                     }
+                    // match arm, e.g 1 => "one"
+                    Some(&">") => {
+                        iter.next();
+                        ret = Separator(FatArrow);
+                    }
+                    _ => (),
                 }
 
                 self.queue.push(ret)
@@ -234,14 +374,38 @@ impl Lexer {
                 }
             }
 
-            "^" => {
-                let mut ret = Operator(Power);
+            "%" => {
+                let mut ret = Operator(Modulo);
 
                 if let Some(x) = iter.peek() {
                     if x == &"=" {
+                        iter.next();
+                        ret = Operator(ModuloEquals);
+                    }
+                }
+
+                self.queue.push(ret)
+            }
+
+            "^" => {
+                let mut ret = Operator(Power);
+
+                match iter.peek() {
+                    Some(&"=") => {
                         iter.next();
                         ret = Operator(PowerEquals);
                     }
+                    // xor, e.g a ^^ b
+                    Some(&"^") => {
+                        iter.next();
+                        ret = Operator(BitwiseXor);
+
+                        if let Some(&"=") = iter.peek() {
+                            iter.next();
+                            ret = Operator(BitwiseXorEquals);
+                        }
+                    }
+                    _ => (),
                 }
 
                 self.queue.push(ret)
@@ -250,11 +414,21 @@ impl Lexer {
             ">" => {
                 let mut ret = Operator(GreaterThan);
 
-                if let Some(x) = iter.peek() {
-                    if x == &"=" {
+                match iter.peek() {
+                    Some(&"=") => {
                         iter.next();
                         ret = Operator(GreaterOrEqual);
                     }
+                    Some(&">") => {
+                        iter.next();
+                        ret = Operator(ShiftRight);
+
+                        if let Some(&"=") = iter.peek() {
+                            iter.next();
+                            ret = Operator(ShiftRightEquals);
+                        }
+                    }
+                    _ => (),
                 }
 
                 self.queue.push(ret)
@@ -263,11 +437,21 @@ impl Lexer {
             "<" => {
                 let mut ret = Operator(LowerThan);
 
-                if let Some(x) = iter.peek() {
-                    if x == &"=" {
+                match iter.peek() {
+                    Some(&"=") => {
                         iter.next();
                         ret = Operator(LowerOrEqual);
                     }
+                    Some(&"<") => {
+                        iter.next();
+                        ret = Operator(ShiftLeft);
+
+                        if let Some(&"=") = iter.peek() {
+                            iter.next();
+                            ret = Operator(ShiftLeftEquals);
+                        }
+                    }
+                    _ => (),
                 }
 
                 self.queue.push(ret)
@@ -276,11 +460,16 @@ impl Lexer {
             "&" => {
                 let mut ret = Operator(BitwiseAnd);
 
-                if let Some(x) = iter.peek() {
-                    if x == &"&" {
+                match iter.peek() {
+                    Some(&"&") => {
                         iter.next();
                         ret = Operator(And);
                     }
+                    Some(&"=") => {
+                        iter.next();
+                        ret = Operator(BitwiseAndEquals);
+                    }
+                    _ => (),
                 }
 
                 self.queue.push(ret)
@@ -289,16 +478,23 @@ impl Lexer {
             "|" => {
                 let mut ret = Operator(BitwiseOr);
 
-                if let Some(x) = iter.peek() {
-                    if x == &"|" {
+                match iter.peek() {
+                    Some(&"|") => {
                         iter.next();
                         ret = Operator(Or);
                     }
+                    Some(&"=") => {
+                        iter.next();
+                        ret = Operator(BitwiseOrEquals);
+                    }
+                    _ => (),
                 }
 
                 self.queue.push(ret)
             }
 
+            "~" => self.queue.push(Operator(BitwiseNot)),
+
             "!" => {
                 let mut ret = Operator(Bang);
 
@@ -315,20 +511,32 @@ impl Lexer {
             // keywords
             "bool" => self.queue.push(Keyword(Bool)),
             "break" => self.queue.push(Keyword(Break)),
+            "catch" => self.queue.push(Keyword(Catch)),
+            "const" => self.queue.push(Keyword(Const)),
             "continue" => self.queue.push(Keyword(Continue)),
             "elif" => self.queue.push(Keyword(Elif)),
             "else" => self.queue.push(Keyword(Else)),
+            "enum" => self.queue.push(Keyword(Enum)),
+            "error" => self.queue.push(Keyword(Error)),
+            "f64" => self.queue.push(Keyword(F64)),
             "fn" => self.queue.push(Keyword(Function)),
             "for" => self.queue.push(Keyword(For)),
+            "i64" => self.queue.push(Keyword(I64)),
             "if" => self.queue.push(Keyword(If)),
+            "in" => self.queue.push(Keyword(In)),
             "let" => self.queue.push(Keyword(Let)),
             "match" => self.queue.push(Keyword(Match)),
+            "none" => self.queue.push(Keyword(NoneValue)),
             "num" => self.queue.push(Keyword(Num)),
+            "pub" => self.queue.push(Keyword(Pub)),
             "fnum" => self.queue.push(Keyword(Fnum)),
             "while" => self.queue.push(Keyword(While)),
             "str" => self.queue.push(Keyword(Str)),
             "struct" => self.queue.push(Keyword(Struct)),
             "test" => self.queue.push(Keyword(Test)),
+            "trait" => self.queue.push(Keyword(Trait)),
+            "u64" => self.queue.push(Keyword(U64)),
+            "u8" => self.queue.push(Keyword(U8)),
             "return" => self.queue.push(Keyword(Return)),
             "import" => self.queue.push(Keyword(Import)),
 
@@ -372,6 +580,71 @@ impl Lexer {
         Ok(true)
     }
 
+    /// Reads the code of an expression interpolated in a str, until the closing brace
+    fn read_interpolation(
+        &mut self,
+        iter: &mut Peekable<UWordBounds>,
+    ) -> Result<String, CrocoError> {
+        let mut expr = String::new();
+        // braces can be used inside the expression
+        let mut depth = 0;
+
+        loop {
+            self.word_index += 1;
+
+            match iter.next() {
+                Some("}") if depth == 0 => break,
+                // an interpolated expression can't span multiple lines
+                Some(el) if el != "\n" && el != "\r\n" => {
+                    match el {
+                        "{" => depth += 1,
+                        "}" => depth -= 1,
+                        _ => (),
+                    }
+
+                    expr.push_str(el);
+                }
+                _ => {
+                    return Err(CrocoError::new(
+                        &CodePos {
+                            file: self.file.clone(),
+                            line: self.line_index,
+                            word: self.word_index,
+                        },
+                        "unclosed brace in the str",
+                    ))
+                }
+            }
+        }
+
+        if expr.trim().is_empty() {
+            return Err(CrocoError::new(
+                &CodePos {
+                    file: self.file.clone(),
+                    line: self.line_index,
+                    word: self.word_index,
+                },
+                "expected an expression between the braces, use {{ to escape a brace",
+            ));
+        }
+
+        Ok(expr)
+    }
+
+    /// Gets the tokens of an expression interpolated in a str
+    fn lex_interpolation(&self, expr: &str) -> Result<Vec<Token>, CrocoError> {
+        let mut lexer = Lexer::new();
+        lexer.set_file(&self.file);
+        lexer.set_namespace(self.namespace.clone());
+        lexer.line_index = self.line_index;
+
+        Ok(lexer
+            .process(expr)?
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect())
+    }
+
     /// Returns an array of tokens
     pub fn process(&mut self, code: &str) -> Result<Vec<(Token, CodePos)>, CrocoError> {
         let mut iter = code.split_word_bounds().peekable();
@@ -75,8 +75,9 @@ pub fn link(object_file: &str, output_file: &str) -> Result<String, String> {
         .or_else(|| file_in_path("gcc"))
         .ok_or("no suitable linker found")?;
 
+    // the math functions may be lowered to libm calls
     let mut command = Command::new(&gnulike_cc);
-    command.args(&[object_file, "-o", output_file]);
+    command.args(&[object_file, "-o", output_file, "-lm"]);
 
     if let Ok(status) = command.status() {
         if status.success() {
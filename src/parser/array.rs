@@ -6,8 +6,8 @@ use crate::parser::ExprParsingType::*;
 use crate::token::{CodePos, SeparatorEnum::*, Token, Token::*};
 
 impl Parser {
-    /// Parses an array literal to the corresponding symbol node.
-    /// e.g [3+3, 5, 8]
+    /// Parses an array or map literal to the corresponding symbol node.
+    /// e.g [3+3, 5, 8] or ["a": 1, "b": 2]
     /// warning: does not consume the opening square bracket
     pub fn parse_array(
         &mut self,
@@ -16,12 +16,24 @@ impl Parser {
         let mut symbols: Vec<Box<dyn BackendNode>> = Vec::new();
 
         loop {
+            self.discard_newlines(iter);
+
             if let Separator(RightSquareBracket) = self.peek_token(iter) {
                 self.next_token(iter);
                 break;
             }
 
-            symbols.push(self.parse_expr(iter, AllowStructDeclaration)?);
+            let symbol = self.parse_expr(iter, AllowStructDeclaration)?;
+
+            // a colon after the first element means that we're declaring a map
+            if let Separator(Colon) = self.peek_token(iter) {
+                if symbols.is_empty() {
+                    self.next_token(iter);
+                    return self.parse_map(iter, symbol);
+                }
+            }
+
+            symbols.push(symbol);
             self.discard_newlines(iter);
 
             match self.next_token(iter) {
@@ -44,4 +56,54 @@ impl Parser {
             self.token_pos.clone(),
         )))
     }
+
+    /// Parses a map literal, once its first key has been parsed.
+    /// The entries are separated by commas or new lines.
+    /// warning: does not consume the first key and the colon following it
+    fn parse_map(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+        first_key: Box<dyn BackendNode>,
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        let mut contents = Vec::new();
+        let mut key = first_key;
+
+        loop {
+            let value = self.parse_expr(iter, AllowStructDeclaration)?;
+            contents.push((key, value));
+
+            let is_separated = match self.peek_token(iter) {
+                Separator(Comma) => {
+                    self.next_token(iter);
+                    true
+                }
+                Separator(NewLine) => true,
+                _ => false,
+            };
+
+            self.discard_newlines(iter);
+
+            if let Separator(RightSquareBracket) = self.peek_token(iter) {
+                self.next_token(iter);
+                break;
+            }
+
+            if !is_separated {
+                return Err(CrocoError::new(
+                    &self.token_pos,
+                    "unexpected token in map declaration",
+                ));
+            }
+
+            key = self.parse_expr(iter, AllowStructDeclaration)?;
+
+            self.expect_token(iter, Separator(Colon), "expected a colon after the map key")?;
+        }
+
+        // as with arrays, the types of the keys and values are checked at runtime
+        Ok(Box::new(MapCreateNode::new(
+            contents,
+            self.token_pos.clone(),
+        )))
+    }
 }
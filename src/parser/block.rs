@@ -1,4 +1,4 @@
-use super::{ExprParsingType::*, Parser};
+use super::{mutation::Mutation, ExprParsingType::*, Parser, TestBlock};
 
 use crate::ast::{AstNode, BlockScope};
 use crate::error::CrocoError;
@@ -22,6 +22,12 @@ impl Parser {
         is_top_level: bool,
     ) -> Result<Box<dyn BackendNode>, CrocoError> {
         let mut block = BlockNode::new(scope);
+
+        // the variables declared at top level are globals
+        if !is_top_level {
+            self.variables.add_scope();
+        }
+
         // loop until we have no token remaining
         loop {
             let token = self.peek_token(iter);
@@ -36,15 +42,23 @@ impl Parser {
                     break;
                 }
 
-                // declaring a new number variable
-                Keyword(Let) => {
-                    self.next_token(iter);
+                // declaring a new variable, which can't be mutated if it's a const
+                Keyword(Let) | Keyword(Const) => {
+                    let is_const = self.next_token(iter) == Keyword(Const);
+
+                    // destructuring a tuple
+                    if let Separator(LeftParenthesis) = self.peek_token(iter) {
+                        self.next_token(iter);
+                        block.add_child(self.parse_destructure(iter, is_const)?);
+                        continue;
+                    }
+
                     let mut out_node: Option<Box<dyn BackendNode>> = None;
 
                     // we're expecting a variable name
                     let identifier = self.expect_identifier(
                         iter,
-                        "expected a variable name after the let keyword",
+                        "expected a variable name after the let or const keyword",
                     )?;
 
                     let mut assign_type = None;
@@ -61,9 +75,16 @@ impl Parser {
                         | Keyword(Num)
                         | Keyword(Str)
                         | Keyword(Bool)
+                        | Keyword(I64)
+                        | Keyword(U64)
+                        | Keyword(U8)
+                        | Keyword(F64)
+                        | Keyword(Function)
                         | Identifier(_)
                         | Operator(BitwiseAnd)
-                        | Separator(LeftSquareBracket) => {
+                        | Separator(QuestionMark)
+                        | Separator(LeftSquareBracket)
+                        | Separator(LeftParenthesis) => {
                             assign_type = Some(self.parse_var_type(iter)?);
                         }
 
@@ -99,9 +120,28 @@ impl Parser {
                         }
                     }
 
+                    if is_const && out_node.is_none() {
+                        return Err(CrocoError::const_init_error(
+                            &self.token_pos,
+                            &identifier.name,
+                        ));
+                    }
+
+                    // the globals of a module are namespaced
+                    let var_name = if is_top_level {
+                        identifier.get_namespaced_name()
+                    } else {
+                        identifier.name
+                    };
+
+                    let var_type = assign_type
+                        .clone()
+                        .or_else(|| out_node.as_ref().and_then(|node| node.get_static_type()));
+                    self.declare_variable(&var_name, is_const, var_type);
+
                     // add this statement to the block
                     block.add_child(Box::new(VarDeclNode::new(
-                        identifier.get_namespaced_name(),
+                        var_name,
                         out_node,
                         assign_type,
                         self.token_pos.clone(),
@@ -110,7 +150,13 @@ impl Parser {
 
                 // assigning a new value to a variable / struct field, or calling a function
                 Identifier(_) | Operator(Multiplicate) => {
-                    let lvalue_node = self.parse_identifier(iter, AllowStructDeclaration)?;
+                    // the variable which is assigned, or whose fields are assigned
+                    let var_name = match self.peek_token(iter) {
+                        Identifier(identifier) => Some(identifier),
+                        _ => None,
+                    };
+
+                    let mut lvalue_node = self.parse_identifier(iter, AllowStructDeclaration)?;
 
                     if let Operator(op_token) = self.peek_token(iter) {
                         self.next_token(iter);
@@ -123,12 +169,35 @@ impl Parser {
                             | MinusEquals
                             | MultiplicateEquals
                             | DivideEquals
-                            | PowerEquals => {
+                            | PowerEquals
+                            | ModuloEquals
+                            | BitwiseAndEquals
+                            | BitwiseOrEquals
+                            | BitwiseXorEquals
+                            | ShiftLeftEquals
+                            | ShiftRightEquals => {
+
+                                if let Some(identifier) = var_name {
+                                    if self.is_module(&identifier.name) {
+                                        return Err(CrocoError::module_assignment_error(&self.token_pos, &identifier.name));
+                                    }
+
+                                    let var_name = self.resolve_item_name(&identifier);
+                                    if self.is_const(&var_name) {
+                                        return Err(CrocoError::const_assignment_error(&self.token_pos, &var_name));
+                                    }
+                                    self.record_undeclared_mutation(&var_name, Mutation::Assignment);
+
+                                    if var_name == "self" {
+                                        self.mark_self_mutated();
+                                    }
+                                }
 
                                 let expr_node = self.parse_expr(iter, AllowStructDeclaration)?;
 
                                 // add to the root function this statement
                                 if op_token == Assign {
+                                    lvalue_node.set_assigned();
                                     block.add_child(Box::new(AssignmentNode::new(lvalue_node, expr_node, self.token_pos.clone())));
                                 } else {
                                     let mut dyn_op_node: Box<dyn BackendNode> = match op_token {
@@ -139,6 +208,12 @@ impl Parser {
                                         }
                                         DivideEquals => Box::new(DivideNode::new(self.token_pos.clone())),
                                         PowerEquals => Box::new(PowerNode::new(self.token_pos.clone())),
+                                        ModuloEquals => Box::new(ModuloNode::new(self.token_pos.clone())),
+                                        BitwiseAndEquals => Box::new(BitwiseNode::new(BitwiseAnd, self.token_pos.clone())),
+                                        BitwiseOrEquals => Box::new(BitwiseNode::new(BitwiseOr, self.token_pos.clone())),
+                                        BitwiseXorEquals => Box::new(BitwiseNode::new(BitwiseXor, self.token_pos.clone())),
+                                        ShiftLeftEquals => Box::new(BitwiseNode::new(ShiftLeft, self.token_pos.clone())),
+                                        ShiftRightEquals => Box::new(BitwiseNode::new(ShiftRight, self.token_pos.clone())),
                                         _ => unreachable!(),
                                     };
 
@@ -159,7 +234,13 @@ impl Parser {
                             }
                         }
                     } else {
-                        block.add_child(lvalue_node);
+                        // the error of a called function can be handled right away
+                        block.add_child(self.parse_catch(
+                            iter,
+                            lvalue_node,
+                            AllowStructDeclaration,
+                            false,
+                        )?);
                     }
                 }
 
@@ -174,10 +255,12 @@ impl Parser {
                         ));
                     }
 
-                    let struct_name = self.expect_identifier(
-                        iter,
-                        "expected the struct name after struct declaration",
-                    )?;
+                    let struct_name = self
+                        .expect_identifier(
+                            iter,
+                            "expected the struct name after struct declaration",
+                        )?
+                        .get_namespaced_name();
 
                     self.expect_token(
                         iter,
@@ -195,7 +278,7 @@ impl Parser {
                             // struct method
                             Keyword(Function) => {
                                 let (method_name, method_body) =
-                                    self.parse_function_decl(iter, Some(struct_name.name.clone()))?;
+                                    self.parse_function_decl(iter, Some(struct_name.clone()))?;
 
                                 // check if the method name isn't already a field name
                                 if methods.contains_key(&method_name) {
@@ -245,10 +328,10 @@ impl Parser {
                     }
 
                     // register the struct declaration
-                    self.register_struct_decl(&struct_name.name, StructDecl { fields })?;
+                    self.register_struct_decl(&struct_name, StructDecl { fields })?;
 
                     block.add_child(Box::new(StructDeclNode::new(
-                        struct_name.name,
+                        struct_name,
                         methods,
                         self.token_pos.clone(),
                     )));
@@ -306,6 +389,13 @@ impl Parser {
                         .hint("add a main function"));
                     }
 
+                    // unwrapping of an optional, e.g if let node = list.head { ... }
+                    if let Keyword(Let) = self.peek_token(iter) {
+                        self.next_token(iter);
+                        block.add_child(self.parse_if_let(iter)?);
+                        continue;
+                    }
+
                     // we can have multiple conditions in an if elif construct, we use an array to keep track of all of them
                     let mut conditions: Vec<Box<dyn BackendNode>> = Vec::new();
                     // same for the statements inside the if / elif / else
@@ -387,6 +477,84 @@ impl Parser {
                     block.add_child(Box::new(WhileNode::new(cond, body, self.token_pos.clone())))
                 }
 
+                // for loop
+                Keyword(For) => {
+                    self.next_token(iter);
+
+                    if is_top_level {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            "cannot use a for outside a function",
+                        )
+                        .hint("add a main function"));
+                    }
+
+                    self.expect_token(iter, Keyword(Let), "expected the let keyword after for")?;
+
+                    let var_name = self.expect_identifier(
+                        iter,
+                        "expected a variable name after the let keyword",
+                    )?;
+
+                    self.expect_token(
+                        iter,
+                        Keyword(In),
+                        "expected the in keyword after the variable name",
+                    )?;
+
+                    let start = self.parse_expr(iter, DenyStructDeclaration)?;
+
+                    // we're either iterating over a range or over an array
+                    let iterable = match self.peek_token(iter) {
+                        Separator(DoubleDot) | Separator(DoubleDotEquals) => {
+                            let inclusive = self.next_token(iter) == Separator(DoubleDotEquals);
+                            let end = self.parse_expr(iter, DenyStructDeclaration)?;
+
+                            ForIterable::Range {
+                                start,
+                                end,
+                                inclusive,
+                            }
+                        }
+                        _ => ForIterable::Array(start),
+                    };
+
+                    self.expect_token(
+                        iter,
+                        Separator(LeftCurlyBracket),
+                        "expected a left bracket after for expression",
+                    )?;
+
+                    let var_name = var_name.name;
+                    let body = self.parse_block_with_bindings(
+                        iter,
+                        BlockScope::New,
+                        &[(var_name.as_str(), None)],
+                    )?;
+                    block.add_child(Box::new(ForNode::new(
+                        var_name,
+                        iterable,
+                        body,
+                        self.token_pos.clone(),
+                    )))
+                }
+
+                // match statement
+                Keyword(Match) => {
+                    self.next_token(iter);
+
+                    if is_top_level {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            "cannot use a match outside a function",
+                        )
+                        .hint("add a main function"));
+                    }
+
+                    let match_node = self.parse_match(iter, false)?;
+                    block.add_child(match_node);
+                }
+
                 // break from a loop
                 Keyword(Break) => {
                     self.next_token(iter);
@@ -413,6 +581,60 @@ impl Parser {
 
                     block.add_child(Box::new(ContinueNode::new()));
                 }
+                // declaring a test
+                Keyword(Test) => {
+                    self.next_token(iter);
+
+                    if !is_top_level {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            "tests can only be declared at top level",
+                        ));
+                    }
+
+                    let name = self.expect_str(iter, "expected a str after the test keyword")?;
+
+                    self.expect_token(
+                        iter,
+                        Separator(LeftCurlyBracket),
+                        "expected a left bracket after the test name",
+                    )?;
+
+                    // tests are not part of the program, they are collected by the parser
+                    let body = self.parse_block(iter, BlockScope::Function, false)?;
+                    self.register_test(TestBlock { name, body })?;
+                }
+
+                // declaring a trait
+                Keyword(Trait) => {
+                    self.next_token(iter);
+
+                    if !is_top_level {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            "traits can only be declared at top level",
+                        ));
+                    }
+
+                    // traits are not part of the program, they are collected by the parser
+                    self.parse_trait_decl(iter)?;
+                }
+
+                // declaring an enum
+                Keyword(Enum) => {
+                    self.next_token(iter);
+
+                    if !is_top_level {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            "enums can only be declared at top level",
+                        ));
+                    }
+
+                    // enums are not part of the program, they are collected by the parser
+                    self.parse_enum_decl(iter)?;
+                }
+
                 // importing a package
                 Keyword(Import) => {
                     self.next_token(iter);
@@ -426,9 +648,31 @@ impl Parser {
 
                     let import_name =
                         self.expect_str(iter, "expected a str after the import keyword")?;
-                    let import_node =
-                        Box::new(ImportNode::new(import_name, self.token_pos.clone()));
-                    block.add_child(import_node);
+                    block.add_child(self.parse_import(import_name)?);
+                }
+
+                // making the next declaration visible to the files importing this one
+                Keyword(Pub) => {
+                    self.next_token(iter);
+
+                    if !is_top_level {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            "only top level declarations can be pub",
+                        ));
+                    }
+
+                    // the pub items are collected before parsing the file
+                    match self.peek_token(iter) {
+                        Keyword(Function) | Keyword(Struct) | Keyword(Enum) | Keyword(Trait)
+                        | Keyword(Let) | Keyword(Const) => (),
+                        _ => {
+                            return Err(CrocoError::new(
+                                &self.token_pos,
+                                "expected a declaration after pub",
+                            ))
+                        }
+                    }
                 }
 
                 Separator(NewLine) => {
@@ -444,6 +688,29 @@ impl Parser {
             }
         }
 
+        if !is_top_level {
+            self.variables.drop_scope();
+        }
+
         Ok(Box::new(block))
     }
+
+    /// Parses a block in a new scope where some variables are already bound, e.g the variable of a for loop.
+    /// The type of a binding is given when it is known at parse time
+    pub fn parse_block_with_bindings(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+        scope: BlockScope,
+        bindings: &[(&str, Option<SymbolType>)],
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        self.variables.add_scope();
+
+        for (binding, binding_type) in bindings {
+            self.declare_variable(binding, false, binding_type.clone());
+        }
+
+        let body = self.parse_block(iter, scope, false)?;
+        self.variables.drop_scope();
+        Ok(body)
+    }
 }
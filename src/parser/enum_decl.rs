@@ -0,0 +1,122 @@
+use super::{ExprParsingType::*, Parser};
+use crate::ast::{node::EnumCreateNode, BackendNode};
+use crate::error::CrocoError;
+use crate::symbol::EnumDecl;
+use crate::token::{CodePos, SeparatorEnum::*, Token, Token::*};
+
+impl Parser {
+    /// Parses an enum declaration and registers it.
+    /// e.g enum Shape { Circle(fnum), Rect(fnum, fnum), Empty }
+    /// warning: does not consume the enum keyword
+    pub fn parse_enum_decl(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+    ) -> Result<(), CrocoError> {
+        let enum_name =
+            self.expect_identifier(iter, "expected the enum name after enum declaration")?;
+
+        self.expect_token(
+            iter,
+            Separator(LeftCurlyBracket),
+            "expected a left bracket after the enum name",
+        )?;
+
+        let mut variants: Vec<(String, Vec<_>)> = Vec::new();
+
+        loop {
+            self.discard_newlines(iter);
+
+            match self.next_token(iter) {
+                Identifier(variant_name) => {
+                    if variants.iter().any(|(name, _)| *name == variant_name.name) {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            format!("duplicate variant {} in enum", variant_name.name),
+                        ));
+                    }
+
+                    // the types of the values held by the variant, if there's any
+                    let mut payload = Vec::new();
+
+                    if let Separator(LeftParenthesis) = self.peek_token(iter) {
+                        self.next_token(iter);
+
+                        loop {
+                            payload.push(self.parse_var_type(iter)?);
+
+                            match self.next_token(iter) {
+                                Separator(Comma) => (),
+                                Separator(RightParenthesis) => break,
+                                _ => {
+                                    return Err(CrocoError::new(
+                                        &self.token_pos,
+                                        "expected a comma or a right parenthesis in the variant",
+                                    ))
+                                }
+                            }
+                        }
+                    }
+
+                    variants.push((variant_name.name, payload));
+
+                    // variants can optionally be separated by commas
+                    if let Separator(Comma) = self.peek_token(iter) {
+                        self.next_token(iter);
+                    }
+                }
+
+                Separator(RightCurlyBracket) => break,
+
+                _ => {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        "expected a variant name in the enum",
+                    ))
+                }
+            }
+        }
+
+        self.register_enum_decl(&enum_name.get_namespaced_name(), EnumDecl { variants })
+    }
+
+    /// Parses the creation of an enum value.
+    /// e.g Shape.Circle(2.0) or Shape.Empty
+    /// warning: it does not consume the enum name and the dot
+    pub fn parse_enum_create(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+        enum_name: String,
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        let variant =
+            self.expect_identifier(iter, "expected a variant name after the enum name")?;
+
+        let mut args = Vec::new();
+
+        if let Separator(LeftParenthesis) = self.peek_token(iter) {
+            self.next_token(iter);
+
+            loop {
+                self.discard_newlines(iter);
+                args.push(self.parse_expr(iter, AllowStructDeclaration)?);
+
+                match self.next_token(iter) {
+                    Separator(Comma) => (),
+                    Separator(RightParenthesis) => break,
+                    _ => {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            "expected a comma or a right parenthesis after the variant value",
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(Box::new(EnumCreateNode::new(
+            enum_name,
+            variant.name,
+            args,
+            self.token_pos.clone(),
+        )))
+    }
+}
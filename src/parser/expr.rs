@@ -21,6 +21,7 @@ impl Parser {
         // util to know which operator has the highest priority (higher value is higher priority)
         let get_precedence = |op: &Token| -> u8 {
             match op {
+                Operator(Coalesce) => 0,
                 Operator(Or) => 1,
                 Operator(And) => 2,
                 Operator(Equals) | Operator(NotEquals) => 3,
@@ -28,10 +29,14 @@ impl Parser {
                 | Operator(GreaterThan)
                 | Operator(LowerOrEqual)
                 | Operator(LowerThan) => 4,
-                Operator(Plus) | Operator(Minus) => 5,
-                Operator(Multiplicate) | Operator(Divide) => 6,
-                Operator(UnaryMinus) => 7,
-                Operator(Power) => 8,
+                Operator(BitwiseOr) => 5,
+                Operator(BitwiseXor) => 6,
+                Operator(BitwiseAnd) => 7,
+                Operator(ShiftLeft) | Operator(ShiftRight) => 8,
+                Operator(Plus) | Operator(Minus) => 9,
+                Operator(Multiplicate) | Operator(Divide) | Operator(Modulo) => 10,
+                Operator(UnaryMinus) | Operator(BitwiseNot) | Operator(Bang) => 11,
+                Operator(Power) => 12,
                 _ => unreachable!(),
             }
         };
@@ -45,6 +50,9 @@ impl Parser {
                 Operator(Divide)
                     | Operator(Minus)
                     | Operator(Power)
+                    | Operator(Modulo)
+                    | Operator(ShiftLeft)
+                    | Operator(ShiftRight)
                     | Operator(GreaterOrEqual)
                     | Operator(GreaterThan)
                     | Operator(LowerOrEqual)
@@ -52,11 +60,6 @@ impl Parser {
             )
         };
 
-        // if we encouter a right parenthesis while there's no left parenthesis opened in our expression that
-        // means that we're in this situation :
-        // call_my_fn(3 + 4) <- this right parenthesis is the end of the function
-        let mut parenthesis_opened = 0;
-
         // sometimes minus can behave as an unary operator, e.g
         // let a = --6
         // let a = -(6*4)
@@ -67,23 +70,33 @@ impl Parser {
             // make sure that this token belongs to the expression
             // println!("{:?}", self.peek_token(iter));
             match expr_token {
-                // the right parenthesis is the end of a function
-                Separator(RightParenthesis) if parenthesis_opened == 0 => break,
-
                 // end of an expr
-                Separator(NewLine)
+                // parenthesis are parsed as a whole with the identifiers, so a right parenthesis is the end
+                // of a function call or of a tuple, e.g call_my_fn(3 + 4)
+                Separator(RightParenthesis)
+                | Separator(NewLine)
                 | Separator(Comma)
                 | Separator(LeftCurlyBracket)
+                | Separator(RightCurlyBracket)
+                | Separator(DoubleDot)
+                | Separator(DoubleDotEquals)
+                | Separator(Colon)
                 | EOF
-                | Separator(RightSquareBracket) => break,
+                | Separator(RightSquareBracket)
+                | Separator(InterpolationEnd)
+                | Keyword(Else)
+                | Keyword(Catch) => break,
                 _ => (),
             }
 
-            let mut is_next_token_unary =
-                matches!(expr_token, Operator(_) | Separator(LeftParenthesis));
+            let mut is_next_token_unary = matches!(expr_token, Operator(_));
 
             match expr_token {
-                Identifier(_) | Literal(_) | Separator(LeftSquareBracket) => {
+                Identifier(_)
+                | Literal(_)
+                | Separator(LeftSquareBracket)
+                | Separator(LeftParenthesis)
+                | Separator(InterpolationStart) => {
                     output.push(self.parse_identifier(iter, parse_type)?);
                 }
 
@@ -97,6 +110,26 @@ impl Parser {
                     output.push(self.get_node(expr_token)?)
                 }
 
+                Keyword(Match) => {
+                    self.next_token(iter);
+                    output.push(self.parse_match(iter, true)?)
+                }
+
+                Keyword(Function) => {
+                    self.next_token(iter);
+                    output.push(self.parse_closure(iter)?)
+                }
+
+                Keyword(Error) => {
+                    self.next_token(iter);
+                    output.push(self.parse_error(iter)?)
+                }
+
+                Keyword(NoneValue) => {
+                    self.next_token(iter);
+                    output.push(Box::new(NoneNode::new()))
+                }
+
                 Operator(_) => {
                     self.next_token(iter);
                     // if we have an unary operator flag it accordingly
@@ -113,6 +146,14 @@ impl Parser {
                         }
                         // do nothing as "!" is always unary
                         Operator(Bang) => (),
+                        Operator(BitwiseNot) if !is_unary => {
+                            return Err(CrocoError::new(
+                                &self.token_pos,
+                                "misuse of the bitwise not operator",
+                            ))
+                        }
+                        // "~" is always unary as well
+                        Operator(BitwiseNot) => (),
                         _ if is_unary => {
                             return Err(CrocoError::new(
                                 &self.token_pos,
@@ -123,61 +164,19 @@ impl Parser {
                     }
 
                     while let Some(top) = stack.last() {
-                        match top {
-                            Operator(_) => {
-                                if (!right_associative(&top)
-                                    && get_precedence(&top) == get_precedence(&expr_token))
-                                    || get_precedence(&top) > get_precedence(&expr_token)
-                                {
-                                    let op = stack.pop().unwrap();
-                                    match op {
-                                        Operator(_) => self.add_node(&mut output, op)?,
-                                        _ => panic!("not an operator found in the stack"),
-                                    }
-                                } else {
-                                    break;
-                                }
-                            }
-
-                            Separator(_) => break,
-                            _ => (unreachable!()),
+                        if (!right_associative(&top)
+                            && get_precedence(&top) == get_precedence(&expr_token))
+                            || get_precedence(&top) > get_precedence(&expr_token)
+                        {
+                            let op = stack.pop().unwrap();
+                            self.add_node(&mut output, op)?;
+                        } else {
+                            break;
                         }
                     }
 
                     stack.push(expr_token);
                 }
-                Separator(LeftParenthesis) => {
-                    self.next_token(iter);
-                    stack.push(expr_token);
-                    parenthesis_opened += 1;
-                }
-                Separator(RightParenthesis) => {
-                    self.next_token(iter);
-                    parenthesis_opened -= 1;
-
-                    while let Some(top) = stack.last() {
-                        match top {
-                            Separator(LeftParenthesis) => {
-                                stack.pop();
-                                break;
-                            }
-                            _ => {
-                                let popped = stack.pop();
-                                match popped {
-                                    Some(Operator(_)) => {
-                                        self.add_node(&mut output, popped.unwrap())?
-                                    }
-                                    _ => {
-                                        return Err(CrocoError::new(
-                                            &self.token_pos,
-                                            "missing parenthesis in expression",
-                                        ))
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
                 _ => {
                     return Err(CrocoError::new(
                         &self.token_pos,
@@ -190,21 +189,15 @@ impl Parser {
         }
 
         while let Some(popped) = stack.pop() {
-            match popped {
-                Separator(LeftParenthesis) => {
-                    return Err(CrocoError::new(
-                        &self.token_pos,
-                        "missing parenthesis in expression",
-                    ))
-                }
-                _ => self.add_node(&mut output, popped)?,
-            }
+            self.add_node(&mut output, popped)?;
         }
 
         if output.is_empty() {
             return Ok(Box::new(VoidNode::new()));
         }
 
-        Ok(output.pop().unwrap())
+        // the error of a result can be handled right after the expression
+        let expr = output.pop().unwrap();
+        self.parse_catch(iter, expr, parse_type, true)
     }
 }
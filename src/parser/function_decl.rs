@@ -1,9 +1,9 @@
 use super::{Parser, TypedArg};
 use crate::error::CrocoError;
 use crate::symbol::FunctionDecl;
-use crate::token::{CodePos, SeparatorEnum::*, Token, Token::*};
+use crate::token::{CodePos, OperatorEnum::*, SeparatorEnum::*, Token, Token::*};
 use crate::{
-    ast::{BackendNode, BlockScope},
+    ast::{node::ClosureNode, BackendNode, BlockScope},
     symbol_type::SymbolType,
 };
 
@@ -15,15 +15,30 @@ impl Parser {
         iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
         struct_name: Option<String>,
     ) -> Result<(String, Box<dyn BackendNode>), CrocoError> {
-        let mut fn_name = self.expect_identifier(
+        let identifier = self.expect_identifier(
             iter,
             "expected the function name after function declaration",
         )?;
 
+        // the calls on self are recorded to know if the method modifies self
+        self.current_method = struct_name
+            .clone()
+            .map(|struct_name| (struct_name, identifier.name.clone()));
+
         // if we have a method, format correctly the function name
-        if let Some(struct_name) = &struct_name {
-            fn_name.name = format!("_{}_{}", struct_name, fn_name.name)
-        }
+        let fn_name = if let Some(struct_name) = &struct_name {
+            format!("_{}_{}", struct_name, identifier.name)
+        } else {
+            identifier.get_namespaced_name()
+        };
+
+        // the type parameters are only visible in the function signature and body
+        self.generic_names = if let Operator(LowerThan) = self.peek_token(iter) {
+            self.next_token(iter);
+            self.parse_generic_names(iter)?
+        } else {
+            Vec::new()
+        };
 
         self.expect_token(
             iter,
@@ -32,7 +47,7 @@ impl Parser {
         )?;
 
         // if we have a method, the self argument is the first arg
-        let mut typed_args = if let Some(struct_name) = struct_name {
+        let typed_args = if let Some(struct_name) = struct_name {
             vec![TypedArg {
                 arg_name: "self".to_owned(),
                 arg_type: SymbolType::Ref(Box::new(SymbolType::Struct(struct_name))),
@@ -41,6 +56,147 @@ impl Parser {
             Vec::new()
         };
 
+        let typed_args = self.parse_function_args(iter, &fn_name, typed_args)?;
+
+        // Might allow weird parsing: does it matter ?
+        // fn bla()
+        // Void
+        // { ...
+        self.discard_newlines(iter);
+
+        // if the return type isn't specified the function is Void
+        let return_type = if let Separator(LeftCurlyBracket) = self.peek_token(iter) {
+            None
+        } else {
+            Some(self.parse_var_type(iter)?)
+        };
+
+        // the type parameters are resolved from the arguments at the call site
+        for generic_name in &self.generic_names {
+            if !typed_args
+                .iter()
+                .any(|arg| arg.arg_type.contains_generic(generic_name))
+            {
+                return Err(CrocoError::new(
+                    &self.token_pos,
+                    format!(
+                        "the type parameter {} must be used by an argument",
+                        generic_name
+                    ),
+                ));
+            }
+        }
+
+        self.discard_newlines(iter);
+
+        self.expect_token(
+            iter,
+            Separator(LeftCurlyBracket),
+            "expected a left bracket after function declaration",
+        )?;
+
+        let arg_bindings: Vec<(&str, Option<SymbolType>)> = typed_args
+            .iter()
+            .map(|arg| (arg.arg_name.as_str(), Some(arg.arg_type.clone())))
+            .collect();
+        let fn_body = self.parse_block_with_bindings(iter, BlockScope::Function, &arg_bindings)?;
+        self.generic_names.clear();
+        self.current_method = None;
+
+        let fn_decl = FunctionDecl {
+            args: typed_args,
+            return_type,
+        };
+
+        self.register_fn_decl(&fn_name, fn_decl)?;
+
+        Ok((fn_name, fn_body))
+    }
+
+    /// Parses an anonymous function
+    /// e.g fn(x num) num { return x * 2 }
+    /// warning: does not consume the fn keyword
+    pub fn parse_closure(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        let code_pos = self.token_pos.clone();
+
+        self.expect_token(
+            iter,
+            Separator(LeftParenthesis),
+            "expected a left parenthensis after fn in the anonymous function",
+        )?;
+
+        let args = self.parse_function_args(iter, "anonymous", Vec::new())?;
+
+        // if the return type isn't specified the function is Void
+        let return_type = if let Separator(LeftCurlyBracket) = self.peek_token(iter) {
+            None
+        } else {
+            Some(self.parse_var_type(iter)?)
+        };
+
+        self.expect_token(
+            iter,
+            Separator(LeftCurlyBracket),
+            "expected a left bracket after the anonymous function signature",
+        )?;
+
+        let arg_bindings: Vec<(&str, Option<SymbolType>)> = args
+            .iter()
+            .map(|arg| (arg.arg_name.as_str(), Some(arg.arg_type.clone())))
+            .collect();
+        let fn_body = self.parse_block_with_bindings(iter, BlockScope::Function, &arg_bindings)?;
+        let fn_decl = FunctionDecl { args, return_type };
+
+        Ok(Box::new(ClosureNode::new(fn_decl, fn_body, code_pos)))
+    }
+
+    /// Parses the type parameters of a function declaration
+    /// e.g <T, U>
+    /// warning: the lower than sign must already be consumed
+    fn parse_generic_names(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+    ) -> Result<Vec<String>, CrocoError> {
+        let mut generic_names: Vec<String> = Vec::new();
+
+        loop {
+            let generic_name = self.expect_identifier(iter, "expected a type parameter name")?;
+
+            if generic_names.contains(&generic_name.name) {
+                return Err(CrocoError::new(
+                    &self.token_pos,
+                    format!("duplicate type parameter {}", generic_name.name),
+                ));
+            }
+
+            generic_names.push(generic_name.name);
+
+            match self.next_token(iter) {
+                Separator(Comma) => (),
+                Operator(GreaterThan) => break,
+                _ => {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        "expected a comma or > after the type parameter",
+                    ))
+                }
+            }
+        }
+
+        Ok(generic_names)
+    }
+
+    /// Parses the arguments of a function declaration, and appends them to the given ones
+    /// warning: the left parenthesis must already be consumed
+    pub fn parse_function_args(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+        fn_name: &str,
+        mut typed_args: Vec<TypedArg>,
+    ) -> Result<Vec<TypedArg>, CrocoError> {
         let mut first_arg = false;
 
         loop {
@@ -64,7 +220,7 @@ impl Parser {
                         &self.token_pos,
                         format!(
                             "expected a comma or a right parenthesis in {} function declaration",
-                            fn_name.name
+                            fn_name
                         ),
                     ))
                 }
@@ -86,38 +242,6 @@ impl Parser {
             });
         }
 
-        // Might allow weird parsing: does it matter ?
-        // fn bla()
-        // Void
-        // { ...
-        self.discard_newlines(iter);
-
-        // if the return type isn't specified the function is Void
-        let return_type = if let Separator(LeftCurlyBracket) = self.peek_token(iter) {
-            None
-        } else {
-            Some(self.parse_var_type(iter)?)
-        };
-
-        self.discard_newlines(iter);
-
-        self.expect_token(
-            iter,
-            Separator(LeftCurlyBracket),
-            "expected a left bracket after function declaration",
-        )?;
-
-        let fn_body = self.parse_block(iter, BlockScope::Function, false)?;
-
-        // get the namespaced name of the function
-        // let fn_name = identifier.get_namespaced_name();
-        let fn_decl = FunctionDecl {
-            args: typed_args,
-            return_type,
-        };
-
-        self.register_fn_decl(&fn_name.name, fn_decl)?;
-
-        Ok((fn_name.name, fn_body))
+        Ok(typed_args)
     }
 }
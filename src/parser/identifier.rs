@@ -1,12 +1,16 @@
 use std::collections::HashMap;
 
-use super::{ExprParsingType, Parser};
+use super::{
+    mutation::{ChainStep, MethodCall, Mutation},
+    ExprParsingType, Parser,
+};
 
 use crate::ast::AstNode;
 use crate::ast::{node::*, BackendNode};
 use crate::error::CrocoError;
 use crate::parser::ExprParsingType::*;
-use crate::token::{CodePos, OperatorEnum::*, SeparatorEnum::*, Token, Token::*};
+use crate::symbol_type::SymbolType;
+use crate::token::{CodePos, LiteralEnum, OperatorEnum::*, SeparatorEnum::*, Token, Token::*};
 
 impl Parser {
     /// Parses an identifier in the right AstNode given the next tokens as the context
@@ -25,17 +29,25 @@ impl Parser {
 
         // wether or not the expression is assignable
 
+        // wether or not the identifier itself is referenced, which isn't allowed for consts
+        let mut takes_ref = false;
+
+        // the value reached from a const or from self, which the methods called may modify
+        let mut receiver = None;
+
         // ref / deref as many times as needed
         loop {
             match self.peek_token(iter) {
                 // ref
                 Operator(BitwiseAnd) => {
                     chain_ref_nodes.push(Box::new(RefNode::new(self.token_pos.clone())));
+                    takes_ref = true;
                 }
 
                 // deref
                 Operator(Multiplicate) => {
-                    chain_ref_nodes.push(Box::new(DerefNode::new(self.token_pos.clone())))
+                    chain_ref_nodes.push(Box::new(DerefNode::new(self.token_pos.clone())));
+                    takes_ref = false;
                 }
 
                 _ => break,
@@ -47,11 +59,32 @@ impl Parser {
         // we can have either a literal or an identifier
         match self.next_token(iter) {
             Identifier(identifier) => {
+                let name = self.parse_item_name(iter, identifier)?;
+
+                if takes_ref && self.is_const(&name) {
+                    return Err(CrocoError::const_reference_error(&self.token_pos, &name));
+                }
+
+                if takes_ref {
+                    self.record_undeclared_mutation(&name, Mutation::Reference);
+                }
+
+                // self can be modified through the reference
+                if takes_ref && name == "self" {
+                    self.mark_self_mutated();
+                }
+
                 match self.peek_token(iter) {
                     // function call
                     Separator(LeftParenthesis) => {
                         self.next_token(iter);
-                        chain_nodes.push(self.parse_function_call(iter, identifier.name)?);
+                        chain_nodes.push(self.parse_function_call(iter, name)?);
+                    }
+
+                    // enum variant creation
+                    Separator(Dot) if self.enum_names.contains(&name) => {
+                        self.next_token(iter);
+                        chain_nodes.push(self.parse_enum_create(iter, name)?);
                     }
 
                     // struct instanciation
@@ -89,17 +122,22 @@ impl Parser {
                         }
 
                         return Ok(Box::new(StructCreateNode::new(
-                            identifier.name,
+                            name,
                             fields,
                             self.token_pos.clone(),
                         )));
                     }
 
                     // anything else
-                    _ => chain_nodes.push(Box::new(VarCallNode::new(
-                        identifier.name,
-                        self.token_pos.clone(),
-                    ))),
+                    _ => {
+                        receiver = self.method_receiver(&name);
+                        let var_type = self.variable_type(&name);
+                        chain_nodes.push(Box::new(VarCallNode::new_typed(
+                            name,
+                            var_type,
+                            self.token_pos.clone(),
+                        )))
+                    }
                 }
             }
 
@@ -111,6 +149,21 @@ impl Parser {
             // array literal
             Separator(LeftSquareBracket) => chain_nodes.push(self.parse_array(iter)?),
 
+            // expression in parenthesis or tuple literal
+            Separator(LeftParenthesis) => chain_nodes.push(self.parse_tuple(iter, parse_type)?),
+
+            // expression interpolated in a str, implicitly casted to a str
+            Separator(InterpolationStart) => {
+                let mut as_node = AsNode::new_implicit(SymbolType::Str, self.token_pos.clone());
+                as_node.add_child(self.parse_expr(iter, AllowStructDeclaration)?);
+                self.expect_token(
+                    iter,
+                    Separator(InterpolationEnd),
+                    "expected a closing brace after the interpolated expression",
+                )?;
+                chain_nodes.push(Box::new(as_node));
+            }
+
             _ => {
                 return Err(CrocoError::new(
                     &self.token_pos,
@@ -124,20 +177,44 @@ impl Parser {
             match self.peek_token(iter) {
                 // struct instanciation
 
-                // field or method call on a struct
+                // field or method call on a struct, or tuple element
                 Separator(Dot) => {
                     self.next_token(iter);
 
-                    let field = self
-                        .expect_identifier(iter, "expected a field or method name after the dot")?;
+                    let field_name = match self.next_token(iter) {
+                        Identifier(identifier) => identifier.name,
+                        // e.g pair.0
+                        Literal(LiteralEnum::Num(index)) => index.to_string(),
+                        _ => {
+                            return Err(CrocoError::new(
+                                &self.token_pos,
+                                "expected a field or method name after the dot",
+                            ))
+                        }
+                    };
 
                     // check if it's a method
                     if let Separator(LeftParenthesis) = self.peek_token(iter) {
                         self.next_token(iter);
-                        chain_nodes.push(self.parse_function_call(iter, field.name.clone())?);
+
+                        // the value returned by the method is a new value
+                        if let Some(receiver) = receiver.take() {
+                            self.method_calls.push(MethodCall {
+                                receiver,
+                                method_name: field_name.clone(),
+                                code_pos: self.token_pos.clone(),
+                            });
+                        }
+
+                        chain_nodes.push(self.parse_function_call(iter, field_name)?);
                     } else {
+                        if let Some(receiver) = &mut receiver {
+                            receiver.path.push(ChainStep::Field(field_name.clone()));
+                        }
+
                         chain_nodes.push(Box::new(DotFieldNode::new(
-                            field.name,
+                            field_name,
+                            false,
                             self.token_pos.clone(),
                         )));
                     }
@@ -149,6 +226,10 @@ impl Parser {
 
                     let index = self.parse_expr(iter, DenyStructDeclaration)?;
 
+                    if let Some(receiver) = &mut receiver {
+                        receiver.path.push(ChainStep::Index);
+                    }
+
                     self.expect_token(
                         iter,
                         Separator(RightSquareBracket),
@@ -158,6 +239,36 @@ impl Parser {
                     chain_nodes.push(Box::new(ArrayIndexNode::new(index, self.token_pos.clone())));
                 }
 
+                // result unwrapping, e.g read_file("a.txt")?
+                Separator(QuestionMark) => {
+                    self.next_token(iter);
+                    receiver = None;
+                    chain_nodes.push(Box::new(TryNode::new(self.token_pos.clone())));
+                }
+
+                // field access on an optional, e.g node?.next
+                Separator(QuestionDot) => {
+                    self.next_token(iter);
+
+                    let field = self.expect_identifier(iter, "expected a field name after ?.")?;
+
+                    if let Some(receiver) = &mut receiver {
+                        receiver.path.push(ChainStep::Field(field.name.clone()));
+                    }
+
+                    chain_nodes.push(Box::new(DotFieldNode::new(
+                        field.name,
+                        true,
+                        self.token_pos.clone(),
+                    )));
+                }
+
+                // calling a function value reached through an index, a field or a call
+                Separator(LeftParenthesis) => {
+                    self.next_token(iter);
+                    return Err(CrocoError::nested_call_error(&self.token_pos));
+                }
+
                 // he next token doesn't belong here
                 _ => break,
             }
@@ -0,0 +1,218 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use super::Parser;
+
+use crate::ast::{
+    node::{ImportNode, VoidNode},
+    BackendNode, BlockScope,
+};
+use crate::error::{CrocoError, CrocoErrorKind};
+use crate::lexer::Lexer;
+
+/// What the files importing a module need to know about it
+#[derive(Clone)]
+struct ModuleExports {
+    name: String,
+    pub_names: HashSet<String>,
+    trait_names: HashSet<String>,
+    enum_names: HashSet<String>,
+    /// The namespaced names of the pub consts
+    const_names: Vec<String>,
+    /// The struct methods which modify self, with the struct name and the method name
+    mutating_methods: HashSet<(String, String)>,
+}
+
+/// The modules of a program, shared by the parsers of all its files
+#[derive(Default)]
+pub struct ModuleCache {
+    /// The modules already parsed, by canonical path
+    parsed: HashMap<PathBuf, ModuleExports>,
+    /// The files being parsed, from the file run to the last one imported.
+    /// The canonical path is stored with the path shown in errors
+    parsing: Vec<(PathBuf, String)>,
+    /// The canonical path of the file of each module name, since a name is a namespace
+    names: HashMap<String, PathBuf>,
+}
+
+impl Parser {
+    /// Parses the module imported and brings its pub items in scope, under the module name.
+    /// e.g import "./geometry" makes geometry.area available
+    pub fn parse_import(
+        &mut self,
+        import_name: String,
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        let mut import_node = ImportNode::new(import_name.clone(), self.token_pos.clone());
+
+        // find the real module name
+        // e.g "./module/me/love" => "love"
+        let module_name = import_name.rsplit('/').next().unwrap().to_owned();
+
+        if self.modules.contains_key(&module_name) {
+            return Err(CrocoError::new(
+                &self.token_pos,
+                format!("a module named {} is already imported", module_name),
+            ));
+        }
+
+        // we have an absolute path e.g import "math"
+        // the builtin modules are imported by the backends
+        if !import_name.starts_with('.') {
+            self.modules.insert(module_name, None);
+            return Ok(Box::new(import_node));
+        }
+
+        // we have a relative path e.g import "./my_module"
+        // it is relative to the file importing it
+        let importer = Path::new(&*self.token_pos.file);
+        let relative_path: PathBuf = Path::new(&format!("{}.croco", import_name))
+            .components()
+            .filter(|component| *component != Component::CurDir)
+            .collect();
+
+        let file_path = importer
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .join(relative_path);
+
+        let canonical_path = file_path.canonicalize().map_err(|_| {
+            CrocoError::new(
+                &self.token_pos,
+                format!("cannot find the file {}", file_path.display()),
+            )
+        })?;
+
+        {
+            let mut cache = self.module_cache.borrow_mut();
+
+            // the file run is the first one being parsed
+            if cache.parsing.is_empty() {
+                if let Ok(importer_path) = importer.canonicalize() {
+                    cache
+                        .parsing
+                        .push((importer_path, importer.display().to_string()));
+                }
+            }
+
+            if let Some(start) = cache
+                .parsing
+                .iter()
+                .position(|(path, _)| *path == canonical_path)
+            {
+                let mut cycle: Vec<String> = cache.parsing[start..]
+                    .iter()
+                    .map(|(_, shown_path)| shown_path.clone())
+                    .collect();
+                cycle.push(file_path.display().to_string());
+
+                return Err(CrocoError::import_cycle_error(&self.token_pos, &cycle));
+            }
+
+            // the module has already been run by the first file importing it
+            if let Some(exports) = cache.parsed.get(&canonical_path).cloned() {
+                drop(cache);
+                self.use_module(exports);
+                return Ok(Box::new(VoidNode::new()));
+            }
+
+            match cache.names.get(&module_name) {
+                Some(path) if *path != canonical_path => {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        format!(
+                            "another file of the program is already imported as the module {}",
+                            module_name
+                        ),
+                    ))
+                }
+                _ => (),
+            }
+
+            cache
+                .names
+                .insert(module_name.clone(), canonical_path.clone());
+            cache
+                .parsing
+                .push((canonical_path.clone(), file_path.display().to_string()));
+        }
+
+        let file_contents = fs::read_to_string(&file_path).map_err(|_| {
+            CrocoError::new(
+                &self.token_pos,
+                format!("cannot read the file {}", file_path.display()),
+            )
+        })?;
+
+        // everything declared at the top level of the module is namespaced with the module name
+        let mut lexer = Lexer::new();
+        lexer.set_file(&file_path.to_string_lossy());
+        lexer.set_namespace(module_name.clone());
+
+        let tokens = lexer.process(&file_contents).map_err(|mut e| {
+            e.set_kind_if_unknown(CrocoErrorKind::Syntax);
+            e
+        })?;
+
+        // the module is a block which doesn't introduce a new scope,
+        // so that its globals are visible to its functions
+        let mut parser = Parser::new();
+        parser.module_cache = self.module_cache.clone();
+        parser.set_scope(BlockScope::Keep);
+        import_node.bottom = Some(parser.process(tokens)?);
+
+        // the declarations of the module are needed by the backends, even the private ones
+        for (fn_name, fn_decl) in parser.take_fn_decls() {
+            self.register_fn_decl(&fn_name, fn_decl)?;
+        }
+
+        for (struct_name, struct_decl) in parser.take_struct_decls() {
+            self.register_struct_decl(&struct_name, struct_decl)?;
+        }
+
+        for (trait_name, trait_decl) in parser.take_trait_decls() {
+            self.register_trait_decl(&trait_name, trait_decl)?;
+        }
+
+        for (enum_name, enum_decl) in parser.take_enum_decls() {
+            self.register_enum_decl(&enum_name, enum_decl)?;
+        }
+
+        // the pub consts of the module can't be mutated either
+        let const_names = parser
+            .pub_names
+            .iter()
+            .map(|pub_name| format!("{}.{}", module_name, pub_name))
+            .filter(|namespaced_name| parser.is_const(namespaced_name))
+            .collect();
+
+        let exports = ModuleExports {
+            name: module_name,
+            pub_names: parser.pub_names,
+            trait_names: parser.trait_names,
+            enum_names: parser.enum_names,
+            const_names,
+            mutating_methods: parser.mutating_methods,
+        };
+
+        let mut cache = self.module_cache.borrow_mut();
+        cache.parsing.pop();
+        cache.parsed.insert(canonical_path, exports.clone());
+        drop(cache);
+
+        self.use_module(exports);
+        Ok(Box::new(import_node))
+    }
+
+    /// Brings the pub items of a module in scope
+    fn use_module(&mut self, exports: ModuleExports) {
+        for const_name in &exports.const_names {
+            self.declare_variable(const_name, true, None);
+        }
+
+        self.mutating_methods.extend(exports.mutating_methods);
+        self.trait_names.extend(exports.trait_names);
+        self.enum_names.extend(exports.enum_names);
+        self.modules.insert(exports.name, Some(exports.pub_names));
+    }
+}
@@ -0,0 +1,289 @@
+use super::{ExprParsingType::*, Parser};
+
+use crate::ast::{
+    node::{MatchArm, MatchNode, MatchPattern},
+    BackendNode, BlockScope,
+};
+use crate::error::CrocoError;
+use crate::symbol_type::SymbolType;
+use crate::token::{
+    literal_eq, CodePos, LiteralEnum, OperatorEnum::*, SeparatorEnum::*, Token, Token::*,
+};
+
+impl Parser {
+    /// Parses a match statement or expression.
+    /// e.g match a { 1 | 2 => "small" _ => "big" }
+    /// warning: it does not consume the match keyword
+    pub fn parse_match(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+        is_expr: bool,
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        let code_pos = self.token_pos.clone();
+        let bottom = self.parse_expr(iter, DenyStructDeclaration)?;
+
+        self.expect_token(
+            iter,
+            Separator(LeftCurlyBracket),
+            "expected a left bracket after the matched value",
+        )?;
+
+        let mut arms: Vec<MatchArm> = Vec::new();
+
+        // all the patterns and the arms values must be of the same type
+        let mut pattern_literal: Option<LiteralEnum> = None;
+        let mut pattern_enum: Option<String> = None;
+        let mut arms_type: Option<SymbolType> = None;
+        let mut has_wildcard = false;
+
+        loop {
+            self.discard_newlines(iter);
+
+            if let Separator(RightCurlyBracket) = self.peek_token(iter) {
+                self.next_token(iter);
+                break;
+            }
+
+            let mut patterns = Vec::new();
+
+            // alternatives are separated by pipes, e.g 1 | 2 | 3
+            loop {
+                let pattern = self.parse_match_pattern(iter)?;
+
+                match &pattern {
+                    MatchPattern::Literal(literal) | MatchPattern::Range { start: literal, .. } => {
+                        match &pattern_literal {
+                            Some(first) if !literal_eq(first, literal) => {
+                                return Err(CrocoError::new(
+                                    &self.token_pos,
+                                    "all the patterns must be of the same type",
+                                ))
+                            }
+                            Some(_) => (),
+                            None => pattern_literal = Some(literal.clone()),
+                        }
+                    }
+                    MatchPattern::Variant { enum_type, .. } => match &pattern_enum {
+                        Some(first) if first != enum_type => {
+                            return Err(CrocoError::new(
+                                &self.token_pos,
+                                "all the patterns must be of the same type",
+                            ))
+                        }
+                        Some(_) => (),
+                        None => pattern_enum = Some(enum_type.clone()),
+                    },
+                    MatchPattern::Wildcard => has_wildcard = true,
+                }
+
+                if pattern_literal.is_some() && pattern_enum.is_some() {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        "all the patterns must be of the same type",
+                    ));
+                }
+
+                patterns.push(pattern);
+
+                if let Operator(BitwiseOr) = self.peek_token(iter) {
+                    self.next_token(iter);
+                } else {
+                    break;
+                }
+            }
+
+            // with alternatives we can't know which values the variables would hold
+            let has_bindings = patterns.iter().any(|pattern| {
+                matches!(pattern, MatchPattern::Variant { bindings, .. } if bindings.iter().any(Option::is_some))
+            });
+
+            if patterns.len() > 1 && has_bindings {
+                return Err(CrocoError::new(
+                    &self.token_pos,
+                    "variables can't be bound in an arm with several patterns",
+                ));
+            }
+
+            self.expect_token(
+                iter,
+                Separator(FatArrow),
+                "expected => after the match pattern",
+            )?;
+
+            // the bound variables are only visible in the arm body
+            self.variables.add_scope();
+
+            for pattern in &patterns {
+                if let MatchPattern::Variant { bindings, .. } = pattern {
+                    for binding in bindings.iter().flatten() {
+                        self.declare_variable(binding, false, None);
+                    }
+                }
+            }
+
+            let body = match self.peek_token(iter) {
+                Separator(LeftCurlyBracket) => {
+                    self.next_token(iter);
+                    self.parse_block(iter, BlockScope::New, false)?
+                }
+                _ => self.parse_expr(iter, AllowStructDeclaration)?,
+            };
+
+            self.variables.drop_scope();
+
+            if let Some(body_type) = body.get_static_type() {
+                match &arms_type {
+                    Some(arms_type) if *arms_type != body_type => {
+                        return Err(CrocoError::match_arms_type_error(
+                            &self.token_pos,
+                            arms_type,
+                            &body_type,
+                        ))
+                    }
+                    Some(_) => (),
+                    None => arms_type = Some(body_type),
+                }
+            }
+
+            arms.push(MatchArm { patterns, body });
+
+            // arms can optionally be separated by commas
+            if let Separator(Comma) = self.peek_token(iter) {
+                self.next_token(iter);
+            }
+        }
+
+        // a match used as a value must always produce one.
+        // matches on enums are checked by the backends, once the enum declarations are known
+        if is_expr && !has_wildcard && pattern_enum.is_none() {
+            return Err(CrocoError::new(
+                &self.token_pos,
+                "a match used as a value must handle all the cases",
+            )
+            .hint("add a _ arm"));
+        }
+
+        Ok(Box::new(MatchNode::new(bottom, arms, code_pos)))
+    }
+
+    /// Parses a pattern of a match arm, e.g 3, 0..=5, Shape.Circle(r) or _
+    fn parse_match_pattern(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+    ) -> Result<MatchPattern, CrocoError> {
+        if let Separator(Underscore) = self.peek_token(iter) {
+            self.next_token(iter);
+            return Ok(MatchPattern::Wildcard);
+        }
+
+        if let Identifier(identifier) = self.peek_token(iter) {
+            if self.is_module(&identifier.name)
+                || self
+                    .enum_names
+                    .contains(&self.resolve_item_name(&identifier))
+            {
+                return self.parse_variant_pattern(iter);
+            }
+        }
+
+        let start = self.parse_pattern_literal(iter)?;
+
+        match self.peek_token(iter) {
+            Separator(DoubleDot) | Separator(DoubleDotEquals) => {
+                let inclusive = self.next_token(iter) == Separator(DoubleDotEquals);
+                let end = self.parse_pattern_literal(iter)?;
+
+                if !start.is_num_fnum() || !literal_eq(&start, &end) {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        "range bounds must be numbers of the same type",
+                    ));
+                }
+
+                Ok(MatchPattern::Range {
+                    start,
+                    end,
+                    inclusive,
+                })
+            }
+
+            _ => Ok(MatchPattern::Literal(start)),
+        }
+    }
+
+    /// Parses an enum variant in a match pattern, e.g Shape.Rect(w, _)
+    fn parse_variant_pattern(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+    ) -> Result<MatchPattern, CrocoError> {
+        let enum_type =
+            self.expect_identifier(iter, "expected an enum name in the match pattern")?;
+        let enum_type = self.parse_item_name(iter, enum_type)?;
+
+        self.expect_token(iter, Separator(Dot), "expected a dot after the enum name")?;
+
+        let variant = self
+            .expect_identifier(iter, "expected a variant name after the enum name")?
+            .name;
+
+        let mut bindings = Vec::new();
+
+        if let Separator(LeftParenthesis) = self.peek_token(iter) {
+            self.next_token(iter);
+
+            loop {
+                match self.next_token(iter) {
+                    Identifier(binding) => bindings.push(Some(binding.name)),
+                    Separator(Underscore) => bindings.push(None),
+                    _ => {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            "expected a variable name or _ in the variant pattern",
+                        ))
+                    }
+                }
+
+                match self.next_token(iter) {
+                    Separator(Comma) => (),
+                    Separator(RightParenthesis) => break,
+                    _ => {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            "expected a comma or a right parenthesis in the variant pattern",
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(MatchPattern::Variant {
+            enum_type,
+            variant,
+            bindings,
+        })
+    }
+
+    /// Parses a literal in a match pattern, which can be a negative number
+    fn parse_pattern_literal(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+    ) -> Result<LiteralEnum, CrocoError> {
+        match self.next_token(iter) {
+            Literal(literal) => Ok(literal),
+
+            Operator(Minus) => match self.next_token(iter) {
+                Literal(LiteralEnum::Num(n)) => Ok(LiteralEnum::Num(-n)),
+                Literal(LiteralEnum::Fnum(n)) => Ok(LiteralEnum::Fnum(-n)),
+                _ => Err(CrocoError::new(
+                    &self.token_pos,
+                    "expected a number after the minus sign",
+                )),
+            },
+
+            _ => Err(CrocoError::new(
+                &self.token_pos,
+                "expected a literal or _ in the match pattern",
+            )),
+        }
+    }
+}
@@ -1,25 +1,36 @@
 mod array;
 mod block;
+mod enum_decl;
 mod expr;
 mod function_call;
 mod function_decl;
 mod identifier;
+mod import;
 mod iter;
+mod match_expr;
+mod mutation;
 mod node;
+mod optional;
+mod result;
+mod trait_decl;
+mod tuple;
 mod utils;
 mod var_type;
 
 use crate::error::CrocoError;
 use crate::{
     ast::*,
-    symbol::{FunctionDecl, StructDecl},
+    symbol::{EnumDecl, FunctionDecl, StructDecl, SymTable, TraitDecl},
 };
 use crate::{
     symbol_type::SymbolType,
-    token::{CodePos, Token},
+    token::{CodePos, KeywordEnum, SeparatorEnum, Token},
 };
+use import::ModuleCache;
+use mutation::{MethodCall, UndeclaredMutation};
 use std::{
-    collections::{hash_map::Drain, HashMap},
+    cell::RefCell,
+    collections::{hash_map::Drain, HashMap, HashSet},
     rc::Rc,
 };
 
@@ -36,6 +47,37 @@ pub struct Parser {
     fn_decls: HashMap<String, FunctionDecl>,
     /// All the struct declarations
     struct_decls: HashMap<String, StructDecl>,
+    /// All the trait declarations
+    trait_decls: HashMap<String, TraitDecl>,
+    /// All the enum declarations
+    enum_decls: HashMap<String, EnumDecl>,
+    /// The names of all the traits of the file, so that the types can be resolved before the traits are parsed
+    trait_names: HashSet<String>,
+    /// The names of all the enums of the file, for the same reason
+    enum_names: HashSet<String>,
+    /// The names of the items declared at the top level of the file, which are namespaced in a module
+    top_level_names: HashSet<String>,
+    /// The names of the items declared with pub, which can be used by the files importing the module
+    pub_names: HashSet<String>,
+    /// The modules imported by the file, bound to their pub items.
+    /// The items of the builtin modules are only known by the backends
+    modules: HashMap<String, Option<HashSet<String>>>,
+    /// The type parameters of the function signature being parsed
+    generic_names: Vec<String>,
+    /// All the test blocks
+    tests: Vec<TestBlock>,
+    /// The variables visible from the code being parsed
+    variables: SymTable<ParsedVariable>,
+    /// The modules of the program, shared with the parsers of the files imported
+    module_cache: Rc<RefCell<ModuleCache>>,
+    /// The struct method being parsed, with the struct name and the method name
+    current_method: Option<(String, String)>,
+    /// The struct methods which modify self, with the struct name and the method name
+    mutating_methods: HashSet<(String, String)>,
+    /// The methods called on consts or on self, checked once the whole file is parsed
+    method_calls: Vec<MethodCall>,
+    /// The assignments and references to variables which aren't declared yet, checked once the whole file is parsed
+    undeclared_mutations: Vec<UndeclaredMutation>,
 }
 
 impl Parser {
@@ -53,6 +95,21 @@ impl Parser {
             next_token: Token::Discard,
             fn_decls: HashMap::new(),
             struct_decls: HashMap::new(),
+            trait_decls: HashMap::new(),
+            enum_decls: HashMap::new(),
+            trait_names: HashSet::new(),
+            enum_names: HashSet::new(),
+            top_level_names: HashSet::new(),
+            pub_names: HashSet::new(),
+            modules: HashMap::new(),
+            generic_names: Vec::new(),
+            tests: Vec::new(),
+            variables: SymTable::new(),
+            module_cache: Rc::new(RefCell::new(ModuleCache::default())),
+            current_method: None,
+            mutating_methods: HashSet::new(),
+            method_calls: Vec::new(),
+            undeclared_mutations: Vec::new(),
         }
     }
 
@@ -66,9 +123,57 @@ impl Parser {
         &mut self,
         tokens: Vec<(Token, CodePos)>,
     ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        // a type annotation can refer to a trait or an enum declared further in the file,
+        // and an item of a module can be used before its declaration
+        let mut depth = 0;
+
+        for (i, (token, _)) in tokens.iter().enumerate() {
+            let keyword = match token {
+                Token::Separator(SeparatorEnum::LeftCurlyBracket) => {
+                    depth += 1;
+                    continue;
+                }
+                Token::Separator(SeparatorEnum::RightCurlyBracket) => {
+                    depth -= 1;
+                    continue;
+                }
+                Token::Keyword(keyword) if depth == 0 => keyword,
+                _ => continue,
+            };
+
+            let identifier = match tokens.get(i + 1) {
+                Some((Token::Identifier(identifier), _)) => identifier,
+                _ => continue,
+            };
+
+            match keyword {
+                KeywordEnum::Trait => {
+                    self.trait_names
+                        .insert(identifier.clone().get_namespaced_name());
+                }
+                KeywordEnum::Enum => {
+                    self.enum_names
+                        .insert(identifier.clone().get_namespaced_name());
+                }
+                KeywordEnum::Function
+                | KeywordEnum::Struct
+                | KeywordEnum::Let
+                | KeywordEnum::Const => (),
+                _ => continue,
+            }
+
+            self.top_level_names.insert(identifier.name.clone());
+
+            if i > 0 && tokens[i - 1].0 == Token::Keyword(KeywordEnum::Pub) {
+                self.pub_names.insert(identifier.name.clone());
+            }
+        }
+
         // iterator which returns a movable and peekable token iterator
         let mut iter = tokens.into_iter().peekable();
         let root = self.parse_block(&mut iter, self.scope.clone(), true)?;
+        self.check_method_calls()?;
+        self.check_undeclared_mutations()?;
         Ok(root)
     }
 
@@ -88,6 +193,20 @@ impl Parser {
             ));
         }
 
+        if self.trait_decls.contains_key(decl_name) {
+            return Err(CrocoError::new(
+                &self.token_pos,
+                "trait with the same name already declared",
+            ));
+        }
+
+        if self.enum_decls.contains_key(decl_name) {
+            return Err(CrocoError::new(
+                &self.token_pos,
+                "enum with the same name already declared",
+            ));
+        }
+
         Ok(())
     }
 
@@ -112,6 +231,26 @@ impl Parser {
         Ok(())
     }
 
+    pub fn register_trait_decl(
+        &mut self,
+        trait_name: &str,
+        trait_decl: TraitDecl,
+    ) -> Result<(), CrocoError> {
+        self.check_decls(trait_name)?;
+        self.trait_decls.insert(trait_name.to_owned(), trait_decl);
+        Ok(())
+    }
+
+    pub fn register_enum_decl(
+        &mut self,
+        enum_name: &str,
+        enum_decl: EnumDecl,
+    ) -> Result<(), CrocoError> {
+        self.check_decls(enum_name)?;
+        self.enum_decls.insert(enum_name.to_owned(), enum_decl);
+        Ok(())
+    }
+
     /// Returns all the function declarations found by the parser
     pub fn take_fn_decls(&mut self) -> Drain<String, FunctionDecl> {
         self.fn_decls.drain()
@@ -121,6 +260,40 @@ impl Parser {
     pub fn take_struct_decls(&mut self) -> Drain<String, StructDecl> {
         self.struct_decls.drain()
     }
+
+    /// Returns all the trait declarations found by the parser
+    pub fn take_trait_decls(&mut self) -> Drain<String, TraitDecl> {
+        self.trait_decls.drain()
+    }
+
+    /// Returns all the enum declarations found by the parser
+    pub fn take_enum_decls(&mut self) -> Drain<String, EnumDecl> {
+        self.enum_decls.drain()
+    }
+
+    pub fn register_test(&mut self, test: TestBlock) -> Result<(), CrocoError> {
+        if self.tests.iter().any(|t| t.name == test.name) {
+            return Err(CrocoError::new(
+                &self.token_pos,
+                "test with the same name already declared",
+            ));
+        }
+
+        self.tests.push(test);
+        Ok(())
+    }
+
+    /// Returns all the test blocks found by the parser, in declaration order
+    pub fn take_tests(&mut self) -> Vec<TestBlock> {
+        std::mem::take(&mut self.tests)
+    }
+}
+
+/// A test block, e.g test "addition" { ... }
+#[derive(Clone)]
+pub struct TestBlock {
+    pub name: String,
+    pub body: Box<dyn BackendNode>,
 }
 
 /// An argument type and its name
@@ -129,6 +302,14 @@ pub struct TypedArg {
     pub arg_name: String,
     pub arg_type: SymbolType,
 }
+
+/// What the parser knows about a variable
+#[derive(Clone, Debug)]
+struct ParsedVariable {
+    is_const: bool,
+    /// The type of the variable, if it is known at parse time
+    var_type: Option<SymbolType>,
+}
 /// defines if a struct declaration can be present in an expression
 #[derive(PartialEq, Copy, Clone)]
 pub enum ExprParsingType {
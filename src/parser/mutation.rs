@@ -0,0 +1,227 @@
+use super::Parser;
+
+use crate::error::CrocoError;
+use crate::symbol_type::SymbolType;
+use crate::token::CodePos;
+
+/// The builtin methods of arrays and maps which modify the value they're called on
+const MUTATING_BUILTIN_METHODS: [&str; 5] = ["insert", "pop", "push", "remove", "reverse"];
+
+/// The variable whose value may be modified by a method call
+#[derive(Clone, Debug)]
+pub enum MutationRoot {
+    /// A const, which can't be modified
+    Const(String),
+    /// The self of a struct method, with the struct name and the method name
+    SelfOf(String, String),
+    /// A variable which isn't declared yet, which may be a global const declared further in the file
+    Undeclared(String),
+}
+
+/// What lies between the root variable and the value a method is called on
+#[derive(Clone, Debug)]
+pub enum ChainStep {
+    Field(String),
+    Index,
+}
+
+/// The value a method is called on, reached from a const or from self
+#[derive(Clone, Debug)]
+pub struct MethodReceiver {
+    pub root: MutationRoot,
+    /// The type of the root variable, if it is known at parse time
+    pub root_type: Option<SymbolType>,
+    pub path: Vec<ChainStep>,
+}
+
+/// How a variable is modified
+#[derive(Clone, Debug)]
+pub enum Mutation {
+    Assignment,
+    Reference,
+}
+
+/// An assignment or a reference to a variable which isn't declared yet.
+/// Whether it's a const is only known once the whole file is parsed
+#[derive(Clone, Debug)]
+pub struct UndeclaredMutation {
+    pub var_name: String,
+    pub mutation: Mutation,
+    pub code_pos: CodePos,
+}
+
+/// A method call which may modify its receiver.
+/// Whether it does is only known once all the struct methods are parsed
+#[derive(Clone, Debug)]
+pub struct MethodCall {
+    pub receiver: MethodReceiver,
+    pub method_name: String,
+    pub code_pos: CodePos,
+}
+
+impl Parser {
+    /// Returns the receiver of the methods called on this variable,
+    /// if the variable is a const or the self of the method being parsed
+    pub fn method_receiver(&self, var_name: &str) -> Option<MethodReceiver> {
+        let root = if self.is_const(var_name) {
+            MutationRoot::Const(var_name.to_owned())
+        } else if var_name == "self" {
+            let (struct_name, method_name) = self.current_method.clone()?;
+            MutationRoot::SelfOf(struct_name, method_name)
+        } else if self.variables.get_symbol(var_name).is_err() {
+            MutationRoot::Undeclared(var_name.to_owned())
+        } else {
+            return None;
+        };
+
+        Some(MethodReceiver {
+            root,
+            root_type: self.variable_type(var_name),
+            path: Vec::new(),
+        })
+    }
+
+    /// Records an assignment or a reference to a variable, if it isn't declared yet
+    pub fn record_undeclared_mutation(&mut self, var_name: &str, mutation: Mutation) {
+        if self.variables.get_symbol(var_name).is_err() {
+            self.undeclared_mutations.push(UndeclaredMutation {
+                var_name: var_name.to_owned(),
+                mutation,
+                code_pos: self.token_pos.clone(),
+            });
+        }
+    }
+
+    /// Records that the struct method being parsed modifies self
+    pub fn mark_self_mutated(&mut self) {
+        if let Some(method) = &self.current_method {
+            self.mutating_methods.insert(method.clone());
+        }
+    }
+
+    /// Checks that no method modifying its receiver is called on a const.
+    /// The methods calling a method which modifies self are found first,
+    /// since a method can be called before it is declared
+    pub fn check_method_calls(&mut self) -> Result<(), CrocoError> {
+        loop {
+            let newly_mutating: Vec<(String, String)> = self
+                .method_calls
+                .iter()
+                .filter_map(|call| match &call.receiver.root {
+                    MutationRoot::SelfOf(struct_name, method_name) => {
+                        Some((call, (struct_name.clone(), method_name.clone())))
+                    }
+                    MutationRoot::Const(_) | MutationRoot::Undeclared(_) => None,
+                })
+                .filter(|(call, method)| {
+                    !self.mutating_methods.contains(method) && self.is_mutating_call(call)
+                })
+                .map(|(_, method)| method)
+                .collect();
+
+            if newly_mutating.is_empty() {
+                break;
+            }
+
+            self.mutating_methods.extend(newly_mutating);
+        }
+
+        for call in &self.method_calls {
+            let var_name = match &call.receiver.root {
+                MutationRoot::Const(var_name) => var_name,
+                // only the global variables are visible once the whole file is parsed
+                MutationRoot::Undeclared(var_name) if self.is_const(var_name) => var_name,
+                _ => continue,
+            };
+
+            if self.is_mutating_call(call) {
+                return Err(CrocoError::const_method_call_error(
+                    &call.code_pos,
+                    var_name,
+                    &call.method_name,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the variables assigned or referenced before their declaration aren't global consts
+    pub fn check_undeclared_mutations(&self) -> Result<(), CrocoError> {
+        for mutation in &self.undeclared_mutations {
+            // only the global variables are visible once the whole file is parsed
+            if !self.is_const(&mutation.var_name) {
+                continue;
+            }
+
+            return Err(match mutation.mutation {
+                Mutation::Assignment => {
+                    CrocoError::const_assignment_error(&mutation.code_pos, &mutation.var_name)
+                }
+                Mutation::Reference => {
+                    CrocoError::const_reference_error(&mutation.code_pos, &mutation.var_name)
+                }
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the method called modifies its receiver
+    fn is_mutating_call(&self, call: &MethodCall) -> bool {
+        let is_mutating_builtin = MUTATING_BUILTIN_METHODS.contains(&call.method_name.as_str());
+
+        match self.receiver_type(&call.receiver) {
+            Some(SymbolType::Struct(struct_name)) => self
+                .mutating_methods
+                .contains(&(struct_name, call.method_name.clone())),
+            Some(SymbolType::Array(_)) | Some(SymbolType::Map(_, _)) => is_mutating_builtin,
+
+            // without the type, any method with this name modifying its receiver is rejected
+            None => {
+                is_mutating_builtin
+                    || self
+                        .mutating_methods
+                        .iter()
+                        .any(|(_, method_name)| *method_name == call.method_name)
+            }
+
+            Some(_) => false,
+        }
+    }
+
+    /// Returns the type of the value a method is called on, if it is known at parse time
+    fn receiver_type(&self, receiver: &MethodReceiver) -> Option<SymbolType> {
+        let mut receiver_type = strip_indirections(receiver.root_type.clone()?);
+
+        for step in &receiver.path {
+            receiver_type = match (step, receiver_type) {
+                (ChainStep::Field(field_name), SymbolType::Struct(struct_name)) => self
+                    .struct_decls
+                    .get(&struct_name)?
+                    .fields
+                    .get(field_name)?
+                    .clone(),
+                (ChainStep::Field(field_name), SymbolType::Tuple(types)) => {
+                    types.get(field_name.parse::<usize>().ok()?)?.clone()
+                }
+                (ChainStep::Index, SymbolType::Array(el_type)) => *el_type,
+                (ChainStep::Index, SymbolType::Map(_, value_type)) => *value_type,
+                _ => return None,
+            };
+
+            receiver_type = strip_indirections(receiver_type);
+        }
+
+        Some(receiver_type)
+    }
+}
+
+/// Returns the type behind the references and optionals, which are reached automatically
+fn strip_indirections(mut symbol_type: SymbolType) -> SymbolType {
+    while let SymbolType::Ref(inner) | SymbolType::Optional(inner) = symbol_type {
+        symbol_type = *inner;
+    }
+
+    symbol_type
+}
@@ -31,6 +31,13 @@ impl Parser {
             Operator(Multiplicate) => Ok(Box::new(MultiplicateNode::new(code_pos))),
             Operator(Divide) => Ok(Box::new(DivideNode::new(code_pos))),
             Operator(Power) => Ok(Box::new(PowerNode::new(code_pos))),
+            Operator(Modulo) => Ok(Box::new(ModuloNode::new(code_pos))),
+            Operator(BitwiseAnd) => Ok(Box::new(BitwiseNode::new(BitwiseAnd, code_pos))),
+            Operator(BitwiseOr) => Ok(Box::new(BitwiseNode::new(BitwiseOr, code_pos))),
+            Operator(BitwiseXor) => Ok(Box::new(BitwiseNode::new(BitwiseXor, code_pos))),
+            Operator(ShiftLeft) => Ok(Box::new(BitwiseNode::new(ShiftLeft, code_pos))),
+            Operator(ShiftRight) => Ok(Box::new(BitwiseNode::new(ShiftRight, code_pos))),
+            Operator(BitwiseNot) => Ok(Box::new(BitwiseNotNode::new(code_pos))),
             Operator(Equals) => Ok(Box::new(CompareNode::new(Equals, code_pos))),
             Operator(NotEquals) => Ok(Box::new(CompareNode::new(NotEquals, code_pos))),
             Operator(GreaterOrEqual) => Ok(Box::new(CompareNode::new(GreaterOrEqual, code_pos))),
@@ -38,6 +45,7 @@ impl Parser {
             Operator(LowerOrEqual) => Ok(Box::new(CompareNode::new(LowerOrEqual, code_pos))),
             Operator(LowerThan) => Ok(Box::new(CompareNode::new(LowerThan, code_pos))),
             Operator(Bang) => Ok(Box::new(NotNode::new(code_pos))),
+            Operator(Coalesce) => Ok(Box::new(CoalesceNode::new(code_pos))),
             _ => Err(CrocoError::new(
                 &self.token_pos,
                 &format!("can't evaluate token in expression: {:?}", token),
@@ -0,0 +1,51 @@
+use super::{ExprParsingType::*, Parser};
+
+use crate::ast::{node::IfLetNode, BackendNode, BlockScope};
+use crate::error::CrocoError;
+use crate::token::{CodePos, KeywordEnum::*, OperatorEnum::*, SeparatorEnum::*, Token, Token::*};
+
+impl Parser {
+    /// Parses the unwrapping of an optional, e.g if let node = list.head { ... } else { ... }
+    /// warning: it does not consume the if and let keywords
+    pub fn parse_if_let(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        let code_pos = self.token_pos.clone();
+
+        let var_name = self
+            .expect_identifier(iter, "expected a variable name after if let")?
+            .name;
+
+        self.expect_token(iter, Operator(Assign), "expected = after the variable name")?;
+
+        let value = self.parse_expr(iter, DenyStructDeclaration)?;
+
+        self.expect_token(
+            iter,
+            Separator(LeftCurlyBracket),
+            "expected left bracket after if let expression",
+        )?;
+
+        let body =
+            self.parse_block_with_bindings(iter, BlockScope::New, &[(var_name.as_str(), None)])?;
+
+        let else_body = if let Keyword(Else) = self.peek_token(iter) {
+            self.next_token(iter);
+
+            self.expect_token(
+                iter,
+                Separator(LeftCurlyBracket),
+                "expected left bracket after else expression",
+            )?;
+
+            Some(self.parse_block(iter, BlockScope::New, false)?)
+        } else {
+            None
+        };
+
+        Ok(Box::new(IfLetNode::new(
+            var_name, value, body, else_body, code_pos,
+        )))
+    }
+}
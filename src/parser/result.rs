@@ -0,0 +1,92 @@
+use super::{ExprParsingType, Parser};
+
+use crate::ast::{
+    node::{CatchHandler, CatchNode, ErrorNode},
+    BackendNode, BlockScope,
+};
+use crate::error::CrocoError;
+use crate::parser::ExprParsingType::*;
+use crate::symbol_type::SymbolType;
+use crate::token::{CodePos, KeywordEnum::*, SeparatorEnum::*, Token, Token::*};
+
+impl Parser {
+    /// Parses an error construction, e.g error("file not found")
+    /// warning: it does not consume the error keyword
+    pub fn parse_error(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        let code_pos = self.token_pos.clone();
+
+        self.expect_token(
+            iter,
+            Separator(LeftParenthesis),
+            "expected a left parenthesis after error",
+        )?;
+
+        let message = self.parse_expr(iter, DenyStructDeclaration)?;
+
+        self.expect_token(
+            iter,
+            Separator(RightParenthesis),
+            "expected a right parenthesis after the error message",
+        )?;
+
+        Ok(Box::new(ErrorNode::new(message, code_pos)))
+    }
+
+    /// Parses the handling of an error after an expression, if there's any.
+    /// e.g read_file("a.txt") else "" or read_file("a.txt") catch e { ... }
+    /// is_value is true if the expression is used as a value, and not as a statement
+    pub fn parse_catch(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+        expr: Box<dyn BackendNode>,
+        parse_type: ExprParsingType,
+        is_value: bool,
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        let handler = match self.peek_token(iter) {
+            Keyword(Else) => {
+                self.next_token(iter);
+                CatchHandler::Fallback(self.parse_expr(iter, parse_type)?)
+            }
+
+            Keyword(Catch) => {
+                self.next_token(iter);
+                let var_name = self
+                    .expect_identifier(iter, "expected a variable name after catch")?
+                    .name;
+
+                self.expect_token(
+                    iter,
+                    Separator(LeftCurlyBracket),
+                    "expected a left bracket after the error variable",
+                )?;
+
+                let body = self.parse_block_with_bindings(
+                    iter,
+                    BlockScope::New,
+                    &[(var_name.as_str(), Some(SymbolType::Str))],
+                )?;
+
+                // the catch block has no value to give in place of the error
+                if is_value && !body.exits_early() {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        "a catch block used as a value must end with a return, a break or a continue",
+                    ));
+                }
+
+                CatchHandler::Block { var_name, body }
+            }
+
+            _ => return Ok(expr),
+        };
+
+        Ok(Box::new(CatchNode::new(
+            expr,
+            handler,
+            self.token_pos.clone(),
+        )))
+    }
+}
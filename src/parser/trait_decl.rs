@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use super::Parser;
+use crate::error::CrocoError;
+use crate::symbol::{FunctionDecl, TraitDecl};
+use crate::token::{CodePos, KeywordEnum::*, SeparatorEnum::*, Token, Token::*};
+
+impl Parser {
+    /// Parses a trait declaration and registers it.
+    /// e.g trait Speak { fn say() str }
+    /// warning: does not consume the trait keyword
+    pub fn parse_trait_decl(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+    ) -> Result<(), CrocoError> {
+        let trait_name =
+            self.expect_identifier(iter, "expected the trait name after trait declaration")?;
+
+        self.expect_token(
+            iter,
+            Separator(LeftCurlyBracket),
+            "expected a left bracket after the trait name",
+        )?;
+
+        let mut methods: BTreeMap<String, FunctionDecl> = BTreeMap::new();
+
+        loop {
+            self.discard_newlines(iter);
+
+            match self.next_token(iter) {
+                Keyword(Function) => {
+                    let method_name =
+                        self.expect_identifier(iter, "expected the method name in the trait")?;
+
+                    self.expect_token(
+                        iter,
+                        Separator(LeftParenthesis),
+                        "expected a left parenthensis after the method name",
+                    )?;
+
+                    let args = self.parse_function_args(iter, &method_name.name, Vec::new())?;
+
+                    // if the return type isn't specified the method is Void
+                    let return_type = match self.peek_token(iter) {
+                        Separator(NewLine) | Separator(RightCurlyBracket) => None,
+                        _ => Some(self.parse_var_type(iter)?),
+                    };
+
+                    let method_decl = FunctionDecl { args, return_type };
+
+                    if methods
+                        .insert(method_name.name.clone(), method_decl)
+                        .is_some()
+                    {
+                        return Err(CrocoError::new(
+                            &self.token_pos,
+                            format!("duplicate method {} in trait", method_name.name),
+                        ));
+                    }
+                }
+
+                Separator(RightCurlyBracket) => break,
+
+                _ => {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        "expected a method signature in the trait",
+                    ))
+                }
+            }
+        }
+
+        self.register_trait_decl(&trait_name.get_namespaced_name(), TraitDecl { methods })
+    }
+}
@@ -0,0 +1,94 @@
+use super::{ExprParsingType, ExprParsingType::*, Parser};
+
+use crate::ast::{
+    node::{DestructureNode, TupleCreateNode},
+    BackendNode,
+};
+use crate::error::CrocoError;
+use crate::token::{CodePos, OperatorEnum::*, SeparatorEnum::*, Token, Token::*};
+
+impl Parser {
+    /// Parses either an expression in parenthesis or a tuple, e.g (3 + 4) or (3, "three")
+    /// warning: it does not consume the left parenthesis
+    pub fn parse_tuple(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+        parse_type: ExprParsingType,
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        let code_pos = self.token_pos.clone();
+        let first = self.parse_expr(iter, parse_type)?;
+
+        // a single expression is just grouped
+        if let Separator(RightParenthesis) = self.peek_token(iter) {
+            self.next_token(iter);
+            return Ok(first);
+        }
+
+        let mut elements = vec![first];
+
+        loop {
+            match self.next_token(iter) {
+                Separator(Comma) => elements.push(self.parse_expr(iter, parse_type)?),
+                Separator(RightParenthesis) => break,
+                _ => {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        "expected a comma or a right parenthesis in the tuple",
+                    ))
+                }
+            }
+        }
+
+        Ok(Box::new(TupleCreateNode::new(elements, code_pos)))
+    }
+
+    /// Parses the declaration of the variables holding the elements of a tuple
+    /// e.g let (quotient, _) = div(7, 2)
+    /// warning: it does not consume the let keyword and the left parenthesis
+    pub fn parse_destructure(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+        is_const: bool,
+    ) -> Result<Box<dyn BackendNode>, CrocoError> {
+        let code_pos = self.token_pos.clone();
+        let mut var_names = Vec::new();
+
+        loop {
+            match self.next_token(iter) {
+                Identifier(identifier) => var_names.push(Some(identifier.name)),
+                Separator(Underscore) => var_names.push(None),
+                _ => {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        "expected a variable name in the tuple",
+                    ))
+                }
+            }
+
+            match self.next_token(iter) {
+                Separator(Comma) => (),
+                Separator(RightParenthesis) => break,
+                _ => {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        "expected a comma or a right parenthesis in the tuple",
+                    ))
+                }
+            }
+        }
+
+        self.expect_token(
+            iter,
+            Operator(Assign),
+            "expected an equals sign after the tuple",
+        )?;
+
+        let right = self.parse_expr(iter, AllowStructDeclaration)?;
+
+        for var_name in var_names.iter().flatten() {
+            self.declare_variable(var_name, is_const, None);
+        }
+
+        Ok(Box::new(DestructureNode::new(var_names, right, code_pos)))
+    }
+}
@@ -1,6 +1,7 @@
-use super::Parser;
+use super::{ParsedVariable, Parser};
 
 use crate::error::CrocoError;
+use crate::symbol_type::SymbolType;
 use crate::token::{CodePos, Identifier, LiteralEnum, SeparatorEnum::*, Token, Token::*};
 
 impl Parser {
@@ -48,6 +49,81 @@ impl Parser {
         }
     }
 
+    /// Records a variable declared in the current scope, which shadows the previous ones
+    pub fn declare_variable(
+        &mut self,
+        var_name: &str,
+        is_const: bool,
+        var_type: Option<SymbolType>,
+    ) {
+        // redeclarations are checked by the backends, the variable is replaced anyways
+        let _ = self
+            .variables
+            .insert_symbol(var_name, ParsedVariable { is_const, var_type });
+    }
+
+    /// Returns true if the variable visible with this name is a const
+    pub fn is_const(&self, var_name: &str) -> bool {
+        matches!(
+            self.variables.get_symbol(var_name),
+            Ok(ParsedVariable { is_const: true, .. })
+        )
+    }
+
+    /// Returns the type of the variable visible with this name, if it is known at parse time
+    pub fn variable_type(&self, var_name: &str) -> Option<SymbolType> {
+        self.variables
+            .get_symbol(var_name)
+            .ok()
+            .and_then(|variable| variable.var_type.clone())
+    }
+
+    /// Returns true if the name refers to an imported module, and not to a variable
+    pub fn is_module(&self, name: &str) -> bool {
+        self.modules.contains_key(name) && self.variables.get_symbol(name).is_err()
+    }
+
+    /// Returns the name of an item of the file, which is namespaced if the file is a module.
+    /// e.g area becomes geometry.area in the geometry module
+    pub fn resolve_item_name(&self, identifier: &Identifier) -> String {
+        if self.top_level_names.contains(&identifier.name)
+            && !self.variables.is_local_symbol(&identifier.name)
+        {
+            identifier.clone().get_namespaced_name()
+        } else {
+            identifier.name.clone()
+        }
+    }
+
+    /// Parses the name of an item, which can belong to an imported module, e.g geometry.area
+    /// warning: does not consume the first identifier
+    pub fn parse_item_name(
+        &mut self,
+        iter: &mut std::iter::Peekable<std::vec::IntoIter<(Token, CodePos)>>,
+        identifier: Identifier,
+    ) -> Result<String, CrocoError> {
+        if !self.is_module(&identifier.name) {
+            return Ok(self.resolve_item_name(&identifier));
+        }
+
+        self.expect_token(iter, Separator(Dot), "expected a dot after the module name")?;
+
+        let item = self.expect_identifier(iter, "expected an item name after the module name")?;
+
+        // only the pub items of a module can be used by the files importing it
+        if let Some(Some(pub_names)) = self.modules.get(&identifier.name) {
+            if !pub_names.contains(&item.name) {
+                return Err(CrocoError::private_item_error(
+                    &self.token_pos,
+                    &identifier.name,
+                    &item.name,
+                ));
+            }
+        }
+
+        Ok(format!("{}.{}", identifier.name, item.name))
+    }
+
     /// Discards all next tokens that are newlines
     pub fn discard_newlines(
         &mut self,
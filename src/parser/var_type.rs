@@ -2,12 +2,12 @@ use super::Parser;
 
 use crate::error::CrocoError;
 use crate::{
-    symbol_type::SymbolType,
+    symbol_type::{FunctionType, SymbolType},
     token::{CodePos, KeywordEnum::*, OperatorEnum::*, SeparatorEnum::*, Token, Token::*},
 };
 
 /// Parses the type of a symbol
-/// e.g [num], MyStruct, str
+/// e.g [num], [str: bool], MyStruct, MyTrait, MyEnum, T, fn(num) str, !str, (num, str), str
 impl Parser {
     pub fn parse_var_type(
         &mut self,
@@ -19,16 +19,131 @@ impl Parser {
             Keyword(Fnum) => Ok(SymbolType::Fnum),
             Keyword(Num) => Ok(SymbolType::Num),
             Keyword(Bool) => Ok(SymbolType::Bool),
+            Keyword(I64) => Ok(SymbolType::I64),
+            Keyword(U64) => Ok(SymbolType::U64),
+            Keyword(U8) => Ok(SymbolType::U8),
+            Keyword(F64) => Ok(SymbolType::F64),
+
+            // function
+            Keyword(Function) => {
+                self.expect_token(
+                    iter,
+                    Separator(LeftParenthesis),
+                    "expected a left parenthesis after fn in the function type",
+                )?;
+
+                let mut args = Vec::new();
+
+                if let Separator(RightParenthesis) = self.peek_token(iter) {
+                    self.next_token(iter);
+                } else {
+                    loop {
+                        args.push(self.parse_var_type(iter)?);
+
+                        match self.next_token(iter) {
+                            Separator(Comma) => (),
+                            Separator(RightParenthesis) => break,
+                            _ => {
+                                return Err(CrocoError::new(
+                                    &self.token_pos,
+                                    "expected a comma or a right parenthesis in the function type",
+                                ))
+                            }
+                        }
+                    }
+                }
+
+                // if the next token can't start a type the function is Void
+                let return_type = if can_start_type(&self.peek_token(iter)) {
+                    Some(Box::new(self.parse_var_type(iter)?))
+                } else {
+                    None
+                };
+
+                Ok(SymbolType::Function(FunctionType { args, return_type }))
+            }
+
+            // result, e.g !str, or ! for a fallible function without value
+            Operator(Bang) => {
+                if can_start_type(&self.peek_token(iter)) {
+                    Ok(SymbolType::Result(Some(Box::new(
+                        self.parse_var_type(iter)?,
+                    ))))
+                } else {
+                    Ok(SymbolType::Result(None))
+                }
+            }
+
+            // optional, e.g ?Node
+            Separator(QuestionMark) => {
+                Ok(SymbolType::Optional(Box::new(self.parse_var_type(iter)?)))
+            }
+
+            // tuple, e.g (num, str)
+            Separator(LeftParenthesis) => {
+                let mut types = vec![self.parse_var_type(iter)?];
+
+                loop {
+                    match self.next_token(iter) {
+                        Separator(Comma) => types.push(self.parse_var_type(iter)?),
+                        Separator(RightParenthesis) => break,
+                        _ => {
+                            return Err(CrocoError::new(
+                                &self.token_pos,
+                                "expected a comma or a right parenthesis in the tuple type",
+                            ))
+                        }
+                    }
+                }
+
+                if types.len() < 2 {
+                    return Err(CrocoError::new(
+                        &self.token_pos,
+                        "a tuple type needs at least two elements",
+                    ));
+                }
+
+                Ok(SymbolType::Tuple(types))
+            }
 
             // ref
             Operator(BitwiseAnd) => Ok(SymbolType::Ref(Box::new(self.parse_var_type(iter)?))),
 
-            // struct
-            Identifier(identifier) => Ok(SymbolType::Struct(identifier.name)),
+            // type parameter
+            Identifier(identifier) if self.generic_names.contains(&identifier.name) => {
+                Ok(SymbolType::Generic(identifier.name))
+            }
+
+            // trait, enum or struct, which can come from a module e.g geometry.Point
+            Identifier(identifier) => {
+                let name = self.parse_item_name(iter, identifier)?;
 
-            // array
+                if self.trait_names.contains(&name) {
+                    Ok(SymbolType::Trait(name))
+                } else if self.enum_names.contains(&name) {
+                    Ok(SymbolType::Enum(name))
+                } else {
+                    Ok(SymbolType::Struct(name))
+                }
+            }
+
+            // array or map
             Separator(LeftSquareBracket) => {
-                let ret = SymbolType::Array(Box::new(self.parse_var_type(iter)?));
+                let el_type = self.parse_var_type(iter)?;
+
+                let ret = if let Separator(Colon) = self.peek_token(iter) {
+                    self.next_token(iter);
+
+                    match el_type {
+                        SymbolType::Num | SymbolType::Str | SymbolType::Bool => (),
+                        _ => return Err(CrocoError::map_key_error(&self.token_pos)),
+                    }
+
+                    let value_type = self.parse_var_type(iter)?;
+                    SymbolType::Map(Box::new(el_type), Box::new(value_type))
+                } else {
+                    SymbolType::Array(Box::new(el_type))
+                };
 
                 self.expect_token(
                     iter,
@@ -43,3 +158,25 @@ impl Parser {
         }
     }
 }
+
+/// Checks if a token can be the start of a type
+pub fn can_start_type(token: &Token) -> bool {
+    matches!(
+        token,
+        Keyword(Str)
+            | Keyword(Fnum)
+            | Keyword(Num)
+            | Keyword(Bool)
+            | Keyword(I64)
+            | Keyword(U64)
+            | Keyword(U8)
+            | Keyword(F64)
+            | Keyword(Function)
+            | Operator(BitwiseAnd)
+            | Operator(Bang)
+            | Separator(QuestionMark)
+            | Separator(LeftSquareBracket)
+            | Separator(LeftParenthesis)
+            | Identifier(_)
+    )
+}
@@ -1,4 +1,7 @@
-use crate::{parser::TypedArg, symbol_type::SymbolType};
+use crate::{
+    parser::TypedArg,
+    symbol_type::{FunctionType, SymbolType},
+};
 use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 
@@ -9,6 +12,54 @@ pub struct FunctionDecl {
     pub return_type: Option<SymbolType>,
 }
 
+impl FunctionDecl {
+    /// Creates a function declaration with unnamed arguments from a function type
+    pub fn from_type(fn_type: &FunctionType) -> FunctionDecl {
+        FunctionDecl {
+            args: fn_type
+                .args
+                .iter()
+                .map(|arg| TypedArg {
+                    arg_name: String::new(),
+                    arg_type: arg.clone(),
+                })
+                .collect(),
+            return_type: fn_type.return_type.as_ref().map(|ret| *ret.clone()),
+        }
+    }
+
+    /// Returns the type of a function value with this declaration
+    pub fn get_type(&self) -> SymbolType {
+        SymbolType::Function(FunctionType {
+            args: self.args.iter().map(|arg| arg.arg_type.clone()).collect(),
+            return_type: self.return_type.clone().map(Box::new),
+        })
+    }
+
+    /// Checks if the function has type parameters
+    pub fn is_generic(&self) -> bool {
+        self.args.iter().any(|arg| arg.arg_type.is_generic())
+    }
+
+    /// Returns the function declaration with the type parameters replaced by the types they're bound to
+    pub fn resolve_generics(&self, bindings: &HashMap<String, SymbolType>) -> FunctionDecl {
+        FunctionDecl {
+            args: self
+                .args
+                .iter()
+                .map(|arg| TypedArg {
+                    arg_name: arg.arg_name.clone(),
+                    arg_type: arg.arg_type.resolve_generics(bindings),
+                })
+                .collect(),
+            return_type: self
+                .return_type
+                .as_ref()
+                .map(|return_type| return_type.resolve_generics(bindings)),
+        }
+    }
+}
+
 /// A struct declaration
 #[derive(Clone)]
 pub struct StructDecl {
@@ -23,6 +74,33 @@ impl fmt::Debug for StructDecl {
     }
 }
 
+/// A trait declaration
+#[derive(Clone, Debug)]
+pub struct TraitDecl {
+    // the signatures of the methods, without the self argument.
+    // in crocol the methods are order dependant in the vtables, so use a BTreeMap
+    pub methods: BTreeMap<String, FunctionDecl>,
+}
+
+/// An enum declaration
+#[derive(Clone, Debug)]
+pub struct EnumDecl {
+    // the variants with the types of their payload.
+    // the tag of a variant is its position, so the variants are kept in declaration order
+    pub variants: Vec<(String, Vec<SymbolType>)>,
+}
+
+impl EnumDecl {
+    /// Returns the tag and the payload types of a variant
+    pub fn get_variant(&self, variant_name: &str) -> Option<(usize, &[SymbolType])> {
+        self.variants
+            .iter()
+            .enumerate()
+            .find(|(_, (name, _))| name == variant_name)
+            .map(|(tag, (_, payload))| (tag, payload.as_slice()))
+    }
+}
+
 /// A top-level declaration such as a function declaration or a struct declaration
 #[derive(Clone, Debug)]
 pub enum Decl<U: Clone + fmt::Debug> {
@@ -32,6 +110,12 @@ pub enum Decl<U: Clone + fmt::Debug> {
     /// The blueprint of a struct such as "struct A { .. }"
     StructDecl(StructDecl),
 
+    /// The blueprint of a trait such as "trait A { .. }"
+    TraitDecl(TraitDecl),
+
+    /// The blueprint of an enum such as "enum A { .. }"
+    EnumDecl(EnumDecl),
+
     /// A global variable
     GlobalVariable(U),
 }
@@ -67,6 +151,27 @@ impl<T: Clone + fmt::Debug> SymTable<T> {
         self.symbols = symbols;
     }
 
+    /// Returns all the variables visible from the current scope, without the global ones.
+    /// The variables of the inner scopes shadow the others.
+    pub fn get_visible_symbols(&self) -> HashMap<String, T> {
+        let mut visible = HashMap::new();
+
+        for table in self.symbols.iter() {
+            for (name, symbol) in table {
+                visible.insert(name.clone(), symbol.clone());
+            }
+        }
+
+        visible
+    }
+
+    /// Returns true if the variable is declared in a scope, and not globally
+    pub fn is_local_symbol(&self, var_name: &str) -> bool {
+        self.symbols
+            .iter()
+            .any(|table| table.contains_key(var_name))
+    }
+
     /// Returns the desired symbol starting from the inner scope, and ending with the global scope
     pub fn get_symbol<'a>(&'a self, var_name: &str) -> Result<&'a T, String> {
         for table in self.symbols.iter().rev() {
@@ -83,6 +188,18 @@ impl<T: Clone + fmt::Debug> SymTable<T> {
         Err(format!("variable {} has not been declared", var_name))
     }
 
+    /// Replaces a variable declared in a scope, in the scope where it has been declared
+    pub fn replace_local_symbol(&mut self, var_name: &str, symbol: T) -> Result<(), String> {
+        for table in self.symbols.iter_mut().rev() {
+            if let Some(old_symbol) = table.get_mut(var_name) {
+                *old_symbol = symbol;
+                return Ok(());
+            }
+        }
+
+        Err(format!("variable {} has not been declared", var_name))
+    }
+
     /// Returns the desired function declaration starting from the inner scope
     pub fn get_function_decl(&mut self, fn_name: &str) -> Result<&mut FunctionDecl, String> {
         match self.top_level.get_mut(fn_name) {
@@ -112,6 +229,64 @@ impl<T: Clone + fmt::Debug> SymTable<T> {
         }
     }
 
+    /// Returns the desired trait declaration
+    pub fn get_trait_decl(&self, trait_name: &str) -> Result<&TraitDecl, String> {
+        match self.top_level.get(trait_name) {
+            Some(Decl::TraitDecl(trait_decl)) => Ok(trait_decl),
+            Some(_) => Err(format!(
+                "trying to get {} as a trait but it's not",
+                trait_name
+            )),
+            None => Err(format!("trait {} has not been declared", trait_name)),
+        }
+    }
+
+    /// Returns the desired enum declaration
+    pub fn get_enum_decl(&self, enum_name: &str) -> Result<&EnumDecl, String> {
+        match self.top_level.get(enum_name) {
+            Some(Decl::EnumDecl(enum_decl)) => Ok(enum_decl),
+            Some(_) => Err(format!(
+                "trying to get {} as an enum but it's not",
+                enum_name
+            )),
+            None => Err(format!("enum {} has not been declared", enum_name)),
+        }
+    }
+
+    /// Checks if a struct has all the methods of a trait, with the same signatures
+    pub fn check_trait_impl(&mut self, struct_name: &str, trait_name: &str) -> Result<(), String> {
+        let trait_decl = self.get_trait_decl(trait_name)?.clone();
+
+        for (method_name, method_decl) in trait_decl.methods {
+            let struct_method = self
+                .get_function_decl(&format!("_{}_{}", struct_name, method_name))
+                .map_err(|_| {
+                    format!(
+                        "struct {} doesn't implement the trait {}: method {} is missing",
+                        struct_name, trait_name, method_name
+                    )
+                })?;
+
+            // the self argument isn't part of the trait signature
+            let args_match = struct_method.args.len() == method_decl.args.len() + 1
+                && struct_method
+                    .args
+                    .iter()
+                    .skip(1)
+                    .zip(method_decl.args.iter())
+                    .all(|(a, b)| a.arg_type == b.arg_type);
+
+            if !args_match || struct_method.return_type != method_decl.return_type {
+                return Err(format!(
+                    "struct {} doesn't implement the trait {}: method {} has a different signature",
+                    struct_name, trait_name, method_name
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Inserts to the closest scope if possible, or to the global scope
     pub fn insert_symbol(&mut self, name: &str, symbol: T) -> Result<(), String> {
         let var_already_declared;
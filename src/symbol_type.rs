@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{collections::HashMap, fmt};
 
 /// The type of a symbol, which is backend-agnostic
 #[derive(Clone, Debug)]
@@ -7,19 +7,38 @@ pub enum SymbolType {
     Array(Box<SymbolType>),
     Map(Box<SymbolType>, Box<SymbolType>),
     Struct(String),
+    Trait(String),
+    Enum(String),
+    // a type parameter of a generic function, such as T in fn first<T>(arr [T]) T
+    Generic(String),
     Function(FunctionType),
+    // the result of a fallible function, holding either a value or an error, such as !str
+    Result(Option<Box<SymbolType>>),
+    // an error built with error(), which can be turned into any result
+    Error,
+    // a value which may be absent, such as ?Node
+    Optional(Box<SymbolType>),
+    // the none literal, which can be turned into any optional
+    None,
+    // a fixed group of values of different types, such as (num, str)
+    Tuple(Vec<SymbolType>),
     CrocoType,
     // primitive types
     Bool,
     Str,
     Fnum,
     Num,
+    // sized number types
+    I64,
+    U64,
+    U8,
+    F64,
 }
 
 #[derive(Clone, Debug)]
 pub struct FunctionType {
     pub args: Vec<SymbolType>,
-    pub return_type: Box<SymbolType>,
+    pub return_type: Option<Box<SymbolType>>,
 }
 
 impl SymbolType {
@@ -30,6 +49,167 @@ impl SymbolType {
             _ => panic!("did not get a reference"),
         }
     }
+
+    /// Checks if the type contains a type parameter
+    pub fn is_generic(&self) -> bool {
+        match self {
+            SymbolType::Generic(_) => true,
+            SymbolType::Ref(t) | SymbolType::Array(t) | SymbolType::Optional(t) => t.is_generic(),
+            SymbolType::Map(k, v) => k.is_generic() || v.is_generic(),
+            SymbolType::Result(t) => t.as_ref().is_some_and(|t| t.is_generic()),
+            SymbolType::Tuple(types) => types.iter().any(|t| t.is_generic()),
+            SymbolType::Function(func) => {
+                func.return_type
+                    .as_ref()
+                    .is_some_and(|ret| ret.is_generic())
+                    || func.args.iter().any(|arg| arg.is_generic())
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks if the type contains the given type parameter
+    pub fn contains_generic(&self, name: &str) -> bool {
+        match self {
+            SymbolType::Generic(generic) => generic == name,
+            SymbolType::Ref(t) | SymbolType::Array(t) | SymbolType::Optional(t) => {
+                t.contains_generic(name)
+            }
+            SymbolType::Map(k, v) => k.contains_generic(name) || v.contains_generic(name),
+            SymbolType::Result(t) => t.as_ref().is_some_and(|t| t.contains_generic(name)),
+            SymbolType::Tuple(types) => types.iter().any(|t| t.contains_generic(name)),
+            SymbolType::Function(func) => {
+                func.return_type
+                    .as_ref()
+                    .is_some_and(|ret| ret.contains_generic(name))
+                    || func.args.iter().any(|arg| arg.contains_generic(name))
+            }
+            _ => false,
+        }
+    }
+
+    /// Binds the type parameters of this type to the types found at the same place in a concrete type.
+    /// Returns false if the types don't match.
+    /// e.g [T] with [num] binds T to num
+    pub fn bind_generics(
+        &self,
+        concrete: &SymbolType,
+        bindings: &mut HashMap<String, SymbolType>,
+    ) -> bool {
+        match (self, concrete) {
+            (SymbolType::Generic(name), _) => match bindings.get(name) {
+                Some(bound) => bound == concrete,
+                None => {
+                    bindings.insert(name.clone(), concrete.clone());
+                    true
+                }
+            },
+            (SymbolType::Ref(a), SymbolType::Ref(b))
+            | (SymbolType::Array(a), SymbolType::Array(b))
+            | (SymbolType::Optional(a), SymbolType::Optional(b)) => a.bind_generics(b, bindings),
+            (SymbolType::Map(a, b), SymbolType::Map(c, d)) => {
+                a.bind_generics(c, bindings) && b.bind_generics(d, bindings)
+            }
+            (SymbolType::Result(Some(a)), SymbolType::Result(Some(b))) => {
+                a.bind_generics(b, bindings)
+            }
+            (SymbolType::Tuple(a), SymbolType::Tuple(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b.iter())
+                        .all(|(a, b)| a.bind_generics(b, bindings))
+            }
+            (SymbolType::Function(a), SymbolType::Function(b)) => {
+                let return_bound = match (&a.return_type, &b.return_type) {
+                    (Some(a), Some(b)) => a.bind_generics(b, bindings),
+                    (None, None) => true,
+                    _ => false,
+                };
+
+                return_bound
+                    && a.args.len() == b.args.len()
+                    && a.args
+                        .iter()
+                        .zip(b.args.iter())
+                        .all(|(a, b)| a.bind_generics(b, bindings))
+            }
+            _ => self == concrete,
+        }
+    }
+
+    /// Replaces the type parameters by the types they're bound to
+    pub fn resolve_generics(&self, bindings: &HashMap<String, SymbolType>) -> SymbolType {
+        match self {
+            SymbolType::Generic(name) => {
+                bindings.get(name).cloned().unwrap_or_else(|| self.clone())
+            }
+            SymbolType::Ref(t) => SymbolType::Ref(Box::new(t.resolve_generics(bindings))),
+            SymbolType::Array(t) => SymbolType::Array(Box::new(t.resolve_generics(bindings))),
+            SymbolType::Optional(t) => SymbolType::Optional(Box::new(t.resolve_generics(bindings))),
+            SymbolType::Map(k, v) => SymbolType::Map(
+                Box::new(k.resolve_generics(bindings)),
+                Box::new(v.resolve_generics(bindings)),
+            ),
+            SymbolType::Result(t) => {
+                SymbolType::Result(t.as_ref().map(|t| Box::new(t.resolve_generics(bindings))))
+            }
+            SymbolType::Tuple(types) => {
+                SymbolType::Tuple(types.iter().map(|t| t.resolve_generics(bindings)).collect())
+            }
+            SymbolType::Function(func) => SymbolType::Function(FunctionType {
+                args: func
+                    .args
+                    .iter()
+                    .map(|arg| arg.resolve_generics(bindings))
+                    .collect(),
+                return_type: func
+                    .return_type
+                    .as_ref()
+                    .map(|ret| Box::new(ret.resolve_generics(bindings))),
+            }),
+            _ => self.clone(),
+        }
+    }
+
+    /// Checks if the type is an integer type
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            SymbolType::Num | SymbolType::I64 | SymbolType::U64 | SymbolType::U8
+        )
+    }
+
+    /// Checks if the type is a floating-point type
+    pub fn is_float(&self) -> bool {
+        matches!(self, SymbolType::Fnum | SymbolType::F64)
+    }
+
+    /// Checks if the type is an integer or a floating-point type
+    pub fn is_number(&self) -> bool {
+        self.is_integer() || self.is_float()
+    }
+
+    /// Checks if the type is an unsigned integer type
+    pub fn is_unsigned(&self) -> bool {
+        matches!(self, SymbolType::U64 | SymbolType::U8)
+    }
+
+    /// Gets the type two numbers are converted to when they're used in the same operation.
+    /// A number can only be widened to a type holding all of its values,
+    /// e.g u8 + num is a num, num + i64 is an i64 and fnum + f64 is a f64, but num + u64 is an error.
+    pub fn widen(&self, other: &SymbolType) -> Option<SymbolType> {
+        match (self, other) {
+            _ if self == other && self.is_number() => Some(self.clone()),
+            (SymbolType::U8, int) | (int, SymbolType::U8) if int.is_integer() => Some(int.clone()),
+            (SymbolType::Num, SymbolType::I64) | (SymbolType::I64, SymbolType::Num) => {
+                Some(SymbolType::I64)
+            }
+            (SymbolType::Fnum, SymbolType::F64) | (SymbolType::F64, SymbolType::Fnum) => {
+                Some(SymbolType::F64)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl PartialEq for SymbolType {
@@ -39,11 +219,23 @@ impl PartialEq for SymbolType {
             (SymbolType::Bool, SymbolType::Bool)
             | (SymbolType::Str, SymbolType::Str)
             | (SymbolType::Num, SymbolType::Num)
-            | (SymbolType::Fnum, SymbolType::Fnum) => true,
+            | (SymbolType::Fnum, SymbolType::Fnum)
+            | (SymbolType::I64, SymbolType::I64)
+            | (SymbolType::U64, SymbolType::U64)
+            | (SymbolType::U8, SymbolType::U8)
+            | (SymbolType::F64, SymbolType::F64) => true,
             (SymbolType::Struct(a), SymbolType::Struct(b)) => a == b,
+            (SymbolType::Trait(a), SymbolType::Trait(b)) => a == b,
+            (SymbolType::Enum(a), SymbolType::Enum(b)) => a == b,
+            (SymbolType::Generic(a), SymbolType::Generic(b)) => a == b,
             (SymbolType::Map(a, b), SymbolType::Map(c, d)) => a == c && b == d,
             (SymbolType::Array(a), SymbolType::Array(b)) => a == b,
             (SymbolType::Ref(a), SymbolType::Ref(b)) => a == b,
+            (SymbolType::Result(a), SymbolType::Result(b)) => a == b,
+            (SymbolType::Error, SymbolType::Error) => true,
+            (SymbolType::Optional(a), SymbolType::Optional(b)) => a == b,
+            (SymbolType::None, SymbolType::None) => true,
+            (SymbolType::Tuple(a), SymbolType::Tuple(b)) => a == b,
             (SymbolType::Function(a), SymbolType::Function(b)) => {
                 if a.args.len() != b.args.len() {
                     return false;
@@ -70,25 +262,39 @@ impl fmt::Display for SymbolType {
         match self {
             SymbolType::Ref(r) => write!(f, "&{}", r),
             SymbolType::Array(a) => write!(f, "[{}]", a),
-            SymbolType::Map(a, b) => write!(f, "{{{}, {}}}", a, b),
+            SymbolType::Map(a, b) => write!(f, "[{}: {}]", a, b),
             SymbolType::Struct(s) => write!(f, "{}", s),
+            SymbolType::Trait(t) => write!(f, "{}", t),
+            SymbolType::Enum(e) => write!(f, "{}", e),
+            SymbolType::Generic(g) => write!(f, "{}", g),
             SymbolType::Function(func) => {
-                write!(f, "{} fn(", func.return_type)?;
-                for i in 0..func.args.len() - 1 {
-                    write!(f, "{}, ", func.args[i])?;
-                }
+                let args: Vec<String> = func.args.iter().map(|arg| arg.to_string()).collect();
+                write!(f, "fn({})", args.join(", "))?;
 
-                if let Some(last) = func.args.last() {
-                    write!(f, "{})", last)
-                } else {
-                    write!(f, ")")
+                if let Some(return_type) = &func.return_type {
+                    write!(f, " {}", return_type)?;
                 }
+
+                Ok(())
+            }
+            SymbolType::Result(Some(t)) => write!(f, "!{}", t),
+            SymbolType::Result(None) => write!(f, "!"),
+            SymbolType::Error => write!(f, "error"),
+            SymbolType::Optional(t) => write!(f, "?{}", t),
+            SymbolType::None => write!(f, "none"),
+            SymbolType::Tuple(types) => {
+                let types: Vec<String> = types.iter().map(|t| t.to_string()).collect();
+                write!(f, "({})", types.join(", "))
             }
             SymbolType::CrocoType => write!(f, "type"),
             SymbolType::Bool => write!(f, "bool"),
             SymbolType::Str => write!(f, "str"),
             SymbolType::Fnum => write!(f, "fnum"),
             SymbolType::Num => write!(f, "num"),
+            SymbolType::I64 => write!(f, "i64"),
+            SymbolType::U64 => write!(f, "u64"),
+            SymbolType::U8 => write!(f, "u8"),
+            SymbolType::F64 => write!(f, "f64"),
         }
     }
 }
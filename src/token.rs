@@ -1,3 +1,4 @@
+use crate::symbol_type::SymbolType;
 use std::{cmp::Ordering, rc::Rc};
 
 #[derive(Debug, Clone)]
@@ -6,6 +7,11 @@ pub enum LiteralEnum {
     Num(i32),
     Fnum(f32),
     Str(String),
+    // sized numbers, written with a suffix such as 10i64
+    I64(i64),
+    U64(u64),
+    U8(u8),
+    F64(f64),
 }
 
 pub fn literal_eq(a: &LiteralEnum, b: &LiteralEnum) -> bool {
@@ -14,7 +20,29 @@ pub fn literal_eq(a: &LiteralEnum, b: &LiteralEnum) -> bool {
 
 impl LiteralEnum {
     pub fn is_num_fnum(&self) -> bool {
-        matches!(self, LiteralEnum::Fnum(_) | LiteralEnum::Num(_))
+        matches!(
+            self,
+            LiteralEnum::Fnum(_)
+                | LiteralEnum::Num(_)
+                | LiteralEnum::I64(_)
+                | LiteralEnum::U64(_)
+                | LiteralEnum::U8(_)
+                | LiteralEnum::F64(_)
+        )
+    }
+
+    /// Gets the type of the literal
+    pub fn get_symbol_type(&self) -> SymbolType {
+        match self {
+            LiteralEnum::Bool(_) => SymbolType::Bool,
+            LiteralEnum::Num(_) => SymbolType::Num,
+            LiteralEnum::Fnum(_) => SymbolType::Fnum,
+            LiteralEnum::Str(_) => SymbolType::Str,
+            LiteralEnum::I64(_) => SymbolType::I64,
+            LiteralEnum::U64(_) => SymbolType::U64,
+            LiteralEnum::U8(_) => SymbolType::U8,
+            LiteralEnum::F64(_) => SymbolType::F64,
+        }
     }
 
     pub fn into_bool(self) -> Result<bool, &'static str> {
@@ -51,6 +79,10 @@ impl PartialEq for LiteralEnum {
         match (self, other) {
             (LiteralEnum::Num(n1), LiteralEnum::Num(n2)) => n1.eq(n2),
             (LiteralEnum::Fnum(f1), LiteralEnum::Fnum(f2)) => f1.eq(f2),
+            (LiteralEnum::I64(n1), LiteralEnum::I64(n2)) => n1.eq(n2),
+            (LiteralEnum::U64(n1), LiteralEnum::U64(n2)) => n1.eq(n2),
+            (LiteralEnum::U8(n1), LiteralEnum::U8(n2)) => n1.eq(n2),
+            (LiteralEnum::F64(f1), LiteralEnum::F64(f2)) => f1.eq(f2),
             (LiteralEnum::Num(n), LiteralEnum::Fnum(f))
             | (LiteralEnum::Fnum(f), LiteralEnum::Num(n)) => (*n as f32).eq(f),
             (LiteralEnum::Str(s1), LiteralEnum::Str(s2)) => s1.eq(s2),
@@ -65,9 +97,14 @@ impl PartialOrd for LiteralEnum {
         match (self, other) {
             (LiteralEnum::Num(n1), LiteralEnum::Num(n2)) => n1.partial_cmp(n2),
             (LiteralEnum::Fnum(f1), LiteralEnum::Fnum(f2)) => f1.partial_cmp(f2),
+            (LiteralEnum::I64(n1), LiteralEnum::I64(n2)) => n1.partial_cmp(n2),
+            (LiteralEnum::U64(n1), LiteralEnum::U64(n2)) => n1.partial_cmp(n2),
+            (LiteralEnum::U8(n1), LiteralEnum::U8(n2)) => n1.partial_cmp(n2),
+            (LiteralEnum::F64(f1), LiteralEnum::F64(f2)) => f1.partial_cmp(f2),
             (LiteralEnum::Fnum(f), LiteralEnum::Num(n)) => f.partial_cmp(&(*n as f32)),
             (LiteralEnum::Num(n), LiteralEnum::Fnum(f)) => (*n as f32).partial_cmp(f),
-            _ => unreachable!(),
+            (LiteralEnum::Str(s1), LiteralEnum::Str(s2)) => s1.partial_cmp(s2),
+            _ => None,
         }
     }
 }
@@ -85,6 +122,15 @@ pub enum SeparatorEnum {
     Comma,
     Colon,
     Dot,
+    DoubleDot,
+    DoubleDotEquals,
+    FatArrow,
+    QuestionMark,
+    QuestionDot,
+    Underscore,
+    // the delimiters of an expression interpolated in a str, e.g "{a}"
+    InterpolationStart,
+    InterpolationEnd,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -95,12 +141,21 @@ pub enum OperatorEnum {
     MultiplicateEquals,
     DivideEquals,
     PowerEquals,
+    ModuloEquals,
+    BitwiseAndEquals,
+    BitwiseOrEquals,
+    BitwiseXorEquals,
+    ShiftLeftEquals,
+    ShiftRightEquals,
 
     // binary operators
     Or,
     And,
     BitwiseAnd,
     BitwiseOr,
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
     Equals,
     NotEquals,
     GreaterThan,
@@ -113,34 +168,50 @@ pub enum OperatorEnum {
     Multiplicate,
     Divide,
     Power,
+    Modulo,
+    Coalesce,
 
     As,
 
     // unary operators
     Bang,
     UnaryMinus,
+    BitwiseNot,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum KeywordEnum {
     Bool,
     Break,
+    Catch,
+    Const,
     Continue,
     Elif,
     Else,
+    Enum,
+    Error,
+    F64,
     Fnum,
     For,
     Function,
+    I64,
     If,
     Import,
+    In,
     Let,
     Match,
+    // none can't be used as a name since it would shadow Option::None in glob imports
+    NoneValue,
     Num,
+    Pub,
     Return,
     Str,
     While,
     Struct,
     Test,
+    Trait,
+    U64,
+    U8,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -160,13 +231,11 @@ impl Identifier {
     }
     ///  returns the namespaced name of the identifer
     pub fn get_namespaced_name(self) -> String {
-        self.name
-        // TODO: re-enable namespaces when the parser will support top level statements
-        // if self.namespace.is_empty() {
-        //     self.name
-        // } else {
-        //     format!("{}.{}", self.namespace, self.name)
-        // }
+        if self.namespace.is_empty() {
+            self.name
+        } else {
+            format!("{}.{}", self.namespace, self.name)
+        }
     }
 }
 
@@ -55,3 +55,50 @@ fn it_is_indexable() {
         ALL_BACKENDS,
     );
 }
+
+#[test]
+fn it_has_methods() {
+    test_file_ok(
+        "tests/arrays/it_has_methods/insert_remove.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok("tests/arrays/it_has_methods/push_pop.croco", ALL_BACKENDS);
+
+    test_file_err(
+        "tests/arrays/it_has_methods/pop_empty_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/arrays/it_has_methods/remove_out_of_bounds_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/arrays/it_has_methods/wrong_element_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok("tests/arrays/it_has_methods/search.croco", ALL_BACKENDS);
+
+    test_file_ok(
+        "tests/arrays/it_has_methods/reverse_slice_concat.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/arrays/it_has_methods/concat_wrong_type_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/arrays/it_has_methods/contains_wrong_type_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/arrays/it_has_methods/join_wrong_type_err.croco",
+        ALL_BACKENDS,
+    );
+}
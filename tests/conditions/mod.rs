@@ -1,6 +1,6 @@
 // If tests
 
-use crate::{test_file_ok, ALL_BACKENDS};
+use crate::{test_file_err, test_file_ok, ALL_BACKENDS};
 
 #[test]
 fn it_matches_if_properly() {
@@ -19,3 +19,71 @@ fn it_matches_if_properly() {
         ALL_BACKENDS,
     )
 }
+
+// Comparisons
+
+#[test]
+fn it_compares_values() {
+    test_file_ok(
+        "tests/conditions/it_compares_values/str_order.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/conditions/it_compares_values/deep_equality.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/conditions/it_compares_values/array_order_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/conditions/it_compares_values/bool_order_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/conditions/it_compares_values/different_types_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+// Match expressions
+
+#[test]
+fn it_matches_patterns() {
+    test_file_ok(
+        "tests/conditions/it_matches_patterns/expression.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/conditions/it_matches_patterns/statement.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_checks_match_types() {
+    test_file_err(
+        "tests/conditions/it_checks_match_types/arms_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/conditions/it_checks_match_types/pattern_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/conditions/it_checks_match_types/wildcard_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/conditions/it_checks_match_types/variable_arms_err.croco",
+        ALL_BACKENDS,
+    );
+}
@@ -0,0 +1,79 @@
+use crate::{test_file_err, test_file_ok, ALL_BACKENDS};
+
+// Const tests
+
+#[test]
+fn it_is_declared_correctly() {
+    test_file_ok(
+        "tests/consts/it_is_declared_correctly/declaration.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/consts/it_is_declared_correctly/shadowing.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/consts/it_is_declared_correctly/global.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/consts/it_is_declared_correctly/uninitialized_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_is_immutable() {
+    test_file_err(
+        "tests/consts/it_is_immutable/assignment_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/consts/it_is_immutable/compound_assignment_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/consts/it_is_immutable/reference_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/consts/it_is_immutable/field_assignment_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/consts/it_is_immutable/global_assignment_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/consts/it_is_immutable/later_global_assignment_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/consts/it_is_immutable/later_global_method_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/consts/it_is_immutable/method_call_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/consts/it_is_immutable/array_method_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/consts/it_is_immutable/read_only_methods.croco",
+        ALL_BACKENDS,
+    );
+}
@@ -0,0 +1,61 @@
+use crate::{test_file_err, test_file_ok, ALL_BACKENDS};
+
+// Enum tests
+
+#[test]
+fn it_is_declared_correctly() {
+    test_file_ok(
+        "tests/enums/it_is_declared_correctly/declaration.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/enums/it_is_declared_correctly/duplicate_variant_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/enums/it_is_declared_correctly/same_name_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_is_created() {
+    test_file_ok("tests/enums/it_is_created/payloads.croco", ALL_BACKENDS);
+
+    test_file_err(
+        "tests/enums/it_is_created/payload_count_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/enums/it_is_created/payload_type_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/enums/it_is_created/uninitialized_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/enums/it_is_created/unknown_variant_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_is_matched() {
+    test_file_ok("tests/enums/it_is_matched/exhaustive.croco", ALL_BACKENDS);
+
+    test_file_err(
+        "tests/enums/it_is_matched/alternative_bindings_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/enums/it_is_matched/non_exhaustive_err.croco",
+        ALL_BACKENDS,
+    );
+}
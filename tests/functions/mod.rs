@@ -10,6 +10,18 @@ fn it_is_called() {
         "tests/functions/it_is_called/multiple_arguments.croco",
         ALL_BACKENDS,
     );
+
+    // omitted trailing optional arguments
+    test_file_ok(
+        "tests/functions/it_is_called/optional_arguments.croco",
+        ALL_BACKENDS,
+    );
+
+    // only the trailing optional arguments can be omitted
+    test_file_err(
+        "tests/functions/it_is_called/optional_arguments_err.croco",
+        ALL_BACKENDS,
+    );
 }
 
 #[test]
@@ -62,7 +74,19 @@ fn it_uses_correctly_variables() {
     // using a global variable in a function
     test_file_ok(
         "tests/functions/it_uses_correctly_variables/global_var.croco",
-        CROCOI,
+        ALL_BACKENDS,
+    );
+
+    // initializing global variables before main
+    test_file_ok(
+        "tests/functions/it_uses_correctly_variables/global_init.croco",
+        ALL_BACKENDS,
+    );
+
+    // naming global variables after the symbols of the C library
+    test_file_ok(
+        "tests/functions/it_uses_correctly_variables/global_c_names.croco",
+        ALL_BACKENDS,
     );
 
     // if the variables are correctly restored after function calls
@@ -89,3 +113,115 @@ fn it_does_not_change_type() {
         ALL_BACKENDS,
     );
 }
+
+#[test]
+fn it_is_generic() {
+    test_file_ok(
+        "tests/functions/it_is_generic/generic_call.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/functions/it_is_generic/multiple_parameters.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/functions/it_is_generic/generic_body.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/functions/it_is_generic/mismatched_types_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/functions/it_is_generic/unused_parameter_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_is_a_value() {
+    test_file_ok(
+        "tests/functions/it_is_a_value/named_function.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok("tests/functions/it_is_a_value/closure.croco", ALL_BACKENDS);
+
+    test_file_ok("tests/functions/it_is_a_value/capture.croco", ALL_BACKENDS);
+
+    test_file_ok(
+        "tests/functions/it_is_a_value/returned_closure.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/functions/it_is_a_value/loop_closures.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/functions/it_is_a_value/not_callable_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/functions/it_is_a_value/indexed_call_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/functions/it_is_a_value/wrong_argument_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/functions/it_is_a_value/generic_value_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_returns_errors() {
+    test_file_ok(
+        "tests/functions/it_returns_errors/propagate.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/functions/it_returns_errors/handle.croco",
+        ALL_BACKENDS,
+    );
+
+    // the fs module is only available in crocoi
+    test_file_ok(
+        "tests/functions/it_returns_errors/builtin_errors.croco",
+        CROCOI,
+    );
+
+    // the os module is only available in crocoi
+    test_file_err("tests/functions/it_returns_errors/exec_err.croco", CROCOI);
+
+    test_file_err(
+        "tests/functions/it_returns_errors/uncaught_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/functions/it_returns_errors/catch_value_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/functions/it_returns_errors/fallback_type_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/functions/it_returns_errors/not_a_result_err.croco",
+        ALL_BACKENDS,
+    );
+}
@@ -0,0 +1,54 @@
+use crate::{test_file_err, test_file_ok, ALL_BACKENDS};
+
+// Import tests
+
+#[test]
+fn it_imports_modules() {
+    test_file_ok(
+        "tests/imports/it_imports_modules/declarations.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/imports/it_imports_modules/namespaced.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/imports/it_imports_modules/private_item_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/imports/it_imports_modules/transitive_import_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/imports/it_imports_modules/builtin_namespace_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/imports/it_imports_modules/module_assignment_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_resolves_imports() {
+    test_file_ok(
+        "tests/imports/it_resolves_imports/cached.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/imports/it_resolves_imports/cycle_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/imports/it_resolves_imports/missing_file_err.croco",
+        ALL_BACKENDS,
+    );
+}
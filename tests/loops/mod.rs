@@ -1,6 +1,6 @@
 use crate::ALL_BACKENDS;
 
-use super::test_file_ok;
+use super::{test_file_err, test_file_ok};
 
 #[test]
 fn it_loops_correctly() {
@@ -17,3 +17,25 @@ fn it_returns_early() {
 
     test_file_ok("tests/loops/it_returns_early/continue.croco", ALL_BACKENDS);
 }
+
+#[test]
+fn it_iterates_with_for() {
+    test_file_ok("tests/loops/it_iterates_with_for/range.croco", ALL_BACKENDS);
+
+    test_file_ok("tests/loops/it_iterates_with_for/array.croco", ALL_BACKENDS);
+
+    test_file_ok(
+        "tests/loops/it_iterates_with_for/break_continue.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/loops/it_iterates_with_for/nested_while.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/loops/it_iterates_with_for/range_err.croco",
+        ALL_BACKENDS,
+    );
+}
@@ -0,0 +1,67 @@
+use crate::{test_file_err, test_file_ok, ALL_BACKENDS};
+
+// Map tests
+
+#[test]
+fn it_can_be_created() {
+    test_file_ok(
+        "tests/maps/it_can_be_created/basic_creation.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/maps/it_can_be_created/empty_creation.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/maps/it_can_be_created/fnum_key_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_does_not_change_type() {
+    test_file_err(
+        "tests/maps/it_does_not_change_type/mixed_keys_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/maps/it_does_not_change_type/mixed_values_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/maps/it_does_not_change_type/wrong_key_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_is_indexable() {
+    test_file_ok("tests/maps/it_is_indexable/assign.croco", ALL_BACKENDS);
+
+    test_file_err(
+        "tests/maps/it_is_indexable/missing_key_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok("tests/maps/it_is_indexable/nested.croco", ALL_BACKENDS);
+}
+
+#[test]
+fn it_has_methods() {
+    test_file_ok(
+        "tests/maps/it_has_methods/contains_remove.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok("tests/maps/it_has_methods/keys_values.croco", ALL_BACKENDS);
+}
+
+#[test]
+fn it_is_passed() {
+    // map parameters and map return values
+    test_file_ok("tests/maps/it_is_passed/function_map.croco", ALL_BACKENDS);
+}
@@ -0,0 +1,16 @@
+// Math module tests
+
+use super::{test_file_err, test_file_ok, ALL_BACKENDS};
+
+#[test]
+fn it_computes() {
+    test_file_ok("tests/math/it_computes/rounding.croco", ALL_BACKENDS);
+    test_file_ok("tests/math/it_computes/functions.croco", ALL_BACKENDS);
+    test_file_ok("tests/math/it_computes/constants.croco", ALL_BACKENDS);
+    test_file_ok("tests/math/it_computes/numbers.croco", ALL_BACKENDS);
+    test_file_err("tests/math/it_computes/wrong_type_err.croco", ALL_BACKENDS);
+    test_file_err(
+        "tests/math/it_computes/number_wrong_type_err.croco",
+        ALL_BACKENDS,
+    );
+}
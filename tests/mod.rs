@@ -1,10 +1,19 @@
 mod arrays;
 mod conditions;
+mod consts;
+mod enums;
 mod functions;
+mod imports;
 mod loops;
+mod maps;
+mod math;
+mod optionals;
 mod primitives;
 mod references;
 mod structs;
+mod testing;
+mod traits;
+mod tuples;
 
 use std::process::Command;
 
@@ -0,0 +1,53 @@
+use crate::{test_file_err, test_file_ok, ALL_BACKENDS};
+
+// Optional tests
+
+#[test]
+fn it_is_declared_correctly() {
+    test_file_ok(
+        "tests/optionals/it_is_declared_correctly/declaration.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/optionals/it_is_declared_correctly/infer_none_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/optionals/it_is_declared_correctly/wrong_type_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_is_unwrapped() {
+    test_file_ok("tests/optionals/it_is_unwrapped/if_let.croco", ALL_BACKENDS);
+    test_file_ok(
+        "tests/optionals/it_is_unwrapped/default.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/optionals/it_is_unwrapped/default_type_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/optionals/it_is_unwrapped/not_optional_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_is_chained() {
+    test_file_ok(
+        "tests/optionals/it_is_chained/linked_list.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/optionals/it_is_chained/field_access_err.croco",
+        ALL_BACKENDS,
+    );
+}
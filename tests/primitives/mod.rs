@@ -1,6 +1,6 @@
 // Primitive tests
 
-use super::{test_file_err, test_file_ok, ALL_BACKENDS, CROCOI};
+use super::{test_file_err, test_file_ok, ALL_BACKENDS};
 
 #[test]
 fn it_assigns_correct_values() {
@@ -45,6 +45,17 @@ fn it_assigns_correct_values() {
         "tests/primitives/it_assigns_correct_values/bool_default_assignment.croco",
         ALL_BACKENDS,
     );
+
+    // sized numbers assignment
+    test_file_ok(
+        "tests/primitives/it_assigns_correct_values/sized_assignment.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/primitives/it_assigns_correct_values/sized_default_assignment.croco",
+        ALL_BACKENDS,
+    );
 }
 
 #[test]
@@ -56,7 +67,7 @@ fn it_shadows_correctly() {
 
     test_file_ok(
         "tests/primitives/it_shadows_correctly/shadow_ok.croco",
-        CROCOI,
+        ALL_BACKENDS,
     );
 }
 
@@ -89,6 +100,152 @@ fn it_calculates_correctly() {
     );
     test_file_ok(
         "tests/primitives/it_calculates_correctly/parenthesis.croco",
-        CROCOI,
+        ALL_BACKENDS,
+    );
+    test_file_ok(
+        "tests/primitives/it_calculates_correctly/modulo.croco",
+        ALL_BACKENDS,
+    );
+    test_file_err(
+        "tests/primitives/it_calculates_correctly/modulo_by_zero_err.croco",
+        ALL_BACKENDS,
+    );
+    test_file_ok(
+        "tests/primitives/it_calculates_correctly/power.croco",
+        ALL_BACKENDS,
+    );
+    test_file_err(
+        "tests/primitives/it_calculates_correctly/negative_exponent_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_has_methods() {
+    test_file_ok(
+        "tests/primitives/it_has_methods/str_methods.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/primitives/it_has_methods/str_graphemes.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/primitives/it_has_methods/num_methods.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/primitives/it_has_methods/str_search.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/primitives/it_has_methods/str_transform.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/primitives/it_has_methods/str_parse.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/primitives/it_has_methods/str_parse_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/primitives/it_has_methods/str_method_args_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/primitives/it_has_methods/unknown_method_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_operates_on_bits() {
+    test_file_ok(
+        "tests/primitives/it_operates_on_bits/bitwise.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/primitives/it_operates_on_bits/shifts.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/primitives/it_operates_on_bits/compound_assignment.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/primitives/it_operates_on_bits/float_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/primitives/it_operates_on_bits/not_float_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_widens_correctly() {
+    test_file_ok(
+        "tests/primitives/it_widens_correctly/widening.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/primitives/it_widens_correctly/sign_mismatch_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/primitives/it_widens_correctly/narrowing_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/primitives/it_widens_correctly/out_of_range_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_casts_correctly() {
+    test_file_ok(
+        "tests/primitives/it_casts_correctly/sized_casts.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_interpolates_strings() {
+    test_file_ok(
+        "tests/primitives/it_interpolates_strings/interpolation.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/primitives/it_interpolates_strings/escaped_braces.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/primitives/it_interpolates_strings/empty_interpolation_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/primitives/it_interpolates_strings/unclosed_brace_err.croco",
+        ALL_BACKENDS,
     );
 }
@@ -0,0 +1,31 @@
+// Built-in test framework
+
+use crate::{test_file_err, test_file_ok, ALL_BACKENDS};
+
+#[test]
+fn it_runs_test_blocks() {
+    // test blocks are ignored when running the program
+    test_file_ok(
+        "tests/testing/it_runs_test_blocks/tests.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/testing/it_runs_test_blocks/nested_err.croco",
+        ALL_BACKENDS,
+    );
+
+    #[cfg(feature = "crocoi")]
+    {
+        let mut crocoi = croco::Crocoi::new();
+        let results = crocoi
+            .test_file("tests/testing/it_runs_test_blocks/tests.croco")
+            .unwrap();
+
+        let names: Vec<_> = results.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["double", "failing", "isolated", "still isolated"]);
+
+        let passed: Vec<_> = results.iter().map(|(_, res)| res.is_ok()).collect();
+        assert_eq!(passed, [true, false, true, true]);
+    }
+}
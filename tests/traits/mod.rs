@@ -0,0 +1,62 @@
+use crate::{test_file_err, test_file_ok, ALL_BACKENDS};
+
+// Trait tests
+
+#[test]
+fn it_is_declared_correctly() {
+    test_file_ok(
+        "tests/traits/it_is_declared_correctly/declaration.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/traits/it_is_declared_correctly/nested_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/traits/it_is_declared_correctly/same_name_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_dispatches_methods() {
+    test_file_ok(
+        "tests/traits/it_dispatches_methods/basic_dispatch.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/traits/it_dispatches_methods/mutation.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_ok(
+        "tests/traits/it_dispatches_methods/parameters.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_checks_implementations() {
+    test_file_err(
+        "tests/traits/it_checks_implementations/missing_method_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/traits/it_checks_implementations/not_in_trait_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/traits/it_checks_implementations/signature_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/traits/it_checks_implementations/uninitialized_err.croco",
+        ALL_BACKENDS,
+    );
+}
@@ -0,0 +1,49 @@
+use crate::{test_file_err, test_file_ok, ALL_BACKENDS};
+
+// Tuple tests
+
+#[test]
+fn it_is_declared_correctly() {
+    test_file_ok(
+        "tests/tuples/it_is_declared_correctly/declaration.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/tuples/it_is_declared_correctly/single_element_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/tuples/it_is_declared_correctly/wrong_type_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_is_accessed() {
+    test_file_ok("tests/tuples/it_is_accessed/fields.croco", ALL_BACKENDS);
+
+    test_file_err(
+        "tests/tuples/it_is_accessed/out_of_range_err.croco",
+        ALL_BACKENDS,
+    );
+}
+
+#[test]
+fn it_is_destructured() {
+    test_file_ok(
+        "tests/tuples/it_is_destructured/multiple_returns.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/tuples/it_is_destructured/count_err.croco",
+        ALL_BACKENDS,
+    );
+
+    test_file_err(
+        "tests/tuples/it_is_destructured/not_tuple_err.croco",
+        ALL_BACKENDS,
+    );
+}